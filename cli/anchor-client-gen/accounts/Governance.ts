@@ -5,6 +5,8 @@ import * as types from "../types" // eslint-disable-line @typescript-eslint/no-u
 import { PROGRAM_ID } from "../programId"
 
 export interface GovernanceFields {
+  /** layout version of the account, see `Governance::migrate` */
+  version: number
   /** amount of dsla to be deposited by the sla_deployer to deploy the sla for each period */
   dslaDepositByPeriod: BN
   /** amount of dsla deposit by period to be given to the platform */
@@ -19,9 +21,50 @@ export interface GovernanceFields {
   protocolRewardsRate: types.DslaDecimalFields
   /** max leverage allowed in a DSLA */
   maxLeverage: types.DslaDecimalFields
+  /** oracle max staleness in seconds used by SLAs that don't set their own */
+  defaultMaxStaleness: number
+  /** highest oracle max staleness in seconds an SLA can set */
+  maxStalenessLimit: number
+  /** oracle max confidence interval used by SLAs that don't set their own */
+  defaultMaxConfidenceInterval: types.DslaDecimalFields
+  /** highest oracle max confidence interval an SLA can set */
+  maxConfidenceIntervalLimit: types.DslaDecimalFields
+  /**
+   * number of seconds after the end of a period during which every attester needs to attest its SLI,
+   * the attesters still missing after it count against the quorum
+   */
+  attestationWindow: number
+  /** number of seconds an SLI proposed with optimistic validation can be disputed */
+  disputeWindow: number
+  /** amount of dsla to be bonded by the validator proposing an SLI */
+  proposalBond: BN
+  /** amount of dsla to be bonded by the challenger disputing a proposed SLI */
+  disputeBond: BN
+  /** account settling the disputed SLIs */
+  arbiter: PublicKey
+  /** account receiving the protocol fees in its associated token accounts */
+  protocolTreasury: PublicKey
+  /** account managing the governance parameters */
+  governanceAuthority: PublicKey
+  /** account the governance authority is being transferred to, until it accepts it */
+  pendingGovernanceAuthority: PublicKey | null
+  /** mint of the DSLA token the deposits, rewards and bonds are paid in */
+  dslaMint: PublicKey
+  /** number of seconds after the end of an SLA before the deposit of its unverified periods can be reclaimed */
+  depositReclaimGracePeriod: number
+  /** who gets the reclaimed deposit of the unverified periods */
+  depositReclaimPolicy: types.DepositReclaimPolicyKind
+  /** percentage of the DSLA deposit paid to the protocol when an SLA is cancelled */
+  slaCancellationFeeRate: types.DslaDecimalFields
+  /** account that can pause and unpause the protocol and each SLA in an emergency */
+  guardian: PublicKey
+  /** whether staking and validation are paused for all SLAs */
+  paused: boolean
 }
 
 export interface GovernanceJSON {
+  /** layout version of the account, see `Governance::migrate` */
+  version: number
   /** amount of dsla to be deposited by the sla_deployer to deploy the sla for each period */
   dslaDepositByPeriod: string
   /** amount of dsla deposit by period to be given to the platform */
@@ -36,10 +79,51 @@ export interface GovernanceJSON {
   protocolRewardsRate: types.DslaDecimalJSON
   /** max leverage allowed in a DSLA */
   maxLeverage: types.DslaDecimalJSON
+  /** oracle max staleness in seconds used by SLAs that don't set their own */
+  defaultMaxStaleness: number
+  /** highest oracle max staleness in seconds an SLA can set */
+  maxStalenessLimit: number
+  /** oracle max confidence interval used by SLAs that don't set their own */
+  defaultMaxConfidenceInterval: types.DslaDecimalJSON
+  /** highest oracle max confidence interval an SLA can set */
+  maxConfidenceIntervalLimit: types.DslaDecimalJSON
+  /**
+   * number of seconds after the end of a period during which every attester needs to attest its SLI,
+   * the attesters still missing after it count against the quorum
+   */
+  attestationWindow: number
+  /** number of seconds an SLI proposed with optimistic validation can be disputed */
+  disputeWindow: number
+  /** amount of dsla to be bonded by the validator proposing an SLI */
+  proposalBond: string
+  /** amount of dsla to be bonded by the challenger disputing a proposed SLI */
+  disputeBond: string
+  /** account settling the disputed SLIs */
+  arbiter: string
+  /** account receiving the protocol fees in its associated token accounts */
+  protocolTreasury: string
+  /** account managing the governance parameters */
+  governanceAuthority: string
+  /** account the governance authority is being transferred to, until it accepts it */
+  pendingGovernanceAuthority: string | null
+  /** mint of the DSLA token the deposits, rewards and bonds are paid in */
+  dslaMint: string
+  /** number of seconds after the end of an SLA before the deposit of its unverified periods can be reclaimed */
+  depositReclaimGracePeriod: number
+  /** who gets the reclaimed deposit of the unverified periods */
+  depositReclaimPolicy: types.DepositReclaimPolicyJSON
+  /** percentage of the DSLA deposit paid to the protocol when an SLA is cancelled */
+  slaCancellationFeeRate: types.DslaDecimalJSON
+  /** account that can pause and unpause the protocol and each SLA in an emergency */
+  guardian: string
+  /** whether staking and validation are paused for all SLAs */
+  paused: boolean
 }
 
 /** collection for all the parametric Governances one account for all SLAs */
 export class Governance {
+  /** layout version of the account, see `Governance::migrate` */
+  readonly version: number
   /** amount of dsla to be deposited by the sla_deployer to deploy the sla for each period */
   readonly dslaDepositByPeriod: BN
   /** amount of dsla deposit by period to be given to the platform */
@@ -54,12 +138,52 @@ export class Governance {
   readonly protocolRewardsRate: types.DslaDecimal
   /** max leverage allowed in a DSLA */
   readonly maxLeverage: types.DslaDecimal
+  /** oracle max staleness in seconds used by SLAs that don't set their own */
+  readonly defaultMaxStaleness: number
+  /** highest oracle max staleness in seconds an SLA can set */
+  readonly maxStalenessLimit: number
+  /** oracle max confidence interval used by SLAs that don't set their own */
+  readonly defaultMaxConfidenceInterval: types.DslaDecimal
+  /** highest oracle max confidence interval an SLA can set */
+  readonly maxConfidenceIntervalLimit: types.DslaDecimal
+  /**
+   * number of seconds after the end of a period during which every attester needs to attest its SLI,
+   * the attesters still missing after it count against the quorum
+   */
+  readonly attestationWindow: number
+  /** number of seconds an SLI proposed with optimistic validation can be disputed */
+  readonly disputeWindow: number
+  /** amount of dsla to be bonded by the validator proposing an SLI */
+  readonly proposalBond: BN
+  /** amount of dsla to be bonded by the challenger disputing a proposed SLI */
+  readonly disputeBond: BN
+  /** account settling the disputed SLIs */
+  readonly arbiter: PublicKey
+  /** account receiving the protocol fees in its associated token accounts */
+  readonly protocolTreasury: PublicKey
+  /** account managing the governance parameters */
+  readonly governanceAuthority: PublicKey
+  /** account the governance authority is being transferred to, until it accepts it */
+  readonly pendingGovernanceAuthority: PublicKey | null
+  /** mint of the DSLA token the deposits, rewards and bonds are paid in */
+  readonly dslaMint: PublicKey
+  /** number of seconds after the end of an SLA before the deposit of its unverified periods can be reclaimed */
+  readonly depositReclaimGracePeriod: number
+  /** who gets the reclaimed deposit of the unverified periods */
+  readonly depositReclaimPolicy: types.DepositReclaimPolicyKind
+  /** percentage of the DSLA deposit paid to the protocol when an SLA is cancelled */
+  readonly slaCancellationFeeRate: types.DslaDecimal
+  /** account that can pause and unpause the protocol and each SLA in an emergency */
+  readonly guardian: PublicKey
+  /** whether staking and validation are paused for all SLAs */
+  readonly paused: boolean
 
   static readonly discriminator = Buffer.from([
     18, 143, 88, 13, 73, 217, 47, 49,
   ])
 
   static readonly layout = borsh.struct([
+    borsh.u8("version"),
     borsh.u64("dslaDepositByPeriod"),
     borsh.u64("dslaProtocolReward"),
     borsh.u64("dslaValidatorReward"),
@@ -67,9 +191,28 @@ export class Governance {
     types.DslaDecimal.layout("slaDeployerRewardsRate"),
     types.DslaDecimal.layout("protocolRewardsRate"),
     types.DslaDecimal.layout("maxLeverage"),
+    borsh.u32("defaultMaxStaleness"),
+    borsh.u32("maxStalenessLimit"),
+    types.DslaDecimal.layout("defaultMaxConfidenceInterval"),
+    types.DslaDecimal.layout("maxConfidenceIntervalLimit"),
+    borsh.u32("attestationWindow"),
+    borsh.u32("disputeWindow"),
+    borsh.u64("proposalBond"),
+    borsh.u64("disputeBond"),
+    borsh.publicKey("arbiter"),
+    borsh.publicKey("protocolTreasury"),
+    borsh.publicKey("governanceAuthority"),
+    borsh.option(borsh.publicKey(), "pendingGovernanceAuthority"),
+    borsh.publicKey("dslaMint"),
+    borsh.u32("depositReclaimGracePeriod"),
+    types.DepositReclaimPolicy.layout("depositReclaimPolicy"),
+    types.DslaDecimal.layout("slaCancellationFeeRate"),
+    borsh.publicKey("guardian"),
+    borsh.bool("paused"),
   ])
 
   constructor(fields: GovernanceFields) {
+    this.version = fields.version
     this.dslaDepositByPeriod = fields.dslaDepositByPeriod
     this.dslaProtocolReward = fields.dslaProtocolReward
     this.dslaValidatorReward = fields.dslaValidatorReward
@@ -81,6 +224,30 @@ export class Governance {
       ...fields.protocolRewardsRate,
     })
     this.maxLeverage = new types.DslaDecimal({ ...fields.maxLeverage })
+    this.defaultMaxStaleness = fields.defaultMaxStaleness
+    this.maxStalenessLimit = fields.maxStalenessLimit
+    this.defaultMaxConfidenceInterval = new types.DslaDecimal({
+      ...fields.defaultMaxConfidenceInterval,
+    })
+    this.maxConfidenceIntervalLimit = new types.DslaDecimal({
+      ...fields.maxConfidenceIntervalLimit,
+    })
+    this.attestationWindow = fields.attestationWindow
+    this.disputeWindow = fields.disputeWindow
+    this.proposalBond = fields.proposalBond
+    this.disputeBond = fields.disputeBond
+    this.arbiter = fields.arbiter
+    this.protocolTreasury = fields.protocolTreasury
+    this.governanceAuthority = fields.governanceAuthority
+    this.pendingGovernanceAuthority = fields.pendingGovernanceAuthority
+    this.dslaMint = fields.dslaMint
+    this.depositReclaimGracePeriod = fields.depositReclaimGracePeriod
+    this.depositReclaimPolicy = fields.depositReclaimPolicy
+    this.slaCancellationFeeRate = new types.DslaDecimal({
+      ...fields.slaCancellationFeeRate,
+    })
+    this.guardian = fields.guardian
+    this.paused = fields.paused
   }
 
   static async fetch(
@@ -125,6 +292,7 @@ export class Governance {
     const dec = Governance.layout.decode(data.slice(8))
 
     return new Governance({
+      version: dec.version,
       dslaDepositByPeriod: dec.dslaDepositByPeriod,
       dslaProtocolReward: dec.dslaProtocolReward,
       dslaValidatorReward: dec.dslaValidatorReward,
@@ -136,11 +304,38 @@ export class Governance {
         dec.protocolRewardsRate
       ),
       maxLeverage: types.DslaDecimal.fromDecoded(dec.maxLeverage),
+      defaultMaxStaleness: dec.defaultMaxStaleness,
+      maxStalenessLimit: dec.maxStalenessLimit,
+      defaultMaxConfidenceInterval: types.DslaDecimal.fromDecoded(
+        dec.defaultMaxConfidenceInterval
+      ),
+      maxConfidenceIntervalLimit: types.DslaDecimal.fromDecoded(
+        dec.maxConfidenceIntervalLimit
+      ),
+      attestationWindow: dec.attestationWindow,
+      disputeWindow: dec.disputeWindow,
+      proposalBond: dec.proposalBond,
+      disputeBond: dec.disputeBond,
+      arbiter: dec.arbiter,
+      protocolTreasury: dec.protocolTreasury,
+      governanceAuthority: dec.governanceAuthority,
+      pendingGovernanceAuthority: dec.pendingGovernanceAuthority,
+      dslaMint: dec.dslaMint,
+      depositReclaimGracePeriod: dec.depositReclaimGracePeriod,
+      depositReclaimPolicy: types.DepositReclaimPolicy.fromDecoded(
+        dec.depositReclaimPolicy
+      ),
+      slaCancellationFeeRate: types.DslaDecimal.fromDecoded(
+        dec.slaCancellationFeeRate
+      ),
+      guardian: dec.guardian,
+      paused: dec.paused,
     })
   }
 
   toJSON(): GovernanceJSON {
     return {
+      version: this.version,
       dslaDepositByPeriod: this.dslaDepositByPeriod.toString(),
       dslaProtocolReward: this.dslaProtocolReward.toString(),
       dslaValidatorReward: this.dslaValidatorReward.toString(),
@@ -148,11 +343,33 @@ export class Governance {
       slaDeployerRewardsRate: this.slaDeployerRewardsRate.toJSON(),
       protocolRewardsRate: this.protocolRewardsRate.toJSON(),
       maxLeverage: this.maxLeverage.toJSON(),
+      defaultMaxStaleness: this.defaultMaxStaleness,
+      maxStalenessLimit: this.maxStalenessLimit,
+      defaultMaxConfidenceInterval: this.defaultMaxConfidenceInterval.toJSON(),
+      maxConfidenceIntervalLimit: this.maxConfidenceIntervalLimit.toJSON(),
+      attestationWindow: this.attestationWindow,
+      disputeWindow: this.disputeWindow,
+      proposalBond: this.proposalBond.toString(),
+      disputeBond: this.disputeBond.toString(),
+      arbiter: this.arbiter.toString(),
+      protocolTreasury: this.protocolTreasury.toString(),
+      governanceAuthority: this.governanceAuthority.toString(),
+      pendingGovernanceAuthority:
+        (this.pendingGovernanceAuthority &&
+          this.pendingGovernanceAuthority.toString()) ||
+        null,
+      dslaMint: this.dslaMint.toString(),
+      depositReclaimGracePeriod: this.depositReclaimGracePeriod,
+      depositReclaimPolicy: this.depositReclaimPolicy.toJSON(),
+      slaCancellationFeeRate: this.slaCancellationFeeRate.toJSON(),
+      guardian: this.guardian.toString(),
+      paused: this.paused,
     }
   }
 
   static fromJSON(obj: GovernanceJSON): Governance {
     return new Governance({
+      version: obj.version,
       dslaDepositByPeriod: new BN(obj.dslaDepositByPeriod),
       dslaProtocolReward: new BN(obj.dslaProtocolReward),
       dslaValidatorReward: new BN(obj.dslaValidatorReward),
@@ -162,6 +379,35 @@ export class Governance {
       ),
       protocolRewardsRate: types.DslaDecimal.fromJSON(obj.protocolRewardsRate),
       maxLeverage: types.DslaDecimal.fromJSON(obj.maxLeverage),
+      defaultMaxStaleness: obj.defaultMaxStaleness,
+      maxStalenessLimit: obj.maxStalenessLimit,
+      defaultMaxConfidenceInterval: types.DslaDecimal.fromJSON(
+        obj.defaultMaxConfidenceInterval
+      ),
+      maxConfidenceIntervalLimit: types.DslaDecimal.fromJSON(
+        obj.maxConfidenceIntervalLimit
+      ),
+      attestationWindow: obj.attestationWindow,
+      disputeWindow: obj.disputeWindow,
+      proposalBond: new BN(obj.proposalBond),
+      disputeBond: new BN(obj.disputeBond),
+      arbiter: new PublicKey(obj.arbiter),
+      protocolTreasury: new PublicKey(obj.protocolTreasury),
+      governanceAuthority: new PublicKey(obj.governanceAuthority),
+      pendingGovernanceAuthority:
+        (obj.pendingGovernanceAuthority &&
+          new PublicKey(obj.pendingGovernanceAuthority)) ||
+        null,
+      dslaMint: new PublicKey(obj.dslaMint),
+      depositReclaimGracePeriod: obj.depositReclaimGracePeriod,
+      depositReclaimPolicy: types.DepositReclaimPolicy.fromJSON(
+        obj.depositReclaimPolicy
+      ),
+      slaCancellationFeeRate: types.DslaDecimal.fromJSON(
+        obj.slaCancellationFeeRate
+      ),
+      guardian: new PublicKey(obj.guardian),
+      paused: obj.paused,
     })
   }
 }
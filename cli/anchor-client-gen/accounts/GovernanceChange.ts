@@ -0,0 +1,124 @@
+import { PublicKey, Connection } from "@solana/web3.js"
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@project-serum/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface GovernanceChangeFields {
+  /** account that proposed the change and paid for this account */
+  proposer: PublicKey
+  /** the new parameters */
+  parameters: types.GovernanceParametersFields
+  /** unix timestamp the change was proposed at */
+  proposedAt: BN
+  /** first unix timestamp the change can be executed at */
+  executableAt: BN
+}
+
+export interface GovernanceChangeJSON {
+  /** account that proposed the change and paid for this account */
+  proposer: string
+  /** the new parameters */
+  parameters: types.GovernanceParametersJSON
+  /** unix timestamp the change was proposed at */
+  proposedAt: string
+  /** first unix timestamp the change can be executed at */
+  executableAt: string
+}
+
+/** change of the `Governance` parameters waiting for its timelock, only one change can be pending at a time */
+export class GovernanceChange {
+  /** account that proposed the change and paid for this account */
+  readonly proposer: PublicKey
+  /** the new parameters */
+  readonly parameters: types.GovernanceParameters
+  /** unix timestamp the change was proposed at */
+  readonly proposedAt: BN
+  /** first unix timestamp the change can be executed at */
+  readonly executableAt: BN
+
+  static readonly discriminator = Buffer.from([
+    128, 7, 83, 14, 255, 93, 211, 44,
+  ])
+
+  static readonly layout = borsh.struct([
+    borsh.publicKey("proposer"),
+    types.GovernanceParameters.layout("parameters"),
+    borsh.i64("proposedAt"),
+    borsh.i64("executableAt"),
+  ])
+
+  constructor(fields: GovernanceChangeFields) {
+    this.proposer = fields.proposer
+    this.parameters = new types.GovernanceParameters({ ...fields.parameters })
+    this.proposedAt = fields.proposedAt
+    this.executableAt = fields.executableAt
+  }
+
+  static async fetch(
+    c: Connection,
+    address: PublicKey
+  ): Promise<GovernanceChange | null> {
+    const info = await c.getAccountInfo(address)
+
+    if (info === null) {
+      return null
+    }
+    if (!info.owner.equals(PROGRAM_ID)) {
+      throw new Error("account doesn't belong to this program")
+    }
+
+    return this.decode(info.data)
+  }
+
+  static async fetchMultiple(
+    c: Connection,
+    addresses: PublicKey[]
+  ): Promise<Array<GovernanceChange | null>> {
+    const infos = await c.getMultipleAccountsInfo(addresses)
+
+    return infos.map((info) => {
+      if (info === null) {
+        return null
+      }
+      if (!info.owner.equals(PROGRAM_ID)) {
+        throw new Error("account doesn't belong to this program")
+      }
+
+      return this.decode(info.data)
+    })
+  }
+
+  static decode(data: Buffer): GovernanceChange {
+    if (!data.slice(0, 8).equals(GovernanceChange.discriminator)) {
+      throw new Error("invalid account discriminator")
+    }
+
+    const dec = GovernanceChange.layout.decode(data.slice(8))
+
+    return new GovernanceChange({
+      proposer: dec.proposer,
+      parameters: types.GovernanceParameters.fromDecoded(dec.parameters),
+      proposedAt: dec.proposedAt,
+      executableAt: dec.executableAt,
+    })
+  }
+
+  toJSON(): GovernanceChangeJSON {
+    return {
+      proposer: this.proposer.toString(),
+      parameters: this.parameters.toJSON(),
+      proposedAt: this.proposedAt.toString(),
+      executableAt: this.executableAt.toString(),
+    }
+  }
+
+  static fromJSON(obj: GovernanceChangeJSON): GovernanceChange {
+    return new GovernanceChange({
+      proposer: new PublicKey(obj.proposer),
+      parameters: types.GovernanceParameters.fromJSON(obj.parameters),
+      proposedAt: new BN(obj.proposedAt),
+      executableAt: new BN(obj.executableAt),
+    })
+  }
+}
@@ -0,0 +1,172 @@
+import { PublicKey, Connection } from "@solana/web3.js"
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@project-serum/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface PeriodCheckpointFields {
+  /** account that paid the rent of the checkpoint, it gets it back with `close_checkpoint` */
+  payer: PublicKey
+  /** the checkpointed period */
+  period: BN
+  /** amount of tokens in the provider pool when the period was checkpointed */
+  providerPoolSize: BN
+  /** amount of tokens in the user pool when the period was checkpointed */
+  userPoolSize: BN
+  /** user token supply when the period was checkpointed */
+  utSupply: BN
+  /** provider token supply when the period was checkpointed */
+  ptSupply: BN
+  /**
+   * max amount of tokens the settlement of the period can move between the pools,
+   * 0 if it was already settled when it was checkpointed
+   */
+  maxTransfer: BN
+}
+
+export interface PeriodCheckpointJSON {
+  /** account that paid the rent of the checkpoint, it gets it back with `close_checkpoint` */
+  payer: string
+  /** the checkpointed period */
+  period: string
+  /** amount of tokens in the provider pool when the period was checkpointed */
+  providerPoolSize: string
+  /** amount of tokens in the user pool when the period was checkpointed */
+  userPoolSize: string
+  /** user token supply when the period was checkpointed */
+  utSupply: string
+  /** provider token supply when the period was checkpointed */
+  ptSupply: string
+  /**
+   * max amount of tokens the settlement of the period can move between the pools,
+   * 0 if it was already settled when it was checkpointed
+   */
+  maxTransfer: string
+}
+
+/**
+ * snapshot of the pools and token supplies of an SLA when one of its periods finished,
+ * the UT and PT held then were the ones exposed to the period, see `checkpoint_period`
+ *
+ * the period is settled from the user pool of its checkpoint, and until then the stakes and withdrawals
+ * are priced as if it moved its `max_transfer` against them, see `Sla::pending_transfer`
+ */
+export class PeriodCheckpoint {
+  /** account that paid the rent of the checkpoint, it gets it back with `close_checkpoint` */
+  readonly payer: PublicKey
+  /** the checkpointed period */
+  readonly period: BN
+  /** amount of tokens in the provider pool when the period was checkpointed */
+  readonly providerPoolSize: BN
+  /** amount of tokens in the user pool when the period was checkpointed */
+  readonly userPoolSize: BN
+  /** user token supply when the period was checkpointed */
+  readonly utSupply: BN
+  /** provider token supply when the period was checkpointed */
+  readonly ptSupply: BN
+  /**
+   * max amount of tokens the settlement of the period can move between the pools,
+   * 0 if it was already settled when it was checkpointed
+   */
+  readonly maxTransfer: BN
+
+  static readonly discriminator = Buffer.from([
+    13, 150, 164, 56, 14, 182, 165, 98,
+  ])
+
+  static readonly layout = borsh.struct([
+    borsh.publicKey("payer"),
+    borsh.u64("period"),
+    borsh.u128("providerPoolSize"),
+    borsh.u128("userPoolSize"),
+    borsh.u128("utSupply"),
+    borsh.u128("ptSupply"),
+    borsh.u128("maxTransfer"),
+  ])
+
+  constructor(fields: PeriodCheckpointFields) {
+    this.payer = fields.payer
+    this.period = fields.period
+    this.providerPoolSize = fields.providerPoolSize
+    this.userPoolSize = fields.userPoolSize
+    this.utSupply = fields.utSupply
+    this.ptSupply = fields.ptSupply
+    this.maxTransfer = fields.maxTransfer
+  }
+
+  static async fetch(
+    c: Connection,
+    address: PublicKey
+  ): Promise<PeriodCheckpoint | null> {
+    const info = await c.getAccountInfo(address)
+
+    if (info === null) {
+      return null
+    }
+    if (!info.owner.equals(PROGRAM_ID)) {
+      throw new Error("account doesn't belong to this program")
+    }
+
+    return this.decode(info.data)
+  }
+
+  static async fetchMultiple(
+    c: Connection,
+    addresses: PublicKey[]
+  ): Promise<Array<PeriodCheckpoint | null>> {
+    const infos = await c.getMultipleAccountsInfo(addresses)
+
+    return infos.map((info) => {
+      if (info === null) {
+        return null
+      }
+      if (!info.owner.equals(PROGRAM_ID)) {
+        throw new Error("account doesn't belong to this program")
+      }
+
+      return this.decode(info.data)
+    })
+  }
+
+  static decode(data: Buffer): PeriodCheckpoint {
+    if (!data.slice(0, 8).equals(PeriodCheckpoint.discriminator)) {
+      throw new Error("invalid account discriminator")
+    }
+
+    const dec = PeriodCheckpoint.layout.decode(data.slice(8))
+
+    return new PeriodCheckpoint({
+      payer: dec.payer,
+      period: dec.period,
+      providerPoolSize: dec.providerPoolSize,
+      userPoolSize: dec.userPoolSize,
+      utSupply: dec.utSupply,
+      ptSupply: dec.ptSupply,
+      maxTransfer: dec.maxTransfer,
+    })
+  }
+
+  toJSON(): PeriodCheckpointJSON {
+    return {
+      payer: this.payer.toString(),
+      period: this.period.toString(),
+      providerPoolSize: this.providerPoolSize.toString(),
+      userPoolSize: this.userPoolSize.toString(),
+      utSupply: this.utSupply.toString(),
+      ptSupply: this.ptSupply.toString(),
+      maxTransfer: this.maxTransfer.toString(),
+    }
+  }
+
+  static fromJSON(obj: PeriodCheckpointJSON): PeriodCheckpoint {
+    return new PeriodCheckpoint({
+      payer: new PublicKey(obj.payer),
+      period: new BN(obj.period),
+      providerPoolSize: new BN(obj.providerPoolSize),
+      userPoolSize: new BN(obj.userPoolSize),
+      utSupply: new BN(obj.utSupply),
+      ptSupply: new BN(obj.ptSupply),
+      maxTransfer: new BN(obj.maxTransfer),
+    })
+  }
+}
@@ -5,10 +5,22 @@ import * as types from "../types" // eslint-disable-line @typescript-eslint/no-u
 import { PROGRAM_ID } from "../programId"
 
 export interface SlaFields {
+  /** layout version of the account, see `Sla::migrate` */
+  version: number
   /** address of who deployed the SLA */
   slaDeployerAddress: PublicKey
-  /** address of the switchboard aggregator account */
-  aggregatorAddress: PublicKey
+  /** oracle network of the feeds */
+  oracleSource: types.OracleSourceKind
+  /** addresses of the oracle feed accounts, max of 8 */
+  oracleAddresses: Array<PublicKey>
+  /** how the results of the oracle feeds are combined into the SLI */
+  aggregationPolicy: types.AggregationPolicyKind
+  /** minimum number of fresh oracle feeds needed to validate a period */
+  minQuorum: number
+  /** max number of seconds since the last update of an oracle feed for it to be fresh */
+  maxStaleness: number
+  /** max confidence interval of the result of an oracle feed for it to be fresh */
+  maxConfidenceInterval: types.DslaDecimalFields
   /** service level objective, the objective to achieve for the provider to be rewarded */
   slo: types.SloFields
   /** leverage for the SLA between provider and user pool */
@@ -25,13 +37,40 @@ export interface SlaFields {
   utSupply: BN
   /** total provider token supply */
   ptSupply: BN
+  /** range of severity max of 10; */
+  severity: Array<types.DslaDecimalFields>
+  /** range of penalty max of 10; */
+  penalty: Array<types.DslaDecimalFields>
+  /** whether the deployer cancelled the SLA before it started */
+  cancelled: boolean
+  /** whether the guardian paused staking and validation of the SLA */
+  paused: boolean
+  /** number of periods with a `PeriodCheckpoint`, the finished periods are checkpointed in order */
+  checkpointedPeriods: number
+  /**
+   * max amount of tokens the checkpointed periods that are not settled yet can still move between the pools,
+   * the stakes and withdrawals are priced as if they did, see `quote`
+   */
+  pendingTransfer: BN
 }
 
 export interface SlaJSON {
+  /** layout version of the account, see `Sla::migrate` */
+  version: number
   /** address of who deployed the SLA */
   slaDeployerAddress: string
-  /** address of the switchboard aggregator account */
-  aggregatorAddress: string
+  /** oracle network of the feeds */
+  oracleSource: types.OracleSourceJSON
+  /** addresses of the oracle feed accounts, max of 8 */
+  oracleAddresses: Array<string>
+  /** how the results of the oracle feeds are combined into the SLI */
+  aggregationPolicy: types.AggregationPolicyJSON
+  /** minimum number of fresh oracle feeds needed to validate a period */
+  minQuorum: number
+  /** max number of seconds since the last update of an oracle feed for it to be fresh */
+  maxStaleness: number
+  /** max confidence interval of the result of an oracle feed for it to be fresh */
+  maxConfidenceInterval: types.DslaDecimalJSON
   /** service level objective, the objective to achieve for the provider to be rewarded */
   slo: types.SloJSON
   /** leverage for the SLA between provider and user pool */
@@ -48,14 +87,41 @@ export interface SlaJSON {
   utSupply: string
   /** total provider token supply */
   ptSupply: string
+  /** range of severity max of 10; */
+  severity: Array<types.DslaDecimalJSON>
+  /** range of penalty max of 10; */
+  penalty: Array<types.DslaDecimalJSON>
+  /** whether the deployer cancelled the SLA before it started */
+  cancelled: boolean
+  /** whether the guardian paused staking and validation of the SLA */
+  paused: boolean
+  /** number of periods with a `PeriodCheckpoint`, the finished periods are checkpointed in order */
+  checkpointedPeriods: number
+  /**
+   * max amount of tokens the checkpointed periods that are not settled yet can still move between the pools,
+   * the stakes and withdrawals are priced as if they did, see `quote`
+   */
+  pendingTransfer: string
 }
 
 /** `Sla` is Service level agreement account containing all the variables to make it possible */
 export class Sla {
+  /** layout version of the account, see `Sla::migrate` */
+  readonly version: number
   /** address of who deployed the SLA */
   readonly slaDeployerAddress: PublicKey
-  /** address of the switchboard aggregator account */
-  readonly aggregatorAddress: PublicKey
+  /** oracle network of the feeds */
+  readonly oracleSource: types.OracleSourceKind
+  /** addresses of the oracle feed accounts, max of 8 */
+  readonly oracleAddresses: Array<PublicKey>
+  /** how the results of the oracle feeds are combined into the SLI */
+  readonly aggregationPolicy: types.AggregationPolicyKind
+  /** minimum number of fresh oracle feeds needed to validate a period */
+  readonly minQuorum: number
+  /** max number of seconds since the last update of an oracle feed for it to be fresh */
+  readonly maxStaleness: number
+  /** max confidence interval of the result of an oracle feed for it to be fresh */
+  readonly maxConfidenceInterval: types.DslaDecimal
   /** service level objective, the objective to achieve for the provider to be rewarded */
   readonly slo: types.Slo
   /** leverage for the SLA between provider and user pool */
@@ -72,14 +138,35 @@ export class Sla {
   readonly utSupply: BN
   /** total provider token supply */
   readonly ptSupply: BN
+  /** range of severity max of 10; */
+  readonly severity: Array<types.DslaDecimal>
+  /** range of penalty max of 10; */
+  readonly penalty: Array<types.DslaDecimal>
+  /** whether the deployer cancelled the SLA before it started */
+  readonly cancelled: boolean
+  /** whether the guardian paused staking and validation of the SLA */
+  readonly paused: boolean
+  /** number of periods with a `PeriodCheckpoint`, the finished periods are checkpointed in order */
+  readonly checkpointedPeriods: number
+  /**
+   * max amount of tokens the checkpointed periods that are not settled yet can still move between the pools,
+   * the stakes and withdrawals are priced as if they did, see `quote`
+   */
+  readonly pendingTransfer: BN
 
   static readonly discriminator = Buffer.from([
     93, 177, 43, 102, 221, 228, 221, 169,
   ])
 
   static readonly layout = borsh.struct([
+    borsh.u8("version"),
     borsh.publicKey("slaDeployerAddress"),
-    borsh.publicKey("aggregatorAddress"),
+    types.OracleSource.layout("oracleSource"),
+    borsh.vec(borsh.publicKey(), "oracleAddresses"),
+    types.AggregationPolicy.layout("aggregationPolicy"),
+    borsh.u8("minQuorum"),
+    borsh.u32("maxStaleness"),
+    types.DslaDecimal.layout("maxConfidenceInterval"),
     types.Slo.layout("slo"),
     types.DslaDecimal.layout("leverage"),
     borsh.publicKey("mintAddress"),
@@ -88,11 +175,25 @@ export class Sla {
     borsh.u128("userPoolSize"),
     borsh.u128("utSupply"),
     borsh.u128("ptSupply"),
+    borsh.vec(types.DslaDecimal.layout(), "severity"),
+    borsh.vec(types.DslaDecimal.layout(), "penalty"),
+    borsh.bool("cancelled"),
+    borsh.bool("paused"),
+    borsh.u32("checkpointedPeriods"),
+    borsh.u128("pendingTransfer"),
   ])
 
   constructor(fields: SlaFields) {
+    this.version = fields.version
     this.slaDeployerAddress = fields.slaDeployerAddress
-    this.aggregatorAddress = fields.aggregatorAddress
+    this.oracleSource = fields.oracleSource
+    this.oracleAddresses = fields.oracleAddresses
+    this.aggregationPolicy = fields.aggregationPolicy
+    this.minQuorum = fields.minQuorum
+    this.maxStaleness = fields.maxStaleness
+    this.maxConfidenceInterval = new types.DslaDecimal({
+      ...fields.maxConfidenceInterval,
+    })
     this.slo = new types.Slo({ ...fields.slo })
     this.leverage = new types.DslaDecimal({ ...fields.leverage })
     this.mintAddress = fields.mintAddress
@@ -101,6 +202,16 @@ export class Sla {
     this.userPoolSize = fields.userPoolSize
     this.utSupply = fields.utSupply
     this.ptSupply = fields.ptSupply
+    this.severity = fields.severity.map(
+      (item) => new types.DslaDecimal({ ...item })
+    )
+    this.penalty = fields.penalty.map(
+      (item) => new types.DslaDecimal({ ...item })
+    )
+    this.cancelled = fields.cancelled
+    this.paused = fields.paused
+    this.checkpointedPeriods = fields.checkpointedPeriods
+    this.pendingTransfer = fields.pendingTransfer
   }
 
   static async fetch(c: Connection, address: PublicKey): Promise<Sla | null> {
@@ -142,8 +253,18 @@ export class Sla {
     const dec = Sla.layout.decode(data.slice(8))
 
     return new Sla({
+      version: dec.version,
       slaDeployerAddress: dec.slaDeployerAddress,
-      aggregatorAddress: dec.aggregatorAddress,
+      oracleSource: types.OracleSource.fromDecoded(dec.oracleSource),
+      oracleAddresses: dec.oracleAddresses,
+      aggregationPolicy: types.AggregationPolicy.fromDecoded(
+        dec.aggregationPolicy
+      ),
+      minQuorum: dec.minQuorum,
+      maxStaleness: dec.maxStaleness,
+      maxConfidenceInterval: types.DslaDecimal.fromDecoded(
+        dec.maxConfidenceInterval
+      ),
       slo: types.Slo.fromDecoded(dec.slo),
       leverage: types.DslaDecimal.fromDecoded(dec.leverage),
       mintAddress: dec.mintAddress,
@@ -152,13 +273,33 @@ export class Sla {
       userPoolSize: dec.userPoolSize,
       utSupply: dec.utSupply,
       ptSupply: dec.ptSupply,
+      severity: dec.severity.map(
+        (
+          item: any /* eslint-disable-line @typescript-eslint/no-explicit-any */
+        ) => types.DslaDecimal.fromDecoded(item)
+      ),
+      penalty: dec.penalty.map(
+        (
+          item: any /* eslint-disable-line @typescript-eslint/no-explicit-any */
+        ) => types.DslaDecimal.fromDecoded(item)
+      ),
+      cancelled: dec.cancelled,
+      paused: dec.paused,
+      checkpointedPeriods: dec.checkpointedPeriods,
+      pendingTransfer: dec.pendingTransfer,
     })
   }
 
   toJSON(): SlaJSON {
     return {
+      version: this.version,
       slaDeployerAddress: this.slaDeployerAddress.toString(),
-      aggregatorAddress: this.aggregatorAddress.toString(),
+      oracleSource: this.oracleSource.toJSON(),
+      oracleAddresses: this.oracleAddresses.map((item) => item.toString()),
+      aggregationPolicy: this.aggregationPolicy.toJSON(),
+      minQuorum: this.minQuorum,
+      maxStaleness: this.maxStaleness,
+      maxConfidenceInterval: this.maxConfidenceInterval.toJSON(),
       slo: this.slo.toJSON(),
       leverage: this.leverage.toJSON(),
       mintAddress: this.mintAddress.toString(),
@@ -167,13 +308,29 @@ export class Sla {
       userPoolSize: this.userPoolSize.toString(),
       utSupply: this.utSupply.toString(),
       ptSupply: this.ptSupply.toString(),
+      severity: this.severity.map((item) => item.toJSON()),
+      penalty: this.penalty.map((item) => item.toJSON()),
+      cancelled: this.cancelled,
+      paused: this.paused,
+      checkpointedPeriods: this.checkpointedPeriods,
+      pendingTransfer: this.pendingTransfer.toString(),
     }
   }
 
   static fromJSON(obj: SlaJSON): Sla {
     return new Sla({
+      version: obj.version,
       slaDeployerAddress: new PublicKey(obj.slaDeployerAddress),
-      aggregatorAddress: new PublicKey(obj.aggregatorAddress),
+      oracleSource: types.OracleSource.fromJSON(obj.oracleSource),
+      oracleAddresses: obj.oracleAddresses.map((item) => new PublicKey(item)),
+      aggregationPolicy: types.AggregationPolicy.fromJSON(
+        obj.aggregationPolicy
+      ),
+      minQuorum: obj.minQuorum,
+      maxStaleness: obj.maxStaleness,
+      maxConfidenceInterval: types.DslaDecimal.fromJSON(
+        obj.maxConfidenceInterval
+      ),
       slo: types.Slo.fromJSON(obj.slo),
       leverage: types.DslaDecimal.fromJSON(obj.leverage),
       mintAddress: new PublicKey(obj.mintAddress),
@@ -182,6 +339,12 @@ export class Sla {
       userPoolSize: new BN(obj.userPoolSize),
       utSupply: new BN(obj.utSupply),
       ptSupply: new BN(obj.ptSupply),
+      severity: obj.severity.map((item) => types.DslaDecimal.fromJSON(item)),
+      penalty: obj.penalty.map((item) => types.DslaDecimal.fromJSON(item)),
+      cancelled: obj.cancelled,
+      paused: obj.paused,
+      checkpointedPeriods: obj.checkpointedPeriods,
+      pendingTransfer: new BN(obj.pendingTransfer),
     })
   }
 }
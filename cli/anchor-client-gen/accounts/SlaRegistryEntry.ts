@@ -0,0 +1,113 @@
+import { PublicKey, Connection } from "@solana/web3.js"
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@project-serum/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface SlaRegistryEntryFields {
+  /** sequential id of the SLA in the global `SlaRegistryHeader` */
+  slaId: BN
+  /** id of the SLA in the index of its deployer */
+  deployerIndexId: BN
+  /** id of the SLA in the index of its mint */
+  mintIndexId: BN
+}
+
+export interface SlaRegistryEntryJSON {
+  /** sequential id of the SLA in the global `SlaRegistryHeader` */
+  slaId: string
+  /** id of the SLA in the index of its deployer */
+  deployerIndexId: string
+  /** id of the SLA in the index of its mint */
+  mintIndexId: string
+}
+
+/** marker of a registered SLA, its address is derived from the SLA address */
+export class SlaRegistryEntry {
+  /** sequential id of the SLA in the global `SlaRegistryHeader` */
+  readonly slaId: BN
+  /** id of the SLA in the index of its deployer */
+  readonly deployerIndexId: BN
+  /** id of the SLA in the index of its mint */
+  readonly mintIndexId: BN
+
+  static readonly discriminator = Buffer.from([
+    194, 150, 44, 27, 45, 207, 63, 187,
+  ])
+
+  static readonly layout = borsh.struct([
+    borsh.u64("slaId"),
+    borsh.u64("deployerIndexId"),
+    borsh.u64("mintIndexId"),
+  ])
+
+  constructor(fields: SlaRegistryEntryFields) {
+    this.slaId = fields.slaId
+    this.deployerIndexId = fields.deployerIndexId
+    this.mintIndexId = fields.mintIndexId
+  }
+
+  static async fetch(
+    c: Connection,
+    address: PublicKey
+  ): Promise<SlaRegistryEntry | null> {
+    const info = await c.getAccountInfo(address)
+
+    if (info === null) {
+      return null
+    }
+    if (!info.owner.equals(PROGRAM_ID)) {
+      throw new Error("account doesn't belong to this program")
+    }
+
+    return this.decode(info.data)
+  }
+
+  static async fetchMultiple(
+    c: Connection,
+    addresses: PublicKey[]
+  ): Promise<Array<SlaRegistryEntry | null>> {
+    const infos = await c.getMultipleAccountsInfo(addresses)
+
+    return infos.map((info) => {
+      if (info === null) {
+        return null
+      }
+      if (!info.owner.equals(PROGRAM_ID)) {
+        throw new Error("account doesn't belong to this program")
+      }
+
+      return this.decode(info.data)
+    })
+  }
+
+  static decode(data: Buffer): SlaRegistryEntry {
+    if (!data.slice(0, 8).equals(SlaRegistryEntry.discriminator)) {
+      throw new Error("invalid account discriminator")
+    }
+
+    const dec = SlaRegistryEntry.layout.decode(data.slice(8))
+
+    return new SlaRegistryEntry({
+      slaId: dec.slaId,
+      deployerIndexId: dec.deployerIndexId,
+      mintIndexId: dec.mintIndexId,
+    })
+  }
+
+  toJSON(): SlaRegistryEntryJSON {
+    return {
+      slaId: this.slaId.toString(),
+      deployerIndexId: this.deployerIndexId.toString(),
+      mintIndexId: this.mintIndexId.toString(),
+    }
+  }
+
+  static fromJSON(obj: SlaRegistryEntryJSON): SlaRegistryEntry {
+    return new SlaRegistryEntry({
+      slaId: new BN(obj.slaId),
+      deployerIndexId: new BN(obj.deployerIndexId),
+      mintIndexId: new BN(obj.mintIndexId),
+    })
+  }
+}
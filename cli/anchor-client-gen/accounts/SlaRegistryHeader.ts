@@ -0,0 +1,117 @@
+import { PublicKey, Connection } from "@solana/web3.js"
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@project-serum/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface SlaRegistryHeaderFields {
+  /** number of SLAs registered, the id of the next SLA */
+  slaCount: BN
+  /** number of pages created */
+  pageCount: BN
+  /** number of SLAs stored in each page */
+  pageSize: number
+}
+
+export interface SlaRegistryHeaderJSON {
+  /** number of SLAs registered, the id of the next SLA */
+  slaCount: string
+  /** number of pages created */
+  pageCount: string
+  /** number of SLAs stored in each page */
+  pageSize: number
+}
+
+/**
+ * the `SlaRegistryHeader` is the header of a registry of SLAs, the global registry of all SLAs
+ * or the index of the SLAs of a deployer or a mint,
+ * the public keys of the SLAs are stored by id in fixed size `SlaRegistryPage` accounts
+ */
+export class SlaRegistryHeader {
+  /** number of SLAs registered, the id of the next SLA */
+  readonly slaCount: BN
+  /** number of pages created */
+  readonly pageCount: BN
+  /** number of SLAs stored in each page */
+  readonly pageSize: number
+
+  static readonly discriminator = Buffer.from([
+    39, 209, 88, 41, 81, 180, 139, 94,
+  ])
+
+  static readonly layout = borsh.struct([
+    borsh.u64("slaCount"),
+    borsh.u64("pageCount"),
+    borsh.u32("pageSize"),
+  ])
+
+  constructor(fields: SlaRegistryHeaderFields) {
+    this.slaCount = fields.slaCount
+    this.pageCount = fields.pageCount
+    this.pageSize = fields.pageSize
+  }
+
+  static async fetch(
+    c: Connection,
+    address: PublicKey
+  ): Promise<SlaRegistryHeader | null> {
+    const info = await c.getAccountInfo(address)
+
+    if (info === null) {
+      return null
+    }
+    if (!info.owner.equals(PROGRAM_ID)) {
+      throw new Error("account doesn't belong to this program")
+    }
+
+    return this.decode(info.data)
+  }
+
+  static async fetchMultiple(
+    c: Connection,
+    addresses: PublicKey[]
+  ): Promise<Array<SlaRegistryHeader | null>> {
+    const infos = await c.getMultipleAccountsInfo(addresses)
+
+    return infos.map((info) => {
+      if (info === null) {
+        return null
+      }
+      if (!info.owner.equals(PROGRAM_ID)) {
+        throw new Error("account doesn't belong to this program")
+      }
+
+      return this.decode(info.data)
+    })
+  }
+
+  static decode(data: Buffer): SlaRegistryHeader {
+    if (!data.slice(0, 8).equals(SlaRegistryHeader.discriminator)) {
+      throw new Error("invalid account discriminator")
+    }
+
+    const dec = SlaRegistryHeader.layout.decode(data.slice(8))
+
+    return new SlaRegistryHeader({
+      slaCount: dec.slaCount,
+      pageCount: dec.pageCount,
+      pageSize: dec.pageSize,
+    })
+  }
+
+  toJSON(): SlaRegistryHeaderJSON {
+    return {
+      slaCount: this.slaCount.toString(),
+      pageCount: this.pageCount.toString(),
+      pageSize: this.pageSize,
+    }
+  }
+
+  static fromJSON(obj: SlaRegistryHeaderJSON): SlaRegistryHeader {
+    return new SlaRegistryHeader({
+      slaCount: new BN(obj.slaCount),
+      pageCount: new BN(obj.pageCount),
+      pageSize: obj.pageSize,
+    })
+  }
+}
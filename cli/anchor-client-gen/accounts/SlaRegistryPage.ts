@@ -4,34 +4,34 @@ import * as borsh from "@project-serum/borsh" // eslint-disable-line @typescript
 import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
 import { PROGRAM_ID } from "../programId"
 
-export interface SlaRegistryFields {
+export interface SlaRegistryPageFields {
   slaAccountAddresses: Array<PublicKey>
 }
 
-export interface SlaRegistryJSON {
+export interface SlaRegistryPageJSON {
   slaAccountAddresses: Array<string>
 }
 
-/** the `SlaRegistry` is an account with a vector containing the public keys for all SLAs */
-export class SlaRegistry {
+/** page of an `SlaRegistryHeader` with the public keys of `page_size` consecutive SLA ids */
+export class SlaRegistryPage {
   readonly slaAccountAddresses: Array<PublicKey>
 
   static readonly discriminator = Buffer.from([
-    95, 29, 91, 241, 143, 43, 156, 245,
+    10, 80, 24, 249, 199, 152, 148, 253,
   ])
 
   static readonly layout = borsh.struct([
     borsh.vec(borsh.publicKey(), "slaAccountAddresses"),
   ])
 
-  constructor(fields: SlaRegistryFields) {
+  constructor(fields: SlaRegistryPageFields) {
     this.slaAccountAddresses = fields.slaAccountAddresses
   }
 
   static async fetch(
     c: Connection,
     address: PublicKey
-  ): Promise<SlaRegistry | null> {
+  ): Promise<SlaRegistryPage | null> {
     const info = await c.getAccountInfo(address)
 
     if (info === null) {
@@ -47,7 +47,7 @@ export class SlaRegistry {
   static async fetchMultiple(
     c: Connection,
     addresses: PublicKey[]
-  ): Promise<Array<SlaRegistry | null>> {
+  ): Promise<Array<SlaRegistryPage | null>> {
     const infos = await c.getMultipleAccountsInfo(addresses)
 
     return infos.map((info) => {
@@ -62,19 +62,19 @@ export class SlaRegistry {
     })
   }
 
-  static decode(data: Buffer): SlaRegistry {
-    if (!data.slice(0, 8).equals(SlaRegistry.discriminator)) {
+  static decode(data: Buffer): SlaRegistryPage {
+    if (!data.slice(0, 8).equals(SlaRegistryPage.discriminator)) {
       throw new Error("invalid account discriminator")
     }
 
-    const dec = SlaRegistry.layout.decode(data.slice(8))
+    const dec = SlaRegistryPage.layout.decode(data.slice(8))
 
-    return new SlaRegistry({
+    return new SlaRegistryPage({
       slaAccountAddresses: dec.slaAccountAddresses,
     })
   }
 
-  toJSON(): SlaRegistryJSON {
+  toJSON(): SlaRegistryPageJSON {
     return {
       slaAccountAddresses: this.slaAccountAddresses.map((item) =>
         item.toString()
@@ -82,8 +82,8 @@ export class SlaRegistry {
     }
   }
 
-  static fromJSON(obj: SlaRegistryJSON): SlaRegistry {
-    return new SlaRegistry({
+  static fromJSON(obj: SlaRegistryPageJSON): SlaRegistryPage {
+    return new SlaRegistryPage({
       slaAccountAddresses: obj.slaAccountAddresses.map(
         (item) => new PublicKey(item)
       ),
@@ -5,15 +5,21 @@ import * as types from "../types" // eslint-disable-line @typescript-eslint/no-u
 import { PROGRAM_ID } from "../programId"
 
 export interface StatusRegistryFields {
+  /** version of the layout of the account, `StatusRegistry::VERSION` once deployed or migrated */
+  version: number
   statusRegistry: Array<types.StatusKind>
 }
 
 export interface StatusRegistryJSON {
+  /** version of the layout of the account, `StatusRegistry::VERSION` once deployed or migrated */
+  version: number
   statusRegistry: Array<types.StatusJSON>
 }
 
 /** the registry with the stored status of each period after validation */
 export class StatusRegistry {
+  /** version of the layout of the account, `StatusRegistry::VERSION` once deployed or migrated */
+  readonly version: number
   readonly statusRegistry: Array<types.StatusKind>
 
   static readonly discriminator = Buffer.from([
@@ -21,10 +27,12 @@ export class StatusRegistry {
   ])
 
   static readonly layout = borsh.struct([
+    borsh.u8("version"),
     borsh.vec(types.Status.layout(), "statusRegistry"),
   ])
 
   constructor(fields: StatusRegistryFields) {
+    this.version = fields.version
     this.statusRegistry = fields.statusRegistry
   }
 
@@ -70,6 +78,7 @@ export class StatusRegistry {
     const dec = StatusRegistry.layout.decode(data.slice(8))
 
     return new StatusRegistry({
+      version: dec.version,
       statusRegistry: dec.statusRegistry.map(
         (
           item: any /* eslint-disable-line @typescript-eslint/no-explicit-any */
@@ -80,12 +89,14 @@ export class StatusRegistry {
 
   toJSON(): StatusRegistryJSON {
     return {
+      version: this.version,
       statusRegistry: this.statusRegistry.map((item) => item.toJSON()),
     }
   }
 
   static fromJSON(obj: StatusRegistryJSON): StatusRegistry {
     return new StatusRegistry({
+      version: obj.version,
       statusRegistry: obj.statusRegistry.map((item) =>
         types.Status.fromJSON(item)
       ),
@@ -1,11 +1,34 @@
+export { PeriodCheckpoint } from "./PeriodCheckpoint"
+export type {
+  PeriodCheckpointFields,
+  PeriodCheckpointJSON,
+} from "./PeriodCheckpoint"
 export { Governance } from "./Governance"
 export type { GovernanceFields, GovernanceJSON } from "./Governance"
+export { GovernanceChange } from "./GovernanceChange"
+export type {
+  GovernanceChangeFields,
+  GovernanceChangeJSON,
+} from "./GovernanceChange"
 export { Lockup } from "./Lockup"
 export type { LockupFields, LockupJSON } from "./Lockup"
 export { SlaAuthority } from "./SlaAuthority"
 export type { SlaAuthorityFields, SlaAuthorityJSON } from "./SlaAuthority"
-export { SlaRegistry } from "./SlaRegistry"
-export type { SlaRegistryFields, SlaRegistryJSON } from "./SlaRegistry"
+export { SlaRegistryHeader } from "./SlaRegistryHeader"
+export type {
+  SlaRegistryHeaderFields,
+  SlaRegistryHeaderJSON,
+} from "./SlaRegistryHeader"
+export { SlaRegistryPage } from "./SlaRegistryPage"
+export type {
+  SlaRegistryPageFields,
+  SlaRegistryPageJSON,
+} from "./SlaRegistryPage"
+export { SlaRegistryEntry } from "./SlaRegistryEntry"
+export type {
+  SlaRegistryEntryFields,
+  SlaRegistryEntryJSON,
+} from "./SlaRegistryEntry"
 export { Sla } from "./Sla"
 export type { SlaFields, SlaJSON } from "./Sla"
 export { StatusRegistry } from "./StatusRegistry"
@@ -0,0 +1,23 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@project-serum/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface AcceptGovernanceAuthorityAccounts {
+  newAuthority: PublicKey
+  governance: PublicKey
+}
+
+export function acceptGovernanceAuthority(
+  accounts: AcceptGovernanceAuthorityAccounts
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.newAuthority, isSigner: true, isWritable: false },
+    { pubkey: accounts.governance, isSigner: false, isWritable: true },
+  ]
+  const identifier = Buffer.from([96, 26, 122, 29, 227, 228, 230, 229])
+  const data = identifier
+  const ix = new TransactionInstruction({ keys, programId: PROGRAM_ID, data })
+  return ix
+}
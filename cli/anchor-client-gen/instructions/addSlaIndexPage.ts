@@ -0,0 +1,43 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@project-serum/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface AddSlaIndexPageArgs {
+  kind: types.SlaIndexKindKind
+}
+
+export interface AddSlaIndexPageAccounts {
+  payer: PublicKey
+  indexedKey: PublicKey
+  slaIndex: PublicKey
+  slaIndexPage: PublicKey
+  systemProgram: PublicKey
+}
+
+export const layout = borsh.struct([types.SlaIndexKind.layout("kind")])
+
+export function addSlaIndexPage(
+  args: AddSlaIndexPageArgs,
+  accounts: AddSlaIndexPageAccounts
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.payer, isSigner: true, isWritable: true },
+    { pubkey: accounts.indexedKey, isSigner: false, isWritable: false },
+    { pubkey: accounts.slaIndex, isSigner: false, isWritable: true },
+    { pubkey: accounts.slaIndexPage, isSigner: false, isWritable: true },
+    { pubkey: accounts.systemProgram, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([180, 152, 203, 142, 38, 152, 10, 158])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      kind: args.kind.toEncodable(),
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId: PROGRAM_ID, data })
+  return ix
+}
@@ -0,0 +1,25 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@project-serum/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface AddSlaRegistryPageAccounts {
+  payer: PublicKey
+  slaRegistry: PublicKey
+  slaRegistryPage: PublicKey
+  systemProgram: PublicKey
+}
+
+export function addSlaRegistryPage(accounts: AddSlaRegistryPageAccounts) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.payer, isSigner: true, isWritable: true },
+    { pubkey: accounts.slaRegistry, isSigner: false, isWritable: true },
+    { pubkey: accounts.slaRegistryPage, isSigner: false, isWritable: true },
+    { pubkey: accounts.systemProgram, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([54, 138, 106, 154, 127, 237, 2, 224])
+  const data = identifier
+  const ix = new TransactionInstruction({ keys, programId: PROGRAM_ID, data })
+  return ix
+}
@@ -0,0 +1,28 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@project-serum/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface CancelGovernanceChangeAccounts {
+  governanceAuthority: PublicKey
+  governance: PublicKey
+  governanceChange: PublicKey
+  /** the proposer gets back the rent of the governance change */
+  proposer: PublicKey
+}
+
+export function cancelGovernanceChange(
+  accounts: CancelGovernanceChangeAccounts
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.governanceAuthority, isSigner: true, isWritable: false },
+    { pubkey: accounts.governance, isSigner: false, isWritable: false },
+    { pubkey: accounts.governanceChange, isSigner: false, isWritable: true },
+    { pubkey: accounts.proposer, isSigner: false, isWritable: true },
+  ]
+  const identifier = Buffer.from([193, 97, 28, 121, 17, 242, 111, 242])
+  const data = identifier
+  const ix = new TransactionInstruction({ keys, programId: PROGRAM_ID, data })
+  return ix
+}
@@ -0,0 +1,43 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@project-serum/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface CheckpointPeriodArgs {
+  period: BN
+}
+
+export interface CheckpointPeriodAccounts {
+  payer: PublicKey
+  sla: PublicKey
+  statusRegistry: PublicKey
+  checkpoint: PublicKey
+  systemProgram: PublicKey
+}
+
+export const layout = borsh.struct([borsh.u64("period")])
+
+export function checkpointPeriod(
+  args: CheckpointPeriodArgs,
+  accounts: CheckpointPeriodAccounts
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.payer, isSigner: true, isWritable: true },
+    { pubkey: accounts.sla, isSigner: false, isWritable: true },
+    { pubkey: accounts.statusRegistry, isSigner: false, isWritable: false },
+    { pubkey: accounts.checkpoint, isSigner: false, isWritable: true },
+    { pubkey: accounts.systemProgram, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([255, 251, 56, 91, 156, 126, 211, 106])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      period: args.period,
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId: PROGRAM_ID, data })
+  return ix
+}
@@ -10,11 +10,28 @@ export interface DeploySlaArgs {
   start: BN
   nPeriods: number
   periodLength: types.PeriodLengthKind
+  severity: Array<types.DslaDecimalFields>
+  penalty: Array<types.DslaDecimalFields>
+  oracleSource: types.OracleSourceKind
+  aggregationPolicy: types.AggregationPolicyKind
+  minQuorum: number
+  maxStaleness: number | null
+  maxConfidenceInterval: types.DslaDecimalFields | null
 }
 
 export interface DeploySlaAccounts {
   deployer: PublicKey
   slaRegistry: PublicKey
+  /** the last page of the registry, a new page needs to be added first when it's full */
+  slaRegistryPage: PublicKey
+  /** index of the SLAs of the deployer, created with its pages on demand by the first SLA that needs them */
+  deployerIndex: PublicKey
+  deployerIndexPage: PublicKey
+  /** index of the SLAs of the mint, created with its pages on demand by the first SLA that needs them */
+  mintIndex: PublicKey
+  mintIndexPage: PublicKey
+  /** can only be created once for each SLA */
+  slaRegistryEntry: PublicKey
   sla: PublicKey
   slaAuthority: PublicKey
   statusRegistry: PublicKey
@@ -27,7 +44,6 @@ export interface DeploySlaAccounts {
   governance: PublicKey
   utMint: PublicKey
   ptMint: PublicKey
-  aggregator: PublicKey
   /** The program for interacting with the token. */
   tokenProgram: PublicKey
   rent: PublicKey
@@ -40,12 +56,25 @@ export const layout = borsh.struct([
   borsh.u128("start"),
   borsh.u32("nPeriods"),
   types.PeriodLength.layout("periodLength"),
+  borsh.vec(types.DslaDecimal.layout(), "severity"),
+  borsh.vec(types.DslaDecimal.layout(), "penalty"),
+  types.OracleSource.layout("oracleSource"),
+  types.AggregationPolicy.layout("aggregationPolicy"),
+  borsh.u8("minQuorum"),
+  borsh.option(borsh.u32(), "maxStaleness"),
+  borsh.option(types.DslaDecimal.layout(), "maxConfidenceInterval"),
 ])
 
 export function deploySla(args: DeploySlaArgs, accounts: DeploySlaAccounts) {
   const keys: Array<AccountMeta> = [
     { pubkey: accounts.deployer, isSigner: true, isWritable: true },
     { pubkey: accounts.slaRegistry, isSigner: false, isWritable: true },
+    { pubkey: accounts.slaRegistryPage, isSigner: false, isWritable: true },
+    { pubkey: accounts.deployerIndex, isSigner: false, isWritable: true },
+    { pubkey: accounts.deployerIndexPage, isSigner: false, isWritable: true },
+    { pubkey: accounts.mintIndex, isSigner: false, isWritable: true },
+    { pubkey: accounts.mintIndexPage, isSigner: false, isWritable: true },
+    { pubkey: accounts.slaRegistryEntry, isSigner: false, isWritable: true },
     { pubkey: accounts.sla, isSigner: true, isWritable: true },
     { pubkey: accounts.slaAuthority, isSigner: false, isWritable: true },
     { pubkey: accounts.statusRegistry, isSigner: false, isWritable: true },
@@ -61,7 +90,6 @@ export function deploySla(args: DeploySlaArgs, accounts: DeploySlaAccounts) {
     { pubkey: accounts.governance, isSigner: false, isWritable: false },
     { pubkey: accounts.utMint, isSigner: false, isWritable: true },
     { pubkey: accounts.ptMint, isSigner: false, isWritable: true },
-    { pubkey: accounts.aggregator, isSigner: false, isWritable: false },
     { pubkey: accounts.tokenProgram, isSigner: false, isWritable: false },
     { pubkey: accounts.rent, isSigner: false, isWritable: false },
     { pubkey: accounts.systemProgram, isSigner: false, isWritable: false },
@@ -75,6 +103,18 @@ export function deploySla(args: DeploySlaArgs, accounts: DeploySlaAccounts) {
       start: args.start,
       nPeriods: args.nPeriods,
       periodLength: args.periodLength.toEncodable(),
+      severity: args.severity.map((item) =>
+        types.DslaDecimal.toEncodable(item)
+      ),
+      penalty: args.penalty.map((item) => types.DslaDecimal.toEncodable(item)),
+      oracleSource: args.oracleSource.toEncodable(),
+      aggregationPolicy: args.aggregationPolicy.toEncodable(),
+      minQuorum: args.minQuorum,
+      maxStaleness: args.maxStaleness,
+      maxConfidenceInterval:
+        (args.maxConfidenceInterval &&
+          types.DslaDecimal.toEncodable(args.maxConfidenceInterval)) ||
+        null,
     },
     buffer
   )
@@ -0,0 +1,26 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@project-serum/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface ExecuteGovernanceChangeAccounts {
+  governance: PublicKey
+  governanceChange: PublicKey
+  /** the proposer gets back the rent of the governance change */
+  proposer: PublicKey
+}
+
+export function executeGovernanceChange(
+  accounts: ExecuteGovernanceChangeAccounts
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.governance, isSigner: false, isWritable: true },
+    { pubkey: accounts.governanceChange, isSigner: false, isWritable: true },
+    { pubkey: accounts.proposer, isSigner: false, isWritable: true },
+  ]
+  const identifier = Buffer.from([225, 251, 5, 141, 164, 215, 16, 150])
+  const data = identifier
+  const ix = new TransactionInstruction({ keys, programId: PROGRAM_ID, data })
+  return ix
+}
@@ -3,13 +3,35 @@ export type {
   InitGovernanceArgs,
   InitGovernanceAccounts,
 } from "./initGovernance"
-export { modifyGovernance } from "./modifyGovernance"
+export { proposeGovernanceChange } from "./proposeGovernanceChange"
 export type {
-  ModifyGovernanceArgs,
-  ModifyGovernanceAccounts,
-} from "./modifyGovernance"
+  ProposeGovernanceChangeArgs,
+  ProposeGovernanceChangeAccounts,
+} from "./proposeGovernanceChange"
+export { executeGovernanceChange } from "./executeGovernanceChange"
+export type { ExecuteGovernanceChangeAccounts } from "./executeGovernanceChange"
+export { cancelGovernanceChange } from "./cancelGovernanceChange"
+export type { CancelGovernanceChangeAccounts } from "./cancelGovernanceChange"
+export { transferGovernanceAuthority } from "./transferGovernanceAuthority"
+export type {
+  TransferGovernanceAuthorityArgs,
+  TransferGovernanceAuthorityAccounts,
+} from "./transferGovernanceAuthority"
+export { acceptGovernanceAuthority } from "./acceptGovernanceAuthority"
+export type {
+  AcceptGovernanceAuthorityAccounts,
+} from "./acceptGovernanceAuthority"
 export { initSlaRegistry } from "./initSlaRegistry"
 export type { InitSlaRegistryAccounts } from "./initSlaRegistry"
+export { addSlaRegistryPage } from "./addSlaRegistryPage"
+export type { AddSlaRegistryPageAccounts } from "./addSlaRegistryPage"
+export { initSlaIndex } from "./initSlaIndex"
+export type { InitSlaIndexArgs, InitSlaIndexAccounts } from "./initSlaIndex"
+export { addSlaIndexPage } from "./addSlaIndexPage"
+export type {
+  AddSlaIndexPageArgs,
+  AddSlaIndexPageAccounts,
+} from "./addSlaIndexPage"
 export { stakeUser } from "./stakeUser"
 export type { StakeUserArgs, StakeUserAccounts } from "./stakeUser"
 export { stakeProvider } from "./stakeProvider"
@@ -19,6 +41,11 @@ export type {
   ValidatePeriodArgs,
   ValidatePeriodAccounts,
 } from "./validatePeriod"
+export { checkpointPeriod } from "./checkpointPeriod"
+export type {
+  CheckpointPeriodArgs,
+  CheckpointPeriodAccounts,
+} from "./checkpointPeriod"
 export { withdrawUser } from "./withdrawUser"
 export type { WithdrawUserArgs, WithdrawUserAccounts } from "./withdrawUser"
 export { withdrawProvider } from "./withdrawProvider"
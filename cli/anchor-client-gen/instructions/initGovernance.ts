@@ -5,32 +5,22 @@ import * as types from "../types" // eslint-disable-line @typescript-eslint/no-u
 import { PROGRAM_ID } from "../programId"
 
 export interface InitGovernanceArgs {
-  dslaDepositByPeriod: BN
-  dslaProtocolReward: BN
-  dslaValidatorReward: BN
-  dslaBurnedByVerification: BN
-  slaDeployerRewardsRate: types.DslaDecimalFields
-  protocolRewardsRate: types.DslaDecimalFields
-  maxLeverage: types.DslaDecimalFields
+  parameters: types.GovernanceParametersFields
 }
 
 export interface InitGovernanceAccounts {
   /** the account that has the authority to upgrade the program */
   programUpgradeAuthority: PublicKey
   governance: PublicKey
+  /** the DSLA mint all the SLAs pay their deposits, rewards and bonds in, it can't be changed afterwards */
+  dslaMint: PublicKey
   program: PublicKey
   programData: PublicKey
   systemProgram: PublicKey
 }
 
 export const layout = borsh.struct([
-  borsh.u64("dslaDepositByPeriod"),
-  borsh.u64("dslaProtocolReward"),
-  borsh.u64("dslaValidatorReward"),
-  borsh.u64("dslaBurnedByVerification"),
-  types.DslaDecimal.layout("slaDeployerRewardsRate"),
-  types.DslaDecimal.layout("protocolRewardsRate"),
-  types.DslaDecimal.layout("maxLeverage"),
+  types.GovernanceParameters.layout("parameters"),
 ])
 
 export function initGovernance(
@@ -44,6 +34,7 @@ export function initGovernance(
       isWritable: true,
     },
     { pubkey: accounts.governance, isSigner: false, isWritable: true },
+    { pubkey: accounts.dslaMint, isSigner: false, isWritable: false },
     { pubkey: accounts.program, isSigner: false, isWritable: false },
     { pubkey: accounts.programData, isSigner: false, isWritable: false },
     { pubkey: accounts.systemProgram, isSigner: false, isWritable: false },
@@ -52,17 +43,7 @@ export function initGovernance(
   const buffer = Buffer.alloc(1000)
   const len = layout.encode(
     {
-      dslaDepositByPeriod: args.dslaDepositByPeriod,
-      dslaProtocolReward: args.dslaProtocolReward,
-      dslaValidatorReward: args.dslaValidatorReward,
-      dslaBurnedByVerification: args.dslaBurnedByVerification,
-      slaDeployerRewardsRate: types.DslaDecimal.toEncodable(
-        args.slaDeployerRewardsRate
-      ),
-      protocolRewardsRate: types.DslaDecimal.toEncodable(
-        args.protocolRewardsRate
-      ),
-      maxLeverage: types.DslaDecimal.toEncodable(args.maxLeverage),
+      parameters: types.GovernanceParameters.toEncodable(args.parameters),
     },
     buffer
   )
@@ -7,6 +7,7 @@ import { PROGRAM_ID } from "../programId"
 export interface InitSlaRegistryAccounts {
   deployer: PublicKey
   slaRegistry: PublicKey
+  slaRegistryPage: PublicKey
   systemProgram: PublicKey
 }
 
@@ -14,6 +15,7 @@ export function initSlaRegistry(accounts: InitSlaRegistryAccounts) {
   const keys: Array<AccountMeta> = [
     { pubkey: accounts.deployer, isSigner: true, isWritable: true },
     { pubkey: accounts.slaRegistry, isSigner: false, isWritable: true },
+    { pubkey: accounts.slaRegistryPage, isSigner: false, isWritable: true },
     { pubkey: accounts.systemProgram, isSigner: false, isWritable: false },
   ]
   const identifier = Buffer.from([20, 58, 193, 30, 243, 195, 230, 15])
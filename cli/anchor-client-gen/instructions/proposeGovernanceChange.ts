@@ -0,0 +1,43 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@project-serum/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface ProposeGovernanceChangeArgs {
+  parameters: types.GovernanceParametersFields
+}
+
+export interface ProposeGovernanceChangeAccounts {
+  governanceAuthority: PublicKey
+  governance: PublicKey
+  governanceChange: PublicKey
+  systemProgram: PublicKey
+}
+
+export const layout = borsh.struct([
+  types.GovernanceParameters.layout("parameters"),
+])
+
+export function proposeGovernanceChange(
+  args: ProposeGovernanceChangeArgs,
+  accounts: ProposeGovernanceChangeAccounts
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.governanceAuthority, isSigner: true, isWritable: true },
+    { pubkey: accounts.governance, isSigner: false, isWritable: false },
+    { pubkey: accounts.governanceChange, isSigner: false, isWritable: true },
+    { pubkey: accounts.systemProgram, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([181, 245, 73, 226, 156, 221, 135, 45])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      parameters: types.GovernanceParameters.toEncodable(args.parameters),
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId: PROGRAM_ID, data })
+  return ix
+}
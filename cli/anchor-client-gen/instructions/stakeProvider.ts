@@ -6,11 +6,13 @@ import { PROGRAM_ID } from "../programId"
 
 export interface StakeProviderArgs {
   tokenAmount: BN
+  minTokensOut: BN
 }
 
 export interface StakeProviderAccounts {
   staker: PublicKey
   sla: PublicKey
+  governance: PublicKey
   slaAuthority: PublicKey
   mint: PublicKey
   pool: PublicKey
@@ -25,7 +27,10 @@ export interface StakeProviderAccounts {
   systemProgram: PublicKey
 }
 
-export const layout = borsh.struct([borsh.u64("tokenAmount")])
+export const layout = borsh.struct([
+  borsh.u64("tokenAmount"),
+  borsh.u64("minTokensOut"),
+])
 
 export function stakeProvider(
   args: StakeProviderArgs,
@@ -34,6 +39,7 @@ export function stakeProvider(
   const keys: Array<AccountMeta> = [
     { pubkey: accounts.staker, isSigner: true, isWritable: true },
     { pubkey: accounts.sla, isSigner: false, isWritable: true },
+    { pubkey: accounts.governance, isSigner: false, isWritable: false },
     { pubkey: accounts.slaAuthority, isSigner: false, isWritable: true },
     { pubkey: accounts.mint, isSigner: false, isWritable: false },
     { pubkey: accounts.pool, isSigner: false, isWritable: true },
@@ -50,6 +56,7 @@ export function stakeProvider(
   const len = layout.encode(
     {
       tokenAmount: args.tokenAmount,
+      minTokensOut: args.minTokensOut,
     },
     buffer
   )
@@ -6,11 +6,13 @@ import { PROGRAM_ID } from "../programId"
 
 export interface StakeUserArgs {
   tokenAmount: BN
+  minTokensOut: BN
 }
 
 export interface StakeUserAccounts {
   staker: PublicKey
   sla: PublicKey
+  governance: PublicKey
   slaAuthority: PublicKey
   mint: PublicKey
   pool: PublicKey
@@ -25,12 +27,16 @@ export interface StakeUserAccounts {
   systemProgram: PublicKey
 }
 
-export const layout = borsh.struct([borsh.u64("tokenAmount")])
+export const layout = borsh.struct([
+  borsh.u64("tokenAmount"),
+  borsh.u64("minTokensOut"),
+])
 
 export function stakeUser(args: StakeUserArgs, accounts: StakeUserAccounts) {
   const keys: Array<AccountMeta> = [
     { pubkey: accounts.staker, isSigner: true, isWritable: true },
     { pubkey: accounts.sla, isSigner: false, isWritable: true },
+    { pubkey: accounts.governance, isSigner: false, isWritable: false },
     { pubkey: accounts.slaAuthority, isSigner: false, isWritable: true },
     { pubkey: accounts.mint, isSigner: false, isWritable: false },
     { pubkey: accounts.pool, isSigner: false, isWritable: true },
@@ -47,6 +53,7 @@ export function stakeUser(args: StakeUserArgs, accounts: StakeUserAccounts) {
   const len = layout.encode(
     {
       tokenAmount: args.tokenAmount,
+      minTokensOut: args.minTokensOut,
     },
     buffer
   )
@@ -0,0 +1,37 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@project-serum/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface TransferGovernanceAuthorityArgs {
+  newAuthority: PublicKey
+}
+
+export interface TransferGovernanceAuthorityAccounts {
+  governanceAuthority: PublicKey
+  governance: PublicKey
+}
+
+export const layout = borsh.struct([borsh.publicKey("newAuthority")])
+
+export function transferGovernanceAuthority(
+  args: TransferGovernanceAuthorityArgs,
+  accounts: TransferGovernanceAuthorityAccounts
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.governanceAuthority, isSigner: true, isWritable: false },
+    { pubkey: accounts.governance, isSigner: false, isWritable: true },
+  ]
+  const identifier = Buffer.from([201, 42, 113, 79, 240, 50, 92, 49])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      newAuthority: args.newAuthority,
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId: PROGRAM_ID, data })
+  return ix
+}
@@ -6,6 +6,7 @@ import { PROGRAM_ID } from "../programId"
 
 export interface WithdrawProviderArgs {
   tokenAmount: BN
+  minTokensOut: BN
 }
 
 export interface WithdrawProviderAccounts {
@@ -24,15 +25,16 @@ export interface WithdrawProviderAccounts {
   ptMint: PublicKey
   governance: PublicKey
   tokenProgram: PublicKey
-  program: PublicKey
-  programData: PublicKey
   protocolTokenAccount: PublicKey
   deployerTokenAccount: PublicKey
   rent: PublicKey
   systemProgram: PublicKey
 }
 
-export const layout = borsh.struct([borsh.u64("tokenAmount")])
+export const layout = borsh.struct([
+  borsh.u64("tokenAmount"),
+  borsh.u64("minTokensOut"),
+])
 
 export function withdrawProvider(
   args: WithdrawProviderArgs,
@@ -54,8 +56,6 @@ export function withdrawProvider(
     { pubkey: accounts.ptMint, isSigner: false, isWritable: true },
     { pubkey: accounts.governance, isSigner: false, isWritable: false },
     { pubkey: accounts.tokenProgram, isSigner: false, isWritable: false },
-    { pubkey: accounts.program, isSigner: false, isWritable: false },
-    { pubkey: accounts.programData, isSigner: false, isWritable: false },
     {
       pubkey: accounts.protocolTokenAccount,
       isSigner: false,
@@ -74,6 +74,7 @@ export function withdrawProvider(
   const len = layout.encode(
     {
       tokenAmount: args.tokenAmount,
+      minTokensOut: args.minTokensOut,
     },
     buffer
   )
@@ -6,6 +6,7 @@ import { PROGRAM_ID } from "../programId"
 
 export interface WithdrawUserArgs {
   tokenAmount: BN
+  minTokensOut: BN
 }
 
 export interface WithdrawUserAccounts {
@@ -26,13 +27,14 @@ export interface WithdrawUserAccounts {
   protocolTokenAccount: PublicKey
   governance: PublicKey
   tokenProgram: PublicKey
-  program: PublicKey
-  programData: PublicKey
   rent: PublicKey
   systemProgram: PublicKey
 }
 
-export const layout = borsh.struct([borsh.u64("tokenAmount")])
+export const layout = borsh.struct([
+  borsh.u64("tokenAmount"),
+  borsh.u64("minTokensOut"),
+])
 
 export function withdrawUser(
   args: WithdrawUserArgs,
@@ -64,8 +66,6 @@ export function withdrawUser(
     },
     { pubkey: accounts.governance, isSigner: false, isWritable: false },
     { pubkey: accounts.tokenProgram, isSigner: false, isWritable: false },
-    { pubkey: accounts.program, isSigner: false, isWritable: false },
-    { pubkey: accounts.programData, isSigner: false, isWritable: false },
     { pubkey: accounts.rent, isSigner: false, isWritable: false },
     { pubkey: accounts.systemProgram, isSigner: false, isWritable: false },
   ]
@@ -74,6 +74,7 @@ export function withdrawUser(
   const len = layout.encode(
     {
       tokenAmount: args.tokenAmount,
+      minTokensOut: args.minTokensOut,
     },
     buffer
   )
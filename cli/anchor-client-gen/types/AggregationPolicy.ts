@@ -0,0 +1,177 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@project-serum/borsh"
+
+export interface MedianJSON {
+  kind: "Median"
+}
+
+export class Median {
+  static readonly discriminator = 0
+  static readonly kind = "Median"
+  readonly discriminator = 0
+  readonly kind = "Median"
+
+  toJSON(): MedianJSON {
+    return {
+      kind: "Median",
+    }
+  }
+
+  toEncodable() {
+    return {
+      Median: {},
+    }
+  }
+}
+
+export interface MinJSON {
+  kind: "Min"
+}
+
+export class Min {
+  static readonly discriminator = 1
+  static readonly kind = "Min"
+  readonly discriminator = 1
+  readonly kind = "Min"
+
+  toJSON(): MinJSON {
+    return {
+      kind: "Min",
+    }
+  }
+
+  toEncodable() {
+    return {
+      Min: {},
+    }
+  }
+}
+
+export interface MaxJSON {
+  kind: "Max"
+}
+
+export class Max {
+  static readonly discriminator = 2
+  static readonly kind = "Max"
+  readonly discriminator = 2
+  readonly kind = "Max"
+
+  toJSON(): MaxJSON {
+    return {
+      kind: "Max",
+    }
+  }
+
+  toEncodable() {
+    return {
+      Max: {},
+    }
+  }
+}
+
+export type TrimmedMeanFields = {
+  trim: number
+}
+export type TrimmedMeanValue = {
+  trim: number
+}
+
+export interface TrimmedMeanJSON {
+  kind: "TrimmedMean"
+  value: {
+    trim: number
+  }
+}
+
+export class TrimmedMean {
+  static readonly discriminator = 3
+  static readonly kind = "TrimmedMean"
+  readonly discriminator = 3
+  readonly kind = "TrimmedMean"
+  readonly value: TrimmedMeanValue
+
+  constructor(value: TrimmedMeanFields) {
+    this.value = {
+      trim: value.trim,
+    }
+  }
+
+  toJSON(): TrimmedMeanJSON {
+    return {
+      kind: "TrimmedMean",
+      value: {
+        trim: this.value.trim,
+      },
+    }
+  }
+
+  toEncodable() {
+    return {
+      TrimmedMean: {
+        trim: this.value.trim,
+      },
+    }
+  }
+}
+
+// eslint-disable-next-line @typescript-eslint/no-explicit-any
+export function fromDecoded(obj: any): types.AggregationPolicyKind {
+  if (typeof obj !== "object") {
+    throw new Error("Invalid enum object")
+  }
+
+  if ("Median" in obj) {
+    return new Median()
+  }
+  if ("Min" in obj) {
+    return new Min()
+  }
+  if ("Max" in obj) {
+    return new Max()
+  }
+  if ("TrimmedMean" in obj) {
+    const val = obj["TrimmedMean"]
+    return new TrimmedMean({
+      trim: val["trim"],
+    })
+  }
+
+  throw new Error("Invalid enum object")
+}
+
+export function fromJSON(
+  obj: types.AggregationPolicyJSON
+): types.AggregationPolicyKind {
+  switch (obj.kind) {
+    case "Median": {
+      return new Median()
+    }
+    case "Min": {
+      return new Min()
+    }
+    case "Max": {
+      return new Max()
+    }
+    case "TrimmedMean": {
+      return new TrimmedMean({
+        trim: obj.value.trim,
+      })
+    }
+  }
+}
+
+export function layout(property?: string) {
+  const ret = borsh.rustEnum([
+    borsh.struct([], "Median"),
+    borsh.struct([], "Min"),
+    borsh.struct([], "Max"),
+    borsh.struct([borsh.u8("trim")], "TrimmedMean"),
+  ])
+  if (property !== undefined) {
+    return ret.replicate(property)
+  }
+  return ret
+}
@@ -0,0 +1,90 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@project-serum/borsh"
+
+export interface DeployerJSON {
+  kind: "Deployer"
+}
+
+export class Deployer {
+  static readonly discriminator = 0
+  static readonly kind = "Deployer"
+  readonly discriminator = 0
+  readonly kind = "Deployer"
+
+  toJSON(): DeployerJSON {
+    return {
+      kind: "Deployer",
+    }
+  }
+
+  toEncodable() {
+    return {
+      Deployer: {},
+    }
+  }
+}
+
+export interface ProtocolTreasuryJSON {
+  kind: "ProtocolTreasury"
+}
+
+export class ProtocolTreasury {
+  static readonly discriminator = 1
+  static readonly kind = "ProtocolTreasury"
+  readonly discriminator = 1
+  readonly kind = "ProtocolTreasury"
+
+  toJSON(): ProtocolTreasuryJSON {
+    return {
+      kind: "ProtocolTreasury",
+    }
+  }
+
+  toEncodable() {
+    return {
+      ProtocolTreasury: {},
+    }
+  }
+}
+
+// eslint-disable-next-line @typescript-eslint/no-explicit-any
+export function fromDecoded(obj: any): types.DepositReclaimPolicyKind {
+  if (typeof obj !== "object") {
+    throw new Error("Invalid enum object")
+  }
+
+  if ("Deployer" in obj) {
+    return new Deployer()
+  }
+  if ("ProtocolTreasury" in obj) {
+    return new ProtocolTreasury()
+  }
+
+  throw new Error("Invalid enum object")
+}
+
+export function fromJSON(
+  obj: types.DepositReclaimPolicyJSON
+): types.DepositReclaimPolicyKind {
+  switch (obj.kind) {
+    case "Deployer": {
+      return new Deployer()
+    }
+    case "ProtocolTreasury": {
+      return new ProtocolTreasury()
+    }
+  }
+}
+
+export function layout(property?: string) {
+  const ret = borsh.rustEnum([
+    borsh.struct([], "Deployer"),
+    borsh.struct([], "ProtocolTreasury"),
+  ])
+  if (property !== undefined) {
+    return ret.replicate(property)
+  }
+  return ret
+}
@@ -0,0 +1,281 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@project-serum/borsh"
+
+export interface GovernanceParametersFields {
+  dslaDepositByPeriod: BN
+  dslaProtocolReward: BN
+  dslaValidatorReward: BN
+  dslaBurnedByVerification: BN
+  slaDeployerRewardsRate: types.DslaDecimalFields
+  protocolRewardsRate: types.DslaDecimalFields
+  maxLeverage: types.DslaDecimalFields
+  defaultMaxStaleness: number
+  maxStalenessLimit: number
+  defaultMaxConfidenceInterval: types.DslaDecimalFields
+  maxConfidenceIntervalLimit: types.DslaDecimalFields
+  attestationWindow: number
+  disputeWindow: number
+  proposalBond: BN
+  disputeBond: BN
+  arbiter: PublicKey
+  protocolTreasury: PublicKey
+  depositReclaimGracePeriod: number
+  depositReclaimPolicy: types.DepositReclaimPolicyKind
+  slaCancellationFeeRate: types.DslaDecimalFields
+  guardian: PublicKey
+}
+
+export interface GovernanceParametersJSON {
+  dslaDepositByPeriod: string
+  dslaProtocolReward: string
+  dslaValidatorReward: string
+  dslaBurnedByVerification: string
+  slaDeployerRewardsRate: types.DslaDecimalJSON
+  protocolRewardsRate: types.DslaDecimalJSON
+  maxLeverage: types.DslaDecimalJSON
+  defaultMaxStaleness: number
+  maxStalenessLimit: number
+  defaultMaxConfidenceInterval: types.DslaDecimalJSON
+  maxConfidenceIntervalLimit: types.DslaDecimalJSON
+  attestationWindow: number
+  disputeWindow: number
+  proposalBond: string
+  disputeBond: string
+  arbiter: string
+  protocolTreasury: string
+  depositReclaimGracePeriod: number
+  depositReclaimPolicy: types.DepositReclaimPolicyJSON
+  slaCancellationFeeRate: types.DslaDecimalJSON
+  guardian: string
+}
+
+/** all the parameters of the `Governance` that can be changed, see `Governance` for their meaning */
+export class GovernanceParameters {
+  readonly dslaDepositByPeriod: BN
+  readonly dslaProtocolReward: BN
+  readonly dslaValidatorReward: BN
+  readonly dslaBurnedByVerification: BN
+  readonly slaDeployerRewardsRate: types.DslaDecimal
+  readonly protocolRewardsRate: types.DslaDecimal
+  readonly maxLeverage: types.DslaDecimal
+  readonly defaultMaxStaleness: number
+  readonly maxStalenessLimit: number
+  readonly defaultMaxConfidenceInterval: types.DslaDecimal
+  readonly maxConfidenceIntervalLimit: types.DslaDecimal
+  readonly attestationWindow: number
+  readonly disputeWindow: number
+  readonly proposalBond: BN
+  readonly disputeBond: BN
+  readonly arbiter: PublicKey
+  readonly protocolTreasury: PublicKey
+  readonly depositReclaimGracePeriod: number
+  readonly depositReclaimPolicy: types.DepositReclaimPolicyKind
+  readonly slaCancellationFeeRate: types.DslaDecimal
+  readonly guardian: PublicKey
+
+  constructor(fields: GovernanceParametersFields) {
+    this.dslaDepositByPeriod = fields.dslaDepositByPeriod
+    this.dslaProtocolReward = fields.dslaProtocolReward
+    this.dslaValidatorReward = fields.dslaValidatorReward
+    this.dslaBurnedByVerification = fields.dslaBurnedByVerification
+    this.slaDeployerRewardsRate = new types.DslaDecimal({
+      ...fields.slaDeployerRewardsRate,
+    })
+    this.protocolRewardsRate = new types.DslaDecimal({
+      ...fields.protocolRewardsRate,
+    })
+    this.maxLeverage = new types.DslaDecimal({ ...fields.maxLeverage })
+    this.defaultMaxStaleness = fields.defaultMaxStaleness
+    this.maxStalenessLimit = fields.maxStalenessLimit
+    this.defaultMaxConfidenceInterval = new types.DslaDecimal({
+      ...fields.defaultMaxConfidenceInterval,
+    })
+    this.maxConfidenceIntervalLimit = new types.DslaDecimal({
+      ...fields.maxConfidenceIntervalLimit,
+    })
+    this.attestationWindow = fields.attestationWindow
+    this.disputeWindow = fields.disputeWindow
+    this.proposalBond = fields.proposalBond
+    this.disputeBond = fields.disputeBond
+    this.arbiter = fields.arbiter
+    this.protocolTreasury = fields.protocolTreasury
+    this.depositReclaimGracePeriod = fields.depositReclaimGracePeriod
+    this.depositReclaimPolicy = fields.depositReclaimPolicy
+    this.slaCancellationFeeRate = new types.DslaDecimal({
+      ...fields.slaCancellationFeeRate,
+    })
+    this.guardian = fields.guardian
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.u64("dslaDepositByPeriod"),
+        borsh.u64("dslaProtocolReward"),
+        borsh.u64("dslaValidatorReward"),
+        borsh.u64("dslaBurnedByVerification"),
+        types.DslaDecimal.layout("slaDeployerRewardsRate"),
+        types.DslaDecimal.layout("protocolRewardsRate"),
+        types.DslaDecimal.layout("maxLeverage"),
+        borsh.u32("defaultMaxStaleness"),
+        borsh.u32("maxStalenessLimit"),
+        types.DslaDecimal.layout("defaultMaxConfidenceInterval"),
+        types.DslaDecimal.layout("maxConfidenceIntervalLimit"),
+        borsh.u32("attestationWindow"),
+        borsh.u32("disputeWindow"),
+        borsh.u64("proposalBond"),
+        borsh.u64("disputeBond"),
+        borsh.publicKey("arbiter"),
+        borsh.publicKey("protocolTreasury"),
+        borsh.u32("depositReclaimGracePeriod"),
+        types.DepositReclaimPolicy.layout("depositReclaimPolicy"),
+        types.DslaDecimal.layout("slaCancellationFeeRate"),
+        borsh.publicKey("guardian"),
+      ],
+      property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new GovernanceParameters({
+      dslaDepositByPeriod: obj.dslaDepositByPeriod,
+      dslaProtocolReward: obj.dslaProtocolReward,
+      dslaValidatorReward: obj.dslaValidatorReward,
+      dslaBurnedByVerification: obj.dslaBurnedByVerification,
+      slaDeployerRewardsRate: types.DslaDecimal.fromDecoded(
+        obj.slaDeployerRewardsRate
+      ),
+      protocolRewardsRate: types.DslaDecimal.fromDecoded(
+        obj.protocolRewardsRate
+      ),
+      maxLeverage: types.DslaDecimal.fromDecoded(obj.maxLeverage),
+      defaultMaxStaleness: obj.defaultMaxStaleness,
+      maxStalenessLimit: obj.maxStalenessLimit,
+      defaultMaxConfidenceInterval: types.DslaDecimal.fromDecoded(
+        obj.defaultMaxConfidenceInterval
+      ),
+      maxConfidenceIntervalLimit: types.DslaDecimal.fromDecoded(
+        obj.maxConfidenceIntervalLimit
+      ),
+      attestationWindow: obj.attestationWindow,
+      disputeWindow: obj.disputeWindow,
+      proposalBond: obj.proposalBond,
+      disputeBond: obj.disputeBond,
+      arbiter: obj.arbiter,
+      protocolTreasury: obj.protocolTreasury,
+      depositReclaimGracePeriod: obj.depositReclaimGracePeriod,
+      depositReclaimPolicy: types.DepositReclaimPolicy.fromDecoded(
+        obj.depositReclaimPolicy
+      ),
+      slaCancellationFeeRate: types.DslaDecimal.fromDecoded(
+        obj.slaCancellationFeeRate
+      ),
+      guardian: obj.guardian,
+    })
+  }
+
+  static toEncodable(fields: GovernanceParametersFields) {
+    return {
+      dslaDepositByPeriod: fields.dslaDepositByPeriod,
+      dslaProtocolReward: fields.dslaProtocolReward,
+      dslaValidatorReward: fields.dslaValidatorReward,
+      dslaBurnedByVerification: fields.dslaBurnedByVerification,
+      slaDeployerRewardsRate: types.DslaDecimal.toEncodable(
+        fields.slaDeployerRewardsRate
+      ),
+      protocolRewardsRate: types.DslaDecimal.toEncodable(
+        fields.protocolRewardsRate
+      ),
+      maxLeverage: types.DslaDecimal.toEncodable(fields.maxLeverage),
+      defaultMaxStaleness: fields.defaultMaxStaleness,
+      maxStalenessLimit: fields.maxStalenessLimit,
+      defaultMaxConfidenceInterval: types.DslaDecimal.toEncodable(
+        fields.defaultMaxConfidenceInterval
+      ),
+      maxConfidenceIntervalLimit: types.DslaDecimal.toEncodable(
+        fields.maxConfidenceIntervalLimit
+      ),
+      attestationWindow: fields.attestationWindow,
+      disputeWindow: fields.disputeWindow,
+      proposalBond: fields.proposalBond,
+      disputeBond: fields.disputeBond,
+      arbiter: fields.arbiter,
+      protocolTreasury: fields.protocolTreasury,
+      depositReclaimGracePeriod: fields.depositReclaimGracePeriod,
+      depositReclaimPolicy: fields.depositReclaimPolicy.toEncodable(),
+      slaCancellationFeeRate: types.DslaDecimal.toEncodable(
+        fields.slaCancellationFeeRate
+      ),
+      guardian: fields.guardian,
+    }
+  }
+
+  toJSON(): GovernanceParametersJSON {
+    return {
+      dslaDepositByPeriod: this.dslaDepositByPeriod.toString(),
+      dslaProtocolReward: this.dslaProtocolReward.toString(),
+      dslaValidatorReward: this.dslaValidatorReward.toString(),
+      dslaBurnedByVerification: this.dslaBurnedByVerification.toString(),
+      slaDeployerRewardsRate: this.slaDeployerRewardsRate.toJSON(),
+      protocolRewardsRate: this.protocolRewardsRate.toJSON(),
+      maxLeverage: this.maxLeverage.toJSON(),
+      defaultMaxStaleness: this.defaultMaxStaleness,
+      maxStalenessLimit: this.maxStalenessLimit,
+      defaultMaxConfidenceInterval: this.defaultMaxConfidenceInterval.toJSON(),
+      maxConfidenceIntervalLimit: this.maxConfidenceIntervalLimit.toJSON(),
+      attestationWindow: this.attestationWindow,
+      disputeWindow: this.disputeWindow,
+      proposalBond: this.proposalBond.toString(),
+      disputeBond: this.disputeBond.toString(),
+      arbiter: this.arbiter.toString(),
+      protocolTreasury: this.protocolTreasury.toString(),
+      depositReclaimGracePeriod: this.depositReclaimGracePeriod,
+      depositReclaimPolicy: this.depositReclaimPolicy.toJSON(),
+      slaCancellationFeeRate: this.slaCancellationFeeRate.toJSON(),
+      guardian: this.guardian.toString(),
+    }
+  }
+
+  static fromJSON(obj: GovernanceParametersJSON): GovernanceParameters {
+    return new GovernanceParameters({
+      dslaDepositByPeriod: new BN(obj.dslaDepositByPeriod),
+      dslaProtocolReward: new BN(obj.dslaProtocolReward),
+      dslaValidatorReward: new BN(obj.dslaValidatorReward),
+      dslaBurnedByVerification: new BN(obj.dslaBurnedByVerification),
+      slaDeployerRewardsRate: types.DslaDecimal.fromJSON(
+        obj.slaDeployerRewardsRate
+      ),
+      protocolRewardsRate: types.DslaDecimal.fromJSON(obj.protocolRewardsRate),
+      maxLeverage: types.DslaDecimal.fromJSON(obj.maxLeverage),
+      defaultMaxStaleness: obj.defaultMaxStaleness,
+      maxStalenessLimit: obj.maxStalenessLimit,
+      defaultMaxConfidenceInterval: types.DslaDecimal.fromJSON(
+        obj.defaultMaxConfidenceInterval
+      ),
+      maxConfidenceIntervalLimit: types.DslaDecimal.fromJSON(
+        obj.maxConfidenceIntervalLimit
+      ),
+      attestationWindow: obj.attestationWindow,
+      disputeWindow: obj.disputeWindow,
+      proposalBond: new BN(obj.proposalBond),
+      disputeBond: new BN(obj.disputeBond),
+      arbiter: new PublicKey(obj.arbiter),
+      protocolTreasury: new PublicKey(obj.protocolTreasury),
+      depositReclaimGracePeriod: obj.depositReclaimGracePeriod,
+      depositReclaimPolicy: types.DepositReclaimPolicy.fromJSON(
+        obj.depositReclaimPolicy
+      ),
+      slaCancellationFeeRate: types.DslaDecimal.fromJSON(
+        obj.slaCancellationFeeRate
+      ),
+      guardian: new PublicKey(obj.guardian),
+    })
+  }
+
+  toEncodable() {
+    return GovernanceParameters.toEncodable(this)
+  }
+}
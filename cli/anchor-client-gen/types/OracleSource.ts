@@ -0,0 +1,148 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@project-serum/borsh"
+
+export interface SwitchboardJSON {
+  kind: "Switchboard"
+}
+
+export class Switchboard {
+  static readonly discriminator = 0
+  static readonly kind = "Switchboard"
+  readonly discriminator = 0
+  readonly kind = "Switchboard"
+
+  toJSON(): SwitchboardJSON {
+    return {
+      kind: "Switchboard",
+    }
+  }
+
+  toEncodable() {
+    return {
+      Switchboard: {},
+    }
+  }
+}
+
+export interface PythJSON {
+  kind: "Pyth"
+}
+
+export class Pyth {
+  static readonly discriminator = 1
+  static readonly kind = "Pyth"
+  readonly discriminator = 1
+  readonly kind = "Pyth"
+
+  toJSON(): PythJSON {
+    return {
+      kind: "Pyth",
+    }
+  }
+
+  toEncodable() {
+    return {
+      Pyth: {},
+    }
+  }
+}
+
+export interface OptimisticJSON {
+  kind: "Optimistic"
+}
+
+export class Optimistic {
+  static readonly discriminator = 2
+  static readonly kind = "Optimistic"
+  readonly discriminator = 2
+  readonly kind = "Optimistic"
+
+  toJSON(): OptimisticJSON {
+    return {
+      kind: "Optimistic",
+    }
+  }
+
+  toEncodable() {
+    return {
+      Optimistic: {},
+    }
+  }
+}
+
+export interface AttestationJSON {
+  kind: "Attestation"
+}
+
+export class Attestation {
+  static readonly discriminator = 3
+  static readonly kind = "Attestation"
+  readonly discriminator = 3
+  readonly kind = "Attestation"
+
+  toJSON(): AttestationJSON {
+    return {
+      kind: "Attestation",
+    }
+  }
+
+  toEncodable() {
+    return {
+      Attestation: {},
+    }
+  }
+}
+
+// eslint-disable-next-line @typescript-eslint/no-explicit-any
+export function fromDecoded(obj: any): types.OracleSourceKind {
+  if (typeof obj !== "object") {
+    throw new Error("Invalid enum object")
+  }
+
+  if ("Switchboard" in obj) {
+    return new Switchboard()
+  }
+  if ("Pyth" in obj) {
+    return new Pyth()
+  }
+  if ("Optimistic" in obj) {
+    return new Optimistic()
+  }
+  if ("Attestation" in obj) {
+    return new Attestation()
+  }
+
+  throw new Error("Invalid enum object")
+}
+
+export function fromJSON(obj: types.OracleSourceJSON): types.OracleSourceKind {
+  switch (obj.kind) {
+    case "Switchboard": {
+      return new Switchboard()
+    }
+    case "Pyth": {
+      return new Pyth()
+    }
+    case "Optimistic": {
+      return new Optimistic()
+    }
+    case "Attestation": {
+      return new Attestation()
+    }
+  }
+}
+
+export function layout(property?: string) {
+  const ret = borsh.rustEnum([
+    borsh.struct([], "Switchboard"),
+    borsh.struct([], "Pyth"),
+    borsh.struct([], "Optimistic"),
+    borsh.struct([], "Attestation"),
+  ])
+  if (property !== undefined) {
+    return ret.replicate(property)
+  }
+  return ret
+}
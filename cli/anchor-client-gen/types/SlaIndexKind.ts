@@ -0,0 +1,88 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@project-serum/borsh"
+
+export interface DeployerJSON {
+  kind: "Deployer"
+}
+
+export class Deployer {
+  static readonly discriminator = 0
+  static readonly kind = "Deployer"
+  readonly discriminator = 0
+  readonly kind = "Deployer"
+
+  toJSON(): DeployerJSON {
+    return {
+      kind: "Deployer",
+    }
+  }
+
+  toEncodable() {
+    return {
+      Deployer: {},
+    }
+  }
+}
+
+export interface MintJSON {
+  kind: "Mint"
+}
+
+export class Mint {
+  static readonly discriminator = 1
+  static readonly kind = "Mint"
+  readonly discriminator = 1
+  readonly kind = "Mint"
+
+  toJSON(): MintJSON {
+    return {
+      kind: "Mint",
+    }
+  }
+
+  toEncodable() {
+    return {
+      Mint: {},
+    }
+  }
+}
+
+// eslint-disable-next-line @typescript-eslint/no-explicit-any
+export function fromDecoded(obj: any): types.SlaIndexKindKind {
+  if (typeof obj !== "object") {
+    throw new Error("Invalid enum object")
+  }
+
+  if ("Deployer" in obj) {
+    return new Deployer()
+  }
+  if ("Mint" in obj) {
+    return new Mint()
+  }
+
+  throw new Error("Invalid enum object")
+}
+
+export function fromJSON(obj: types.SlaIndexKindJSON): types.SlaIndexKindKind {
+  switch (obj.kind) {
+    case "Deployer": {
+      return new Deployer()
+    }
+    case "Mint": {
+      return new Mint()
+    }
+  }
+}
+
+export function layout(property?: string) {
+  const ret = borsh.rustEnum([
+    borsh.struct([], "Deployer"),
+    borsh.struct([], "Mint"),
+  ])
+  if (property !== undefined) {
+    return ret.replicate(property)
+  }
+  return ret
+}
@@ -1,9 +1,18 @@
 import * as FeedErrorCode from "./FeedErrorCode"
+import * as DepositReclaimPolicy from "./DepositReclaimPolicy"
+import * as OracleSource from "./OracleSource"
+import * as AggregationPolicy from "./AggregationPolicy"
+import * as SlaIndexKind from "./SlaIndexKind"
 import * as SloType from "./SloType"
 import * as PeriodLength from "./PeriodLength"
 import * as SlaStatus from "./SlaStatus"
 import * as Status from "./Status"
 
+export { GovernanceParameters } from "./GovernanceParameters"
+export type {
+  GovernanceParametersFields,
+  GovernanceParametersJSON,
+} from "./GovernanceParameters"
 export { Slo } from "./Slo"
 export type { SloFields, SloJSON } from "./Slo"
 export { DslaDecimal } from "./DslaDecimal"
@@ -24,6 +33,50 @@ export type FeedErrorCodeJSON =
   | FeedErrorCode.StaleFeedJSON
   | FeedErrorCode.ConfidenceIntervalExceededJSON
 
+export { DepositReclaimPolicy }
+
+/** who gets the DSLA deposit of the periods of an SLA that were never verified */
+export type DepositReclaimPolicyKind =
+  | DepositReclaimPolicy.Deployer
+  | DepositReclaimPolicy.ProtocolTreasury
+export type DepositReclaimPolicyJSON =
+  | DepositReclaimPolicy.DeployerJSON
+  | DepositReclaimPolicy.ProtocolTreasuryJSON
+
+export { OracleSource }
+
+/** the oracle network the feeds of an `Sla` belong to */
+export type OracleSourceKind =
+  | OracleSource.Switchboard
+  | OracleSource.Pyth
+  | OracleSource.Optimistic
+  | OracleSource.Attestation
+export type OracleSourceJSON =
+  | OracleSource.SwitchboardJSON
+  | OracleSource.PythJSON
+  | OracleSource.OptimisticJSON
+  | OracleSource.AttestationJSON
+
+export { AggregationPolicy }
+
+/** how the results of the different oracle feeds of an `Sla` are combined into a single SLI */
+export type AggregationPolicyKind =
+  | AggregationPolicy.Median
+  | AggregationPolicy.Min
+  | AggregationPolicy.Max
+  | AggregationPolicy.TrimmedMean
+export type AggregationPolicyJSON =
+  | AggregationPolicy.MedianJSON
+  | AggregationPolicy.MinJSON
+  | AggregationPolicy.MaxJSON
+  | AggregationPolicy.TrimmedMeanJSON
+
+export { SlaIndexKind }
+
+/** the kind of key the SLAs of an index are registered by */
+export type SlaIndexKindKind = SlaIndexKind.Deployer | SlaIndexKind.Mint
+export type SlaIndexKindJSON = SlaIndexKind.DeployerJSON | SlaIndexKind.MintJSON
+
 export { SloType }
 
 /** what type of service level objective is this `Slo` */
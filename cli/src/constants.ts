@@ -1,11 +1,14 @@
 import { Keypair, PublicKey } from "@solana/web3.js";
-export const SLA_REGISTRY_SPACE = 5000; // MAX 10 MB
 import BN from "bn.js";
 import {
   PeriodLength,
   Slo,
   SloType,
   DslaDecimal,
+  OracleSource,
+  AggregationPolicy,
+  DepositReclaimPolicy,
+  GovernanceParametersFields,
 } from "../anchor-client-gen/types";
 // SEEDS
 export const SLA_AUTHORITY_SEED = "sla-authority";
@@ -17,10 +20,17 @@ export const PT_MINT_SEED = "pt-mint";
 export const UT_ACCOUNT_SEED = "ut-account";
 export const PT_ACCOUNT_SEED = "pt-account";
 export const GOVERNANCE_SEED = "governance";
+export const GOVERNANCE_CHANGE_SEED = "governance-change";
 export const REWARD_SEED = "reward";
 export const PERIOD_GENERATOR_SEED = "period-generator";
 export const LOCKUP_PROVIDER_SEED = "provider-lockup";
 export const LOCKUP_USER_SEED = "user-lockup";
+export const SLA_REGISTRY_HEADER_SEED = "sla-registry-header";
+export const SLA_REGISTRY_PAGE_SEED = "sla-registry-page";
+export const SLA_REGISTRY_ENTRY_SEED = "sla-registry-entry";
+export const DEPLOYER_INDEX_SEED = "deployer-index";
+export const MINT_INDEX_SEED = "mint-index";
+export const SLA_INDEX_PAGE_SEED = "sla-index-page";
 
 // KEYPAIRS
 export const SLA_PROTOCOL_DEPLOYER = new Keypair({
@@ -37,17 +47,6 @@ export const SLA_PROTOCOL_DEPLOYER = new Keypair({
 });
 
 // DEVNET ADDRESSESS
-export const SLA_REGISTRY_ADDRESS = new Keypair({
-  publicKey: new PublicKey(
-    "EhvvxtcmjriJCFGTCBR8Ng81KhsbmSVw1rEXn4ja7nvg"
-  ).toBuffer(),
-  secretKey: Uint8Array.from([
-    5, 222, 13, 237, 210, 14, 68, 39, 28, 107, 232, 16, 48, 86, 197, 43, 249,
-    19, 198, 164, 255, 57, 113, 233, 188, 64, 2, 200, 221, 229, 252, 109, 203,
-    164, 36, 71, 51, 155, 230, 95, 87, 82, 195, 221, 154, 88, 185, 93, 188, 90,
-    157, 1, 28, 83, 178, 254, 86, 189, 210, 24, 165, 144, 82, 253,
-  ]),
-});
 export const DSLA_MINT = new PublicKey(
   "F9Q9oG47N9P3GbwiD7p5VKYZ1Sw2VawnoA6KyxHxUTRj"
 );
@@ -65,7 +64,7 @@ export const SLA_ADDRESS = new PublicKey(
 // VARIABLES
 // Configure the client to use the env cluster.
 const dslaDepositByPeriod = 250000000;
-export const GOVERNANCE_PARAMETERS = {
+export const GOVERNANCE_PARAMETERS: GovernanceParametersFields = {
   dslaDepositByPeriod: new BN(dslaDepositByPeriod),
   dslaProtocolReward: new BN(dslaDepositByPeriod * 0.5), // 50%
   dslaValidatorReward: new BN(dslaDepositByPeriod * 0.25), // 25%
@@ -79,6 +78,29 @@ export const GOVERNANCE_PARAMETERS = {
     scale: 4,
   }), // 0.15%
   maxLeverage: new DslaDecimal({ mantissa: new BN(1), scale: 0 }),
+  defaultMaxStaleness: 60 * 60, // 1 hour
+  maxStalenessLimit: 24 * 60 * 60, // 1 day
+  defaultMaxConfidenceInterval: new DslaDecimal({
+    mantissa: new BN(1),
+    scale: 2,
+  }), // 0.01
+  maxConfidenceIntervalLimit: new DslaDecimal({
+    mantissa: new BN(1),
+    scale: 1,
+  }), // 0.1
+  attestationWindow: 60 * 60, // 1 hour
+  disputeWindow: 24 * 60 * 60, // 1 day
+  proposalBond: new BN(dslaDepositByPeriod),
+  disputeBond: new BN(dslaDepositByPeriod),
+  arbiter: SLA_PROTOCOL_DEPLOYER.publicKey,
+  protocolTreasury: SLA_PROTOCOL_DEPLOYER.publicKey,
+  depositReclaimGracePeriod: 30 * 24 * 60 * 60, // 30 days
+  depositReclaimPolicy: new DepositReclaimPolicy.ProtocolTreasury(),
+  slaCancellationFeeRate: new DslaDecimal({
+    mantissa: new BN(1),
+    scale: 1,
+  }), // 10%
+  guardian: SLA_PROTOCOL_DEPLOYER.publicKey,
 };
 
 export const SLOS = [
@@ -114,6 +136,13 @@ export const SLAS = [
     periodLength: new PeriodLength.Custom({
       length: new BN(60), //each period is 1 minute
     }),
+    severity: [],
+    penalty: [],
+    oracleSource: new OracleSource.Switchboard(),
+    aggregationPolicy: new AggregationPolicy.Median(),
+    minQuorum: 1,
+    maxStaleness: null,
+    maxConfidenceInterval: null,
   },
   {
     slo: SLOS[1],
@@ -123,6 +152,13 @@ export const SLAS = [
     periodLength: new PeriodLength.Custom({
       length: new BN(1000 * 60),
     }),
+    severity: [],
+    penalty: [],
+    oracleSource: new OracleSource.Switchboard(),
+    aggregationPolicy: new AggregationPolicy.Median(),
+    minQuorum: 1,
+    maxStaleness: null,
+    maxConfidenceInterval: null,
   },
   {
     slo: SLOS[2],
@@ -132,5 +168,12 @@ export const SLAS = [
     periodLength: new PeriodLength.Custom({
       length: new BN(1000 * 60 * 60 * 24),
     }),
+    severity: [],
+    penalty: [],
+    oracleSource: new OracleSource.Switchboard(),
+    aggregationPolicy: new AggregationPolicy.Median(),
+    minQuorum: 1,
+    maxStaleness: null,
+    maxConfidenceInterval: null,
   },
 ];
@@ -1,18 +1,43 @@
-import { initGovernance } from "../anchor-client-gen/instructions";
+import {
+  initGovernance,
+  proposeGovernanceChange,
+  executeGovernanceChange,
+  cancelGovernanceChange,
+  transferGovernanceAuthority,
+  acceptGovernanceAuthority,
+} from "../anchor-client-gen/instructions";
 import { PROGRAM_ID } from "../anchor-client-gen/programId";
 import {
   SLA_PROTOCOL_DEPLOYER,
   GOVERNANCE_PARAMETERS,
   GOVERNANCE_SEED,
+  GOVERNANCE_CHANGE_SEED,
+  DSLA_MINT,
 } from "./constants";
 import {
+  Keypair,
   PublicKey,
   Transaction,
   SystemProgram,
   Connection,
   sendAndConfirmTransaction,
 } from "@solana/web3.js";
-import { Governance } from "../anchor-client-gen/accounts";
+import { Governance, GovernanceChange } from "../anchor-client-gen/accounts";
+import { GovernanceParametersFields } from "../anchor-client-gen/types";
+
+function governancePda() {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from(GOVERNANCE_SEED)],
+    PROGRAM_ID
+  )[0];
+}
+
+function governanceChangePda() {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from(GOVERNANCE_CHANGE_SEED)],
+    PROGRAM_ID
+  )[0];
+}
 
 export async function initGovernanceTx(connection: Connection) {
   // call an instruction
@@ -30,10 +55,11 @@ export async function initGovernanceTx(connection: Connection) {
 
   tx.add(
     initGovernance(
-      { ...GOVERNANCE_PARAMETERS },
+      { parameters: GOVERNANCE_PARAMETERS },
       {
         programUpgradeAuthority: SLA_PROTOCOL_DEPLOYER.publicKey,
         governance: goveranncePda,
+        dslaMint: DSLA_MINT,
         program: PROGRAM_ID,
         programData: programDataPda,
         systemProgram: SystemProgram.programId,
@@ -61,3 +87,109 @@ export async function fetch_governance_account(connection: Connection) {
   const obj = acc.toJSON();
   return obj;
 }
+
+// proposes new governance parameters, they can be executed once the timelock ended
+export async function proposeGovernanceChangeTx(
+  connection: Connection,
+  parameters: GovernanceParametersFields
+) {
+  const tx = new Transaction();
+  tx.add(
+    proposeGovernanceChange(
+      { parameters },
+      {
+        governanceAuthority: SLA_PROTOCOL_DEPLOYER.publicKey,
+        governance: governancePda(),
+        governanceChange: governanceChangePda(),
+        systemProgram: SystemProgram.programId,
+      }
+    )
+  );
+
+  return await sendAndConfirmTransaction(connection, tx, [
+    SLA_PROTOCOL_DEPLOYER,
+  ]);
+}
+
+export async function executeGovernanceChangeTx(connection: Connection) {
+  const governanceChange = await GovernanceChange.fetch(
+    connection,
+    governanceChangePda()
+  );
+  if (governanceChange === null) {
+    throw new Error("no governance change is pending");
+  }
+
+  const tx = new Transaction();
+  tx.add(
+    executeGovernanceChange({
+      governance: governancePda(),
+      governanceChange: governanceChangePda(),
+      proposer: governanceChange.proposer,
+    })
+  );
+
+  return await sendAndConfirmTransaction(connection, tx, [
+    SLA_PROTOCOL_DEPLOYER,
+  ]);
+}
+
+export async function cancelGovernanceChangeTx(connection: Connection) {
+  const governanceChange = await GovernanceChange.fetch(
+    connection,
+    governanceChangePda()
+  );
+  if (governanceChange === null) {
+    throw new Error("no governance change is pending");
+  }
+
+  const tx = new Transaction();
+  tx.add(
+    cancelGovernanceChange({
+      governanceAuthority: SLA_PROTOCOL_DEPLOYER.publicKey,
+      governance: governancePda(),
+      governanceChange: governanceChangePda(),
+      proposer: governanceChange.proposer,
+    })
+  );
+
+  return await sendAndConfirmTransaction(connection, tx, [
+    SLA_PROTOCOL_DEPLOYER,
+  ]);
+}
+
+// the new governance authority needs to accept the transfer with `acceptGovernanceAuthorityTx`
+export async function transferGovernanceAuthorityTx(
+  connection: Connection,
+  newAuthority: PublicKey
+) {
+  const tx = new Transaction();
+  tx.add(
+    transferGovernanceAuthority(
+      { newAuthority },
+      {
+        governanceAuthority: SLA_PROTOCOL_DEPLOYER.publicKey,
+        governance: governancePda(),
+      }
+    )
+  );
+
+  return await sendAndConfirmTransaction(connection, tx, [
+    SLA_PROTOCOL_DEPLOYER,
+  ]);
+}
+
+export async function acceptGovernanceAuthorityTx(
+  connection: Connection,
+  newAuthority: Keypair
+) {
+  const tx = new Transaction();
+  tx.add(
+    acceptGovernanceAuthority({
+      newAuthority: newAuthority.publicKey,
+      governance: governancePda(),
+    })
+  );
+
+  return await sendAndConfirmTransaction(connection, tx, [newAuthority]);
+}
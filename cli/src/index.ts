@@ -1,8 +1,17 @@
 import * as dotenv from "dotenv";
-import { Connection } from "@solana/web3.js";
+import { Connection, PublicKey } from "@solana/web3.js";
+import BN from "bn.js";
 import yargs from "yargs/yargs";
 import { hideBin } from "yargs/helpers";
-import { fetch_governance_account, initGovernanceTx } from "./governance";
+import {
+  fetch_governance_account,
+  initGovernanceTx,
+  proposeGovernanceChangeTx,
+  executeGovernanceChangeTx,
+  cancelGovernanceChangeTx,
+  transferGovernanceAuthorityTx,
+} from "./governance";
+import { GOVERNANCE_PARAMETERS } from "./constants";
 import { create_aggregator_account } from "./switchboard";
 import { initSlaRegistryTx, fetch_sla_registry_account } from "./sla_registry";
 import { deploySlaTx } from "./sla";
@@ -18,12 +27,29 @@ async function main() {
   const connection = new Connection(process.env.RPC_PROVIDER);
 
   const argv = yargs(hideBin(process.argv)).argv;
+  // the fewest tokens a stake or a withdrawal accepts to get, see `min_tokens_out` in the program
+  const minTokensOut = new BN(argv["min_tokens_out"] ?? 0);
   if (argv["init_governance"]) {
     let tx = await initGovernanceTx(connection);
     console.log(
       "initialized governance successfully with transaction id: ",
       tx
     );
+  } else if (argv["propose_governance_change"]) {
+    let tx = await proposeGovernanceChangeTx(connection, GOVERNANCE_PARAMETERS);
+    console.log("proposed governance change successfully: ", tx);
+  } else if (argv["execute_governance_change"]) {
+    let tx = await executeGovernanceChangeTx(connection);
+    console.log("executed governance change successfully: ", tx);
+  } else if (argv["cancel_governance_change"]) {
+    let tx = await cancelGovernanceChangeTx(connection);
+    console.log("cancelled governance change successfully: ", tx);
+  } else if (argv["transfer_governance_authority"]) {
+    let tx = await transferGovernanceAuthorityTx(
+      connection,
+      new PublicKey(argv["new_authority"])
+    );
+    console.log("transferred governance authority successfully: ", tx);
   } else if (argv["print_governance"]) {
     console.log(await fetch_governance_account(connection));
   } else if (argv["get_feed_data"]) {
@@ -44,19 +70,19 @@ async function main() {
     let tx = await initLockupAccountsTx(connection);
     console.log("initialized lockup accounts successfully: ", tx);
   } else if (argv["stake_provider"]) {
-    let tx = await stakerProviderTx(connection);
+    let tx = await stakerProviderTx(connection, minTokensOut);
     console.log("staked successfully: ", tx);
   } else if (argv["stake_user"]) {
-    let tx = await stakerUserTx(connection);
+    let tx = await stakerUserTx(connection, minTokensOut);
     console.log("staked successfully: ", tx);
   } else if (argv["validate_period"]) {
     let tx = await validatePeriodTx(connection);
     console.log("validated period successfully: ", tx);
   } else if (argv["withdraw_provider"]) {
-    let tx = await withdrawProviderTx(connection);
+    let tx = await withdrawProviderTx(connection, minTokensOut);
     console.log("withdrawn funds successfully: ", tx);
   } else if (argv["withdraw_user"]) {
-    let tx = await withdrawUserTx(connection);
+    let tx = await withdrawUserTx(connection, minTokensOut);
     console.log("withdrawn funds successfully: ", tx);
   } else {
     console.log(`received no command`);
@@ -1,6 +1,5 @@
 import {
   SLA_PROTOCOL_DEPLOYER,
-  SLA_REGISTRY_ADDRESS,
   RANDOM_MINT,
   DSLA_MINT,
   //   SLA_ADDRESS,
@@ -13,6 +12,7 @@ import {
   UT_MINT_SEED,
   PT_MINT_SEED,
   AGGREGATOR_ADDRESS,
+  SLA_REGISTRY_ENTRY_SEED,
 } from "./constants";
 import {
   getOrCreateAssociatedTokenAccount,
@@ -29,6 +29,7 @@ import {
   SYSVAR_RENT_PUBKEY,
 } from "@solana/web3.js";
 import { deploySla } from "../anchor-client-gen/instructions";
+import { registerSlaAccounts } from "./sla_registry";
 
 export async function deploySlaTx(connection: Connection) {
   const sla = SLAS[0];
@@ -66,6 +67,16 @@ export async function deploySlaTx(connection: Connection) {
     [Buffer.from(PT_MINT_SEED), slaKeypair.publicKey.toBuffer()],
     PROGRAM_ID
   )[0];
+  const slaRegistryEntryPda = PublicKey.findProgramAddressSync(
+    [Buffer.from(SLA_REGISTRY_ENTRY_SEED), slaKeypair.publicKey.toBuffer()],
+    PROGRAM_ID
+  )[0];
+  const registry = await registerSlaAccounts(
+    connection,
+    SLA_PROTOCOL_DEPLOYER.publicKey,
+    SLA_PROTOCOL_DEPLOYER.publicKey,
+    RANDOM_MINT
+  );
 
   /// TOKEN ACCOUNTS
   let deployerDslaTokenAccount = await getOrCreateAssociatedTokenAccount(
@@ -75,32 +86,37 @@ export async function deploySlaTx(connection: Connection) {
     SLA_PROTOCOL_DEPLOYER.publicKey // owner,
   );
 
-  tx.add(
-    deploySla(
-      { ...sla },
-      {
-        deployer: SLA_PROTOCOL_DEPLOYER.publicKey,
-        slaRegistry: SLA_REGISTRY_ADDRESS.publicKey,
-        sla: slaKeypair.publicKey,
-        slaAuthority: slaAuthorityPda,
-        statusRegistry: statusRegistryPda,
-        mint: RANDOM_MINT,
-        pool: poolPda,
-        dslaMint: DSLA_MINT,
-        dslaPool: dslaPoolPda,
-        /** The token account to pay the DSLA fee from */
-        deployerDslaTokenAccount: deployerDslaTokenAccount.address,
-        governance: governancePda,
-        utMint: utMintPda,
-        ptMint: ptMintPda,
-        aggregator: AGGREGATOR_ADDRESS,
-        /** The program for interacting with the token. */
-        tokenProgram: TOKEN_PROGRAM_ID,
-        rent: SYSVAR_RENT_PUBKEY,
-        systemProgram: SystemProgram.programId,
-      }
-    )
+  const deployIx = deploySla(
+    { ...sla },
+    {
+      deployer: SLA_PROTOCOL_DEPLOYER.publicKey,
+      ...registry.accounts,
+      slaRegistryEntry: slaRegistryEntryPda,
+      sla: slaKeypair.publicKey,
+      slaAuthority: slaAuthorityPda,
+      statusRegistry: statusRegistryPda,
+      mint: RANDOM_MINT,
+      pool: poolPda,
+      dslaMint: DSLA_MINT,
+      dslaPool: dslaPoolPda,
+      /** The token account to pay the DSLA fee from */
+      deployerDslaTokenAccount: deployerDslaTokenAccount.address,
+      governance: governancePda,
+      utMint: utMintPda,
+      ptMint: ptMintPda,
+      /** The program for interacting with the token. */
+      tokenProgram: TOKEN_PROGRAM_ID,
+      rent: SYSVAR_RENT_PUBKEY,
+      systemProgram: SystemProgram.programId,
+    }
   );
+  // the oracle feeds of the SLA are passed as remaining accounts
+  deployIx.keys.push({
+    pubkey: AGGREGATOR_ADDRESS,
+    isSigner: false,
+    isWritable: false,
+  });
+  tx.add(...registry.instructions, deployIx);
 
   return await sendAndConfirmTransaction(connection, tx, [
     SLA_PROTOCOL_DEPLOYER,
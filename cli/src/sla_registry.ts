@@ -1,68 +1,152 @@
-import { initSlaRegistry } from "../anchor-client-gen/instructions";
+import {
+  initSlaRegistry,
+  addSlaRegistryPage,
+} from "../anchor-client-gen/instructions";
 import {
   SLA_PROTOCOL_DEPLOYER,
-  SLA_REGISTRY_SPACE,
-  SLA_REGISTRY_ADDRESS,
+  SLA_REGISTRY_HEADER_SEED,
+  SLA_REGISTRY_PAGE_SEED,
+  SLA_INDEX_PAGE_SEED,
+  DEPLOYER_INDEX_SEED,
+  MINT_INDEX_SEED,
 } from "./constants";
 import {
   Transaction,
+  TransactionInstruction,
   SystemProgram,
   Connection,
+  PublicKey,
   sendAndConfirmTransaction,
-  Keypair,
 } from "@solana/web3.js";
-import { SlaRegistry } from "../anchor-client-gen/accounts";
+import BN from "bn.js";
+import {
+  SlaRegistryHeader,
+  SlaRegistryPage,
+} from "../anchor-client-gen/accounts";
 import { PROGRAM_ID } from "../anchor-client-gen/programId";
 
-export async function initSlaRegistryTx(connection: Connection) {
-  const slaRegistryKeypair = Keypair.generate();
+export function slaRegistryPda() {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from(SLA_REGISTRY_HEADER_SEED)],
+    PROGRAM_ID
+  )[0];
+}
+
+export function slaRegistryPagePda(page: BN) {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from(SLA_REGISTRY_PAGE_SEED), page.toArrayLike(Buffer, "le", 8)],
+    PROGRAM_ID
+  )[0];
+}
+
+export function slaIndexPda(seed: string, indexedKey: PublicKey) {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from(seed), indexedKey.toBuffer()],
+    PROGRAM_ID
+  )[0];
+}
+
+export function slaIndexPagePda(slaIndex: PublicKey, page: BN) {
+  return PublicKey.findProgramAddressSync(
+    [
+      Buffer.from(SLA_INDEX_PAGE_SEED),
+      slaIndex.toBuffer(),
+      page.toArrayLike(Buffer, "le", 8),
+    ],
+    PROGRAM_ID
+  )[0];
+}
 
-  // Seed the created account with lamports for rent exemption
-  const rentExemptionAmount =
-    await connection.getMinimumBalanceForRentExemption(SLA_REGISTRY_SPACE);
+// the page storing the next SLA of the registry or index, a new one is needed when all the pages are full
+function nextPage(header: SlaRegistryHeader) {
+  return header.slaCount.divn(header.pageSize);
+}
 
-  // call an instruction
+export async function initSlaRegistryTx(connection: Connection) {
   const tx = new Transaction();
-  console.log(
-    "sla registry public key: ",
-    slaRegistryKeypair.publicKey.toString()
-  );
-  console.log(
-    "sla registry secret key: ",
-    slaRegistryKeypair.secretKey.toString()
-  );
-  tx.add(
-    SystemProgram.createAccount({
-      fromPubkey: SLA_PROTOCOL_DEPLOYER.publicKey,
-      newAccountPubkey: slaRegistryKeypair.publicKey,
-      lamports: rentExemptionAmount,
-      space: SLA_REGISTRY_SPACE,
-      programId: PROGRAM_ID,
-    })
-  );
   tx.add(
     initSlaRegistry({
       deployer: SLA_PROTOCOL_DEPLOYER.publicKey,
-      slaRegistry: slaRegistryKeypair.publicKey,
+      slaRegistry: slaRegistryPda(),
+      slaRegistryPage: slaRegistryPagePda(new BN(0)),
       systemProgram: SystemProgram.programId,
     })
   );
 
   return await sendAndConfirmTransaction(connection, tx, [
     SLA_PROTOCOL_DEPLOYER,
-    slaRegistryKeypair,
   ]);
 }
 
+// returns the registry accounts to deploy an SLA of `deployer` for `mint` and the instructions
+// to run before, to add a page to the registry when it's full
+export async function registerSlaAccounts(
+  connection: Connection,
+  payer: PublicKey,
+  deployer: PublicKey,
+  mint: PublicKey
+) {
+  const instructions: TransactionInstruction[] = [];
+
+  const slaRegistry = slaRegistryPda();
+  const registry = await SlaRegistryHeader.fetch(connection, slaRegistry);
+  if (registry === null) {
+    throw new Error("the SLA registry needs to be initialized first");
+  }
+  const registryPage = nextPage(registry);
+  if (registryPage.eq(registry.pageCount)) {
+    instructions.push(
+      addSlaRegistryPage({
+        payer,
+        slaRegistry,
+        slaRegistryPage: slaRegistryPagePda(registryPage),
+        systemProgram: SystemProgram.programId,
+      })
+    );
+  }
+
+  // the indexes and their pages are created on demand by the deployment
+  const indexes = [
+    { seed: DEPLOYER_INDEX_SEED, key: deployer },
+    { seed: MINT_INDEX_SEED, key: mint },
+  ].map(async ({ seed, key }) => {
+    const slaIndex = slaIndexPda(seed, key);
+    const index = await SlaRegistryHeader.fetch(connection, slaIndex);
+    const page = index === null ? new BN(0) : nextPage(index);
+    return { slaIndex, slaIndexPage: slaIndexPagePda(slaIndex, page) };
+  });
+  const [deployerIndex, mintIndex] = await Promise.all(indexes);
+
+  return {
+    instructions,
+    accounts: {
+      slaRegistry,
+      slaRegistryPage: slaRegistryPagePda(registryPage),
+      deployerIndex: deployerIndex.slaIndex,
+      deployerIndexPage: deployerIndex.slaIndexPage,
+      mintIndex: mintIndex.slaIndex,
+      mintIndexPage: mintIndex.slaIndexPage,
+    },
+  };
+}
+
 export async function fetch_sla_registry_account(connection: Connection) {
-  const acc = await SlaRegistry.fetch(
-    connection,
-    SLA_REGISTRY_ADDRESS.publicKey
-  );
+  const acc = await SlaRegistryHeader.fetch(connection, slaRegistryPda());
   if (acc === null) {
     console.log("account not found");
+    return null;
   }
+  const pages = await SlaRegistryPage.fetchMultiple(
+    connection,
+    [...Array(acc.pageCount.toNumber()).keys()].map((page) =>
+      slaRegistryPagePda(new BN(page))
+    )
+  );
   // convert to a JSON object
-  const obj = acc.toJSON();
-  return obj;
+  return {
+    ...acc.toJSON(),
+    slaAccountAddresses: pages.flatMap((page) =>
+      page.slaAccountAddresses.map((address) => address.toString())
+    ),
+  };
 }
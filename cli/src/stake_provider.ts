@@ -9,6 +9,7 @@ import {
   PT_MINT_SEED,
   SLA_AUTHORITY_SEED,
 } from "./constants";
+import { governancePda } from "./governance";
 import {
   PublicKey,
   Transaction,
@@ -23,7 +24,10 @@ import {
   TOKEN_PROGRAM_ID,
 } from "@solana/spl-token";
 
-export async function stakerProviderTx(connection: Connection) {
+export async function stakerProviderTx(
+  connection: Connection,
+  minTokensOut: BN
+) {
   // call an instruction
   const tx = new Transaction();
 
@@ -70,10 +74,12 @@ export async function stakerProviderTx(connection: Connection) {
     stakeProvider(
       {
         tokenAmount: new BN(10_000_000_000),
+        minTokensOut,
       },
       {
         staker: SLA_PROTOCOL_DEPLOYER.publicKey,
         sla: SLA_ADDRESS,
+        governance: governancePda(),
         slaAuthority: slaAuthorityPda,
         mint: RANDOM_MINT,
         pool: poolPda,
@@ -9,6 +9,7 @@ import {
   UT_MINT_SEED,
   SLA_AUTHORITY_SEED,
 } from "./constants";
+import { governancePda } from "./governance";
 import {
   PublicKey,
   Transaction,
@@ -23,7 +24,7 @@ import {
   TOKEN_PROGRAM_ID,
 } from "@solana/spl-token";
 
-export async function stakerUserTx(connection: Connection) {
+export async function stakerUserTx(connection: Connection, minTokensOut: BN) {
   // call an instruction
   const tx = new Transaction();
 
@@ -70,10 +71,12 @@ export async function stakerUserTx(connection: Connection) {
     stakeUser(
       {
         tokenAmount: new BN(1_000_000_000),
+        minTokensOut,
       },
       {
         staker: SLA_PROTOCOL_DEPLOYER.publicKey,
         sla: SLA_ADDRESS,
+        governance: governancePda(),
         slaAuthority: slaAuthorityPda,
         mint: RANDOM_MINT,
         pool: poolPda,
@@ -29,15 +29,13 @@ import {
   TOKEN_PROGRAM_ID,
 } from "@solana/spl-token";
 
-export async function withdrawProviderTx(connection: Connection) {
+export async function withdrawProviderTx(
+  connection: Connection,
+  minTokensOut: BN
+) {
   // call an instruction
   const tx = new Transaction();
 
-  const programDataPda = PublicKey.findProgramAddressSync(
-    [PROGRAM_ID.toBuffer()],
-    new PublicKey("BPFLoaderUpgradeab1e11111111111111111111111")
-  )[0];
-
   const slaAuthorityPda = PublicKey.findProgramAddressSync(
     [Buffer.from(SLA_AUTHORITY_SEED), SLA_ADDRESS.toBuffer()],
     PROGRAM_ID
@@ -85,6 +83,7 @@ export async function withdrawProviderTx(connection: Connection) {
     withdrawProvider(
       {
         tokenAmount: new BN(1_000_000),
+        minTokensOut,
       },
       {
         withdrawer: SLA_PROTOCOL_DEPLOYER.publicKey,
@@ -98,8 +97,6 @@ export async function withdrawProviderTx(connection: Connection) {
         ptMint: ptMintPda,
         governance: governancePda,
         tokenProgram: TOKEN_PROGRAM_ID,
-        program: PROGRAM_ID,
-        programData: programDataPda,
         protocolTokenAccount: withdrawerTokenAccount.address,
         deployerTokenAccount: withdrawerTokenAccount.address,
         rent: SYSVAR_RENT_PUBKEY,
@@ -113,15 +110,10 @@ export async function withdrawProviderTx(connection: Connection) {
   ]);
 }
 
-export async function withdrawUserTx(connection: Connection) {
+export async function withdrawUserTx(connection: Connection, minTokensOut: BN) {
   // call an instruction
   const tx = new Transaction();
 
-  const programDataPda = PublicKey.findProgramAddressSync(
-    [PROGRAM_ID.toBuffer()],
-    new PublicKey("BPFLoaderUpgradeab1e11111111111111111111111")
-  )[0];
-
   const slaAuthorityPda = PublicKey.findProgramAddressSync(
     [Buffer.from(SLA_AUTHORITY_SEED), SLA_ADDRESS.toBuffer()],
     PROGRAM_ID
@@ -169,6 +161,7 @@ export async function withdrawUserTx(connection: Connection) {
     withdrawUser(
       {
         tokenAmount: new BN(1_000_000),
+        minTokensOut,
       },
       {
         withdrawer: SLA_PROTOCOL_DEPLOYER.publicKey,
@@ -182,8 +175,6 @@ export async function withdrawUserTx(connection: Connection) {
         utMint: utMintPda,
         governance: governancePda,
         tokenProgram: TOKEN_PROGRAM_ID,
-        program: PROGRAM_ID,
-        programData: programDataPda,
         protocolTokenAccount: withdrawerTokenAccount.address,
         deployerTokenAccount: withdrawerTokenAccount.address,
         rent: SYSVAR_RENT_PUBKEY,
@@ -1,3 +1,5 @@
+use anchor_lang::prelude::*;
+
 pub const DISCRIMINATOR_LENGTH: usize = 8;
 pub const PUBLIC_KEY_LENGTH: usize = 32;
 pub const SLA_AUTHORITY_SEED: &str = "sla-authority";
@@ -9,7 +11,42 @@ pub const PT_MINT_SEED: &str = "pt-mint";
 pub const UT_ACCOUNT_SEED: &str = "ut-account";
 pub const PT_ACCOUNT_SEED: &str = "pt-account";
 pub const GOVERNANCE_SEED: &str = "governance";
+pub const GOVERNANCE_CHANGE_SEED: &str = "governance-change";
+/// min number of seconds between the proposal of a governance change and its execution, 2 days
+pub const GOVERNANCE_CHANGE_DELAY: i64 = 2 * 24 * 60 * 60;
 pub const REWARD_SEED: &str = "reward";
 pub const PERIOD_GENERATOR_SEED: &str = "period-generator";
 pub const LOCKUP_PROVIDER_SEED: &str = "provider-lockup";
 pub const LOCKUP_USER_SEED: &str = "user-lockup";
+pub const PROPOSAL_SEED: &str = "proposal";
+pub const BOND_VAULT_SEED: &str = "bond-vault";
+pub const PYTH_SAMPLE_SEED: &str = "pyth-sample";
+pub const CHECKPOINT_SEED: &str = "checkpoint";
+pub const SLA_REGISTRY_HEADER_SEED: &str = "sla-registry-header";
+pub const SLA_REGISTRY_PAGE_SEED: &str = "sla-registry-page";
+pub const SLA_REGISTRY_ENTRY_SEED: &str = "sla-registry-entry";
+pub const DEPLOYER_INDEX_SEED: &str = "deployer-index";
+pub const MINT_INDEX_SEED: &str = "mint-index";
+pub const SLA_INDEX_PAGE_SEED: &str = "sla-index-page";
+/// number of SLAs stored in each page of the SLA registry
+pub const SLAS_PER_PAGE: u32 = 256;
+/// number of SLAs stored in each page of the deployer and mint indexes
+pub const SLAS_PER_INDEX_PAGE: u32 = 32;
+pub const MAX_ORACLES: usize = 8;
+/// max number of periods of an SLA, the status registry of the largest SLA fits in the
+/// 10KiB an account can be created or grown with
+pub const MAX_PERIODS: u32 = 700;
+/// decimals the UT and PT have on top of the decimals of the staked mint, every pool is priced with
+/// `VIRTUAL_SHARES` UT/PT and `VIRTUAL_ASSETS` tokens more than it has, so that inflating the share price
+/// of a pool costs `VIRTUAL_SHARES` times what the stakes that follow can lose to its rounding
+pub const SHARE_DECIMALS_OFFSET: u8 = 3;
+pub const VIRTUAL_SHARES: u128 = 10u128.pow(SHARE_DECIMALS_OFFSET as u32);
+pub const VIRTUAL_ASSETS: u128 = 1;
+/// max decimals of the staked mint, its UT and PT have up to `MAX_MINT_DECIMALS + SHARE_DECIMALS_OFFSET`
+/// decimals, 12, that clients need to handle
+pub const MAX_MINT_DECIMALS: u8 = 9;
+/// pyth oracle program on devnet like the switchboard program, gSbePebfvPy7tRqimPoVecS2UsBvYv46ynrzWocc92s
+pub const PYTH_PROGRAM_ID: Pubkey = Pubkey::new_from_array([
+    10, 26, 152, 51, 163, 118, 85, 43, 86, 183, 202, 13, 237, 25, 41, 23, 0, 87, 232, 39, 160, 198,
+    39, 244, 182, 71, 185, 238, 144, 153, 175, 180,
+]);
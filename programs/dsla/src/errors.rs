@@ -25,6 +25,88 @@ pub enum ErrorCode {
     NonValidGovernanceParameters, // 6009
     #[msg("Sla not started yet")]
     SlaNotStarted, // 6010
+    #[msg("No finished period left to verify")]
+    NoPendingPeriods, // 6011
+    #[msg("Period has no proposed SLI waiting for the end of the dispute window")]
+    PeriodNotProposed, // 6012
+    #[msg("Period has no disputed SLI waiting for the arbiter")]
+    PeriodNotDisputed, // 6013
+    #[msg("Dispute window of the proposed SLI has ended")]
+    DisputeWindowEnded, // 6014
+    #[msg("Dispute window of the proposed SLI has not ended yet")]
+    DisputeWindowNotEnded, // 6015
+    #[msg("SLA cannot have more than the max number of periods")]
+    MaxPeriodsExceeded, // 6016
+    #[msg("Number of periods needs to be positive and greater than the current one")]
+    InvalidPeriodCount, // 6017
+    #[msg("Sla has already ended")]
+    SlaAlreadyEnded, // 6018
+    #[msg("Signer is not the deployer of the SLA")]
+    UnauthorizedSlaDeployer, // 6019
+    #[msg("Last page of the SLA registry is full, a new page needs to be added")]
+    SlaRegistryFull, // 6020
+    #[msg("Last page of the SLA registry is not full yet")]
+    SlaRegistryNotFull, // 6021
+    #[msg("Governance change cannot be executed before the end of its timelock")]
+    GovernanceChangeTimelocked, // 6022
+    #[msg("Signer is not the governance authority")]
+    UnauthorizedGovernanceAuthority, // 6023
+    #[msg("Not the DSLA mint of the governance")]
+    InvalidDslaMint, // 6024
+    #[msg("Rewards rates need to be positive and sum up to less than 1")]
+    InvalidRewardsRates, // 6025
+    #[msg("Leverage needs to be positive and not higher than the max leverage of the governance")]
+    InvalidLeverage, // 6026
+    #[msg("Severity and penalty need the same length of at most 10 and to be positive and in increasing order")]
+    InvalidSeverityPenalty, // 6027
+    #[msg("Governance account already has the current layout version")]
+    GovernanceAlreadyMigrated, // 6028
+    #[msg("Sla account already has the current layout version")]
+    SlaAlreadyMigrated, // 6029
+    #[msg("Lockup account already has the current layout version")]
+    LockupAlreadyMigrated, // 6030
+    #[msg("Sla has not ended yet")]
+    SlaNotEnded, // 6031
+    #[msg("UT and PT of the SLA need to be burned and its DSLA pool empty")]
+    SlaNotDrained, // 6032
+    #[msg("All the periods of the SLA need to be verified")]
+    UnverifiedPeriods, // 6033
+    #[msg("Lockup still has available or locked tokens")]
+    LockupNotEmpty, // 6034
+    #[msg(
+        "Deposit cannot be reclaimed before the end of the grace period after the end of the SLA"
+    )]
+    DepositReclaimGracePeriod, // 6035
+    #[msg("Sla has already started")]
+    SlaAlreadyStarted, // 6036
+    #[msg("Sla has been cancelled")]
+    SlaCancelled, // 6037
+    #[msg("Protocol is paused by the guardian")]
+    ProtocolPaused, // 6038
+    #[msg("Sla is paused by the guardian")]
+    SlaPaused, // 6039
+    #[msg("Signer is not the guardian")]
+    UnauthorizedGuardian, // 6040
+    #[msg("Finished periods of the SLA need a checkpoint before staking or withdrawing")]
+    PeriodsNotCheckpointed, // 6041
+    #[msg("Amount of tokens out is lower than the minimum accepted")]
+    SlippageExceeded, // 6042
+    #[msg("Pool has no tokens left for its outstanding UT or PT")]
+    EmptyPool, // 6043
+    #[msg("Deposit is too small to mint any UT or PT")]
+    DepositTooSmall, // 6044
+    #[msg("Cannot burn more UT or PT than their supply")]
+    BurnExceedsSupply, // 6045
+    #[msg("Provider pool left would not cover the leveraged user pool")]
+    InsufficientProviderCoverage, // 6046
+    #[msg("Account needs to be migrated to the current layout version first")]
+    AccountNotMigrated, // 6047
+    #[msg("SLA is not registered at this id")]
+    SlaNotRegistered, // 6048
+    #[msg("Status registry account already has the current layout version")]
+    StatusRegistryAlreadyMigrated, // 6049
+    #[msg("Staked mint cannot have more than 9 decimals")]
+    InvalidMintDecimals, // 6050
 }
 
 #[error_code]
@@ -32,8 +114,42 @@ pub enum ErrorCode {
 pub enum FeedErrorCode {
     #[msg("Not a valid Switchboard account")]
     InvalidSwitchboardAccount,
-    #[msg("Switchboard feed has not been updated in 5 minutes")]
+    #[msg("Oracle feed has not been updated in the max staleness of the SLA")]
     StaleFeed,
     #[msg("Switchboard feed exceeded provided confidence interval")]
     ConfidenceIntervalExceeded,
+    #[msg("Not a valid Pyth price account")]
+    InvalidPythAccount,
+    #[msg("Not the history buffer of the Switchboard aggregator")]
+    InvalidHistoryBuffer,
+    #[msg(
+        "An SLA needs between 1 and 8 oracle feeds or attesters, or none for optimistic validation"
+    )]
+    InvalidOracleCount,
+    #[msg("The same oracle feed can only be used once")]
+    DuplicateOracle,
+    #[msg("Oracle feed is not one of the feeds of the SLA")]
+    UnknownOracle,
+    #[msg("Every oracle feed of the SLA needs to be passed in the order of the SLA")]
+    MissingOracle,
+    #[msg("Quorum needs to be between 1 and the number of oracle feeds or attesters")]
+    InvalidQuorum,
+    #[msg("Not enough fresh oracle feeds to reach the quorum")]
+    QuorumNotReached,
+    #[msg("Aggregation policy cannot be applied to the quorum of oracle feeds")]
+    InvalidAggregationPolicy,
+    #[msg("Max staleness needs to be between 1 and the governance limit")]
+    InvalidMaxStaleness,
+    #[msg("Max confidence interval needs to be between 0 and the governance limit")]
+    InvalidMaxConfidenceInterval,
+    #[msg("Periods of the SLA are validated optimistically, not from oracle feeds")]
+    OptimisticValidation,
+    #[msg("Periods of the SLA are validated from oracle feeds, not optimistically")]
+    NotOptimisticValidation,
+    #[msg("Periods of the SLA are not validated from pyth feeds")]
+    NotPythValidation,
+    #[msg("Not the instructions sysvar")]
+    InvalidInstructionsSysvar,
+    #[msg("Not a valid ed25519 attestation instruction")]
+    InvalidAttestation,
 }
@@ -1,3 +1,5 @@
+use crate::state::governance::GovernanceParameters;
+use crate::state::sla::DslaDecimal;
 use crate::state::status_registry::Status;
 
 use anchor_lang::prelude::*;
@@ -9,10 +11,13 @@ pub struct InitializedStatusRegistryEvent {
     pub periods: Vec<Status>,
 }
 
-/// event for the succeful deployment of an sla, exposes the sla account address
+/// event for the succeful deployment of an sla, exposes the sla account address and its registry id
 #[event]
 pub struct DeployedSlaEvent {
     pub sla_account_address: Pubkey,
+    pub sla_id: u64,
+    /// decimals of the UT and PT of the SLA, the decimals of its mint plus `SHARE_DECIMALS_OFFSET`
+    pub share_decimals: u8,
 }
 
 #[event]
@@ -28,3 +33,134 @@ pub struct StakedProviderSideEvent {
 pub struct StakedUserSideEvent {
     pub token_amount: u64,
 }
+
+/// event for the proposal of a change of the governance parameters
+#[event]
+pub struct ProposedGovernanceChangeEvent {
+    pub parameters: GovernanceParameters,
+    pub executable_at: i64,
+}
+
+/// event for the execution of a change of the governance parameters
+#[event]
+pub struct ExecutedGovernanceChangeEvent {
+    pub parameters: GovernanceParameters,
+}
+
+/// event for the cancellation of a pending change of the governance parameters
+#[event]
+pub struct CancelledGovernanceChangeEvent {
+    pub parameters: GovernanceParameters,
+}
+
+/// event for the start of the transfer of the governance authority to `new_authority`
+#[event]
+pub struct TransferredGovernanceAuthorityEvent {
+    pub authority: Pubkey,
+    pub new_authority: Pubkey,
+}
+
+/// event for the acceptance of the governance authority by `new_authority`
+#[event]
+pub struct AcceptedGovernanceAuthorityEvent {
+    pub previous_authority: Pubkey,
+    pub new_authority: Pubkey,
+}
+
+/// event for the guardian pausing or unpausing staking and validation of all slas
+#[event]
+pub struct SetProtocolPausedEvent {
+    pub paused: bool,
+}
+
+/// event for the guardian pausing or unpausing staking and validation of an sla
+#[event]
+pub struct SetSlaPausedEvent {
+    pub sla_account_address: Pubkey,
+    pub paused: bool,
+}
+
+/// event for the migration of the governance account to the layout `version`
+#[event]
+pub struct MigratedGovernanceEvent {
+    pub version: u8,
+}
+
+/// event for the migration of an sla account to the layout `version`
+#[event]
+pub struct MigratedSlaEvent {
+    pub sla_account_address: Pubkey,
+    pub version: u8,
+}
+
+/// event for the migration of a lockup account to the layout `version`
+#[event]
+pub struct MigratedLockupEvent {
+    pub lockup_account_address: Pubkey,
+    pub version: u8,
+}
+
+/// event for the migration of the status registry of an sla to the layout `version`
+#[event]
+pub struct MigratedStatusRegistryEvent {
+    pub status_registry_account_address: Pubkey,
+    pub version: u8,
+}
+
+/// event for an SLI proposed for a period of an sla using optimistic validation
+#[event]
+pub struct ProposedPeriodEvent {
+    pub sla_account_address: Pubkey,
+    pub period: u64,
+    pub proposer: Pubkey,
+    pub value: DslaDecimal,
+    pub dispute_deadline: i64,
+}
+
+/// event for the dispute of the SLI proposed for a period of an sla
+#[event]
+pub struct DisputedPeriodEvent {
+    pub sla_account_address: Pubkey,
+    pub period: u64,
+    pub challenger: Pubkey,
+}
+
+/// event for the validation of a period of an sla, exposes the resulting status
+#[event]
+pub struct ValidatedPeriodEvent {
+    pub sla_account_address: Pubkey,
+    pub period: u64,
+    pub status: Status,
+}
+
+/// event for the closing of an sla and its accounts
+#[event]
+pub struct ClosedSlaEvent {
+    pub sla_account_address: Pubkey,
+}
+
+/// event for the reclaim of the DSLA deposit of the `periods` of an sla that were never verified
+#[event]
+pub struct ReclaimedDslaDepositEvent {
+    pub sla_account_address: Pubkey,
+    pub periods: u32,
+    pub amount: u64,
+    pub destination: Pubkey,
+}
+
+/// event for the cancellation of an sla before it started, exposes the DSLA deposit refunded to the deployer
+#[event]
+pub struct CancelledSlaEvent {
+    pub sla_account_address: Pubkey,
+    pub refunded_deposit: u64,
+    pub cancellation_fee: u64,
+}
+
+/// event for the checkpoint of a finished period of an sla, exposes the max amount of tokens
+/// its settlement can still move between the pools
+#[event]
+pub struct CheckpointedPeriodEvent {
+    pub sla_account_address: Pubkey,
+    pub period: u64,
+    pub max_transfer: u128,
+}
@@ -0,0 +1,35 @@
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::events::AcceptedGovernanceAuthorityEvent;
+use crate::state::governance::Governance;
+use anchor_lang::prelude::*;
+
+/// Instruction for the pending governance authority to accept the transfer of the governance authority
+#[derive(Accounts)]
+pub struct AcceptGovernanceAuthority<'info> {
+    #[account(
+        constraint = governance.pending_governance_authority == Some(new_authority.key())
+            @ ErrorCode::UnauthorizedGovernanceAuthority
+    )]
+    pub new_authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [GOVERNANCE_SEED.as_bytes()],
+        bump,
+        constraint = governance.version == Governance::VERSION @ ErrorCode::AccountNotMigrated
+    )]
+    pub governance: Account<'info, Governance>,
+}
+
+pub(crate) fn handler(ctx: Context<AcceptGovernanceAuthority>) -> Result<()> {
+    let governance = &mut ctx.accounts.governance;
+    let previous_authority = governance.governance_authority;
+    governance.governance_authority = ctx.accounts.new_authority.key();
+    governance.pending_governance_authority = None;
+
+    emit!(AcceptedGovernanceAuthorityEvent {
+        previous_authority,
+        new_authority: governance.governance_authority,
+    });
+    Ok(())
+}
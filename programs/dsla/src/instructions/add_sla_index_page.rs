@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::state::sla_registry::{SlaIndexKind, SlaRegistryHeader, SlaRegistryPage};
+
+/// Instruction to add a page to the index of a deployer or a mint once all its pages are full, anyone can add it
+#[derive(Accounts)]
+#[instruction(kind: SlaIndexKind)]
+pub struct AddSlaIndexPage<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// CHECK: the deployer or the mint the SLAs are indexed by, only used as a seed
+    pub indexed_key: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds = [kind.seed().as_bytes(), indexed_key.key().as_ref()],
+        bump,
+        constraint = sla_index.is_full() @ ErrorCode::SlaRegistryNotFull
+    )]
+    pub sla_index: Account<'info, SlaRegistryHeader>,
+    #[account(
+        init,
+        payer = payer,
+        space = SlaRegistryPage::space(sla_index.page_size),
+        seeds = [
+            SLA_INDEX_PAGE_SEED.as_bytes(),
+            sla_index.key().as_ref(),
+            &sla_index.page_count.to_le_bytes(),
+        ],
+        bump
+    )]
+    pub sla_index_page: Account<'info, SlaRegistryPage>,
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(ctx: Context<AddSlaIndexPage>, _kind: SlaIndexKind) -> Result<()> {
+    let sla_index = &mut ctx.accounts.sla_index;
+    sla_index.page_count = sla_index
+        .page_count
+        .checked_add(1)
+        .ok_or(ErrorCode::CheckedOperationOverflow)?;
+    Ok(())
+}
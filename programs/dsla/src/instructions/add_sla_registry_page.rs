@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::state::sla_registry::{SlaRegistryHeader, SlaRegistryPage};
+
+/// Instruction to add a page to the SLARegistry once all its pages are full, anyone can add it
+#[derive(Accounts)]
+pub struct AddSlaRegistryPage<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [SLA_REGISTRY_HEADER_SEED.as_bytes()],
+        bump,
+        constraint = sla_registry.is_full() @ ErrorCode::SlaRegistryNotFull
+    )]
+    pub sla_registry: Account<'info, SlaRegistryHeader>,
+    #[account(
+        init,
+        payer = payer,
+        space = SlaRegistryPage::space(sla_registry.page_size),
+        seeds = [SLA_REGISTRY_PAGE_SEED.as_bytes(), &sla_registry.page_count.to_le_bytes()],
+        bump
+    )]
+    pub sla_registry_page: Account<'info, SlaRegistryPage>,
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(ctx: Context<AddSlaRegistryPage>) -> Result<()> {
+    let sla_registry = &mut ctx.accounts.sla_registry;
+    sla_registry.page_count = sla_registry
+        .page_count
+        .checked_add(1)
+        .ok_or(ErrorCode::CheckedOperationOverflow)?;
+    Ok(())
+}
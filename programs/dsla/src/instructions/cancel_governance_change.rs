@@ -0,0 +1,35 @@
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::events::CancelledGovernanceChangeEvent;
+use crate::state::governance::{Governance, GovernanceChange};
+use anchor_lang::prelude::*;
+
+/// Instruction to cancel the pending governance change before it's executed
+#[derive(Accounts)]
+pub struct CancelGovernanceChange<'info> {
+    #[account(address = governance.governance_authority @ ErrorCode::UnauthorizedGovernanceAuthority)]
+    pub governance_authority: Signer<'info>,
+    #[account(
+        seeds = [GOVERNANCE_SEED.as_bytes()],
+        bump,
+        constraint = governance.version == Governance::VERSION @ ErrorCode::AccountNotMigrated
+    )]
+    pub governance: Account<'info, Governance>,
+    #[account(
+        mut,
+        close = proposer,
+        seeds = [GOVERNANCE_CHANGE_SEED.as_bytes()],
+        bump
+    )]
+    pub governance_change: Account<'info, GovernanceChange>,
+    /// the proposer gets back the rent of the governance change
+    #[account(mut, address = governance_change.proposer)]
+    pub proposer: SystemAccount<'info>,
+}
+
+pub(crate) fn handler(ctx: Context<CancelGovernanceChange>) -> Result<()> {
+    emit!(CancelledGovernanceChangeEvent {
+        parameters: ctx.accounts.governance_change.parameters.clone(),
+    });
+    Ok(())
+}
@@ -0,0 +1,135 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::events::CancelledSlaEvent;
+use crate::state::sla::Sla;
+use crate::state::status_registry::StatusRegistry;
+use crate::state::{Governance, SlaAuthority, SlaStatus};
+
+/// Instruction for the deployer to cancel an SLA before it starts,
+/// the DSLA deposit is refunded minus the cancellation fee of the governance and all the periods expire,
+/// the stakers get their stake back in full by burning their UT and PT with `withdraw_user` and `withdraw_provider`
+#[derive(Accounts)]
+pub struct CancelSla<'info> {
+    pub deployer: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = sla.sla_deployer_address == deployer.key() @ ErrorCode::UnauthorizedSlaDeployer,
+        constraint = !sla.cancelled @ ErrorCode::SlaCancelled,
+        constraint = sla.version == Sla::VERSION @ ErrorCode::AccountNotMigrated,
+    )]
+    pub sla: Account<'info, Sla>,
+
+    #[account(
+        seeds = [SLA_AUTHORITY_SEED.as_bytes(), sla.key().as_ref()],
+        bump
+    )]
+    pub sla_authority: Account<'info, SlaAuthority>,
+
+    #[account(
+        mut,
+        seeds = [STATUS_REGISTRY_SEED.as_bytes(), sla.key().as_ref()],
+        bump,
+        constraint = status_registry.is_current(sla.period_data.n_periods) @ ErrorCode::AccountNotMigrated
+    )]
+    pub status_registry: Account<'info, StatusRegistry>,
+
+    #[account(
+        seeds = [GOVERNANCE_SEED.as_bytes()],
+        bump,
+        constraint = governance.version == Governance::VERSION @ ErrorCode::AccountNotMigrated
+    )]
+    pub governance: Account<'info, Governance>,
+
+    #[account(address = governance.dsla_mint @ ErrorCode::InvalidDslaMint)]
+    pub dsla_mint: Box<Account<'info, Mint>>,
+    #[account(
+        mut,
+        seeds = [DSLA_POOL_SEED.as_bytes(), sla.key().as_ref()],
+        token::mint = dsla_mint,
+        token::authority = sla_authority,
+        bump,
+    )]
+    pub dsla_pool: Box<Account<'info, TokenAccount>>,
+
+    /// The token account to refund the DSLA deposit to
+    #[account(mut, associated_token::mint=dsla_mint, associated_token::authority=deployer)]
+    pub deployer_dsla_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = dsla_mint,
+        associated_token::authority = governance.protocol_treasury
+    )]
+    pub protocol_dsla_token_account: Box<Account<'info, TokenAccount>>,
+
+    /// The program for interacting with the token.
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> CancelSla<'info> {
+    fn transfer_context(
+        &self,
+        to: &Account<'info, TokenAccount>,
+    ) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            Transfer {
+                from: self.dsla_pool.to_account_info(),
+                to: to.to_account_info(),
+                authority: self.sla_authority.to_account_info(),
+            },
+        )
+    }
+}
+
+pub(crate) fn handler(ctx: Context<CancelSla>) -> Result<()> {
+    require!(
+        ctx.accounts.sla.period_data.get_current_period_id()? == SlaStatus::NotStarted,
+        ErrorCode::SlaAlreadyStarted
+    );
+
+    ctx.accounts.sla.cancelled = true;
+    ctx.accounts.status_registry.expire_unverified();
+
+    // REFUND THE DEPOSIT
+    let deposit = ctx.accounts.dsla_pool.amount;
+    let cancellation_fee = ctx.accounts.governance.sla_cancellation_fee(deposit)?;
+    let refunded_deposit = deposit
+        .checked_sub(cancellation_fee)
+        .ok_or(ErrorCode::CheckedOperationOverflow)?;
+
+    let sla_key = ctx.accounts.sla.key();
+    let authority_bump = *ctx
+        .bumps
+        .get("sla_authority")
+        .expect("sla_authority should exists");
+    let seeds = &[
+        SLA_AUTHORITY_SEED.as_bytes(),
+        sla_key.as_ref(),
+        &[authority_bump],
+    ];
+    let signer_seeds = &[&seeds[..]];
+    token::transfer(
+        ctx.accounts
+            .transfer_context(&ctx.accounts.protocol_dsla_token_account)
+            .with_signer(signer_seeds),
+        cancellation_fee,
+    )?;
+    token::transfer(
+        ctx.accounts
+            .transfer_context(&ctx.accounts.deployer_dsla_token_account)
+            .with_signer(signer_seeds),
+        refunded_deposit,
+    )?;
+
+    emit!(CancelledSlaEvent {
+        sla_account_address: sla_key,
+        refunded_deposit,
+        cancellation_fee,
+    });
+    Ok(())
+}
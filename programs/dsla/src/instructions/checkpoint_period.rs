@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::events::CheckpointedPeriodEvent;
+use crate::state::sla::Sla;
+use crate::state::status_registry::StatusRegistry;
+use crate::state::PeriodCheckpoint;
+
+/// Instruction to checkpoint the pools and supplies of an SLA once a period finished, anyone can checkpoint
+/// by paying the rent of the checkpoint, the finished periods are checkpointed in order
+///
+/// stakes and withdrawals need every finished period to be checkpointed, and the validation of a period
+/// needs its checkpoint, see `PeriodCheckpoint`
+#[derive(Accounts)]
+#[instruction(period: u64)]
+pub struct CheckpointPeriod<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = sla.version == Sla::VERSION @ ErrorCode::AccountNotMigrated
+    )]
+    pub sla: Account<'info, Sla>,
+
+    #[account(
+        seeds = [STATUS_REGISTRY_SEED.as_bytes(), sla.key().as_ref()],
+        bump,
+        constraint = status_registry.is_current(sla.period_data.n_periods) @ ErrorCode::AccountNotMigrated
+    )]
+    pub status_registry: Account<'info, StatusRegistry>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = PeriodCheckpoint::LEN,
+        seeds = [CHECKPOINT_SEED.as_bytes(), sla.key().as_ref(), &period.to_le_bytes()],
+        bump
+    )]
+    pub checkpoint: Account<'info, PeriodCheckpoint>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(ctx: Context<CheckpointPeriod>, period: u64) -> Result<()> {
+    let sla = &mut ctx.accounts.sla;
+    require_eq!(
+        period,
+        sla.checkpointed_periods as u64,
+        ErrorCode::InvalidPeriodId
+    );
+    require_gt!(
+        sla.finished_periods(sla.status()?) as u64,
+        period,
+        ErrorCode::InvalidPeriodId
+    );
+
+    let settled = ctx.accounts.status_registry.status_registry[period as usize].is_settled();
+    let checkpoint = PeriodCheckpoint::new(ctx.accounts.payer.key(), period, sla, settled)?;
+    sla.checkpoint_period(checkpoint.max_transfer)?;
+
+    emit!(CheckpointedPeriodEvent {
+        sla_account_address: sla.key(),
+        period,
+        max_transfer: checkpoint.max_transfer,
+    });
+    ctx.accounts.checkpoint.set_inner(checkpoint);
+    Ok(())
+}
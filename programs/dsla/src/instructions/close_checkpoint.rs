@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::state::status_registry::StatusRegistry;
+use crate::state::PeriodCheckpoint;
+
+/// Instruction for the payer of a checkpoint to get back its rent once its period is settled or expired,
+/// before the SLA is closed with its status registry
+#[derive(Accounts)]
+#[instruction(period: u64)]
+pub struct CloseCheckpoint<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: only the key of the SLA is used for the seeds
+    pub sla: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [STATUS_REGISTRY_SEED.as_bytes(), sla.key().as_ref()],
+        bump,
+        constraint = status_registry.version == StatusRegistry::VERSION @ ErrorCode::AccountNotMigrated
+    )]
+    pub status_registry: Account<'info, StatusRegistry>,
+
+    #[account(
+        mut,
+        close = payer,
+        has_one = payer,
+        seeds = [CHECKPOINT_SEED.as_bytes(), sla.key().as_ref(), &period.to_le_bytes()],
+        bump
+    )]
+    pub checkpoint: Account<'info, PeriodCheckpoint>,
+}
+
+pub(crate) fn handler(ctx: Context<CloseCheckpoint>, period: u64) -> Result<()> {
+    let status_registry = &ctx.accounts.status_registry.status_registry;
+    let status = status_registry
+        .get(period as usize)
+        .ok_or(ErrorCode::InvalidPeriodId)?;
+    // the settlement of the period still reads the checkpoint
+    require!(status.is_settled(), ErrorCode::UnverifiedPeriods);
+    Ok(())
+}
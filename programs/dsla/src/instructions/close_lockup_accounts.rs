@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::state::Lockup;
+
+/// Instruction for a participant to close its lockup accounts of an SLA once they are empty,
+/// the rent goes back to the participant who paid it
+#[derive(Accounts)]
+pub struct CloseLockupAccounts<'info> {
+    #[account(mut)]
+    pub user_provider: Signer<'info>,
+
+    /// CHECK: only used to derive the lockup addresses, the SLA may already be closed
+    pub sla: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        close = user_provider,
+        constraint = pt_lockup.is_empty() @ ErrorCode::LockupNotEmpty,
+        seeds = [
+            user_provider.key().as_ref(),
+            LOCKUP_PROVIDER_SEED.as_bytes(),
+            sla.key().as_ref(),
+        ],
+        bump,
+        constraint = pt_lockup.version == Lockup::VERSION @ ErrorCode::AccountNotMigrated,
+    )]
+    pub pt_lockup: Box<Account<'info, Lockup>>,
+
+    #[account(
+        mut,
+        close = user_provider,
+        constraint = ut_lockup.is_empty() @ ErrorCode::LockupNotEmpty,
+        seeds = [
+            user_provider.key().as_ref(),
+            LOCKUP_USER_SEED.as_bytes(),
+            sla.key().as_ref(),
+        ],
+        bump,
+        constraint = ut_lockup.version == Lockup::VERSION @ ErrorCode::AccountNotMigrated,
+    )]
+    pub ut_lockup: Box<Account<'info, Lockup>>,
+}
+
+pub(crate) fn handler(_ctx: Context<CloseLockupAccounts>) -> Result<()> {
+    Ok(())
+}
@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::state::status_registry::{Status, StatusRegistry};
+use crate::state::PythSample;
+
+/// Instruction for the recorder of a pyth sample to get back its rent once the period of the sample
+/// is settled or expired, before the SLA is closed with its status registry
+#[derive(Accounts)]
+#[instruction(period: u64)]
+pub struct ClosePythSample<'info> {
+    #[account(mut)]
+    pub recorder: Signer<'info>,
+
+    /// CHECK: only the key of the SLA is used for the seeds
+    pub sla: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [STATUS_REGISTRY_SEED.as_bytes(), sla.key().as_ref()],
+        bump,
+        constraint = status_registry.version == StatusRegistry::VERSION @ ErrorCode::AccountNotMigrated
+    )]
+    pub status_registry: Account<'info, StatusRegistry>,
+
+    #[account(
+        mut,
+        close = recorder,
+        has_one = recorder,
+        seeds = [PYTH_SAMPLE_SEED.as_bytes(), sla.key().as_ref(), &period.to_le_bytes()],
+        bump
+    )]
+    pub pyth_sample: Account<'info, PythSample>,
+}
+
+pub(crate) fn handler(ctx: Context<ClosePythSample>, period: u64) -> Result<()> {
+    let status_registry = &ctx.accounts.status_registry.status_registry;
+    let status = status_registry
+        .get(period as usize)
+        .ok_or(ErrorCode::InvalidPeriodId)?;
+    require!(*status != Status::NotVerified, ErrorCode::UnverifiedPeriods);
+    Ok(())
+}
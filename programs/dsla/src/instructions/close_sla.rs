@@ -0,0 +1,244 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, CloseAccount, Mint, Token, TokenAccount, Transfer};
+
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::events::ClosedSlaEvent;
+use crate::state::sla::Sla;
+use crate::state::sla_registry::{SlaRegistryEntry, SlaRegistryHeader, SlaRegistryPage};
+use crate::state::status_registry::StatusRegistry;
+use crate::state::{Governance, SlaAuthority, SlaStatus};
+
+/// Instruction for the deployer to close an ended or cancelled SLA once all its periods are verified and its UT and PT are burned,
+/// the rent of the SLA accounts and token accounts goes back to the deployer who paid it.
+/// The tokens left in the pool without any UT or PT are the rounding of the withdrawals and the part of the pool gains
+/// held by the virtual UT and PT of the share prices, they go to the protocol treasury and not to the deployer or the last
+/// withdrawer, who could be the ones that inflated a share price and would recover what it cost them.
+/// The token program can't close the UT and PT mints, so they stay on-chain,
+/// the SLA is removed from the indexes of its deployer and mint but stays in the global registry,
+/// its registry entry also stays so the SLA address can't be registered again.
+/// SLAs deployed before the paged registry have no registry entry and are in no index, so they are closed without it.
+/// The proposals of the optimistic periods are already closed as their periods need to be finalized or resolved first
+#[derive(Accounts)]
+pub struct CloseSla<'info> {
+    #[account(mut)]
+    pub deployer: Signer<'info>,
+
+    #[account(
+        mut,
+        close = deployer,
+        constraint = sla.sla_deployer_address == deployer.key() @ ErrorCode::UnauthorizedSlaDeployer,
+        constraint = sla.version == Sla::VERSION @ ErrorCode::AccountNotMigrated,
+    )]
+    pub sla: Account<'info, Sla>,
+
+    #[account(
+        mut,
+        close = deployer,
+        seeds = [SLA_AUTHORITY_SEED.as_bytes(), sla.key().as_ref()],
+        bump
+    )]
+    pub sla_authority: Account<'info, SlaAuthority>,
+
+    #[account(
+        mut,
+        close = deployer,
+        seeds = [STATUS_REGISTRY_SEED.as_bytes(), sla.key().as_ref()],
+        bump,
+        constraint = status_registry.is_current(sla.period_data.n_periods) @ ErrorCode::AccountNotMigrated
+    )]
+    pub status_registry: Account<'info, StatusRegistry>,
+
+    /// CHECK: empty for the SLAs deployed before the paged registry, deserialized by the handler otherwise
+    #[account(
+        seeds = [SLA_REGISTRY_ENTRY_SEED.as_bytes(), sla.key().as_ref()],
+        bump
+    )]
+    pub sla_registry_entry: UncheckedAccount<'info>,
+
+    /// CHECK: index of the SLAs of the deployer, deserialized by the handler when the SLA is indexed
+    #[account(
+        seeds = [DEPLOYER_INDEX_SEED.as_bytes(), deployer.key().as_ref()],
+        bump
+    )]
+    pub deployer_index: UncheckedAccount<'info>,
+    /// CHECK: page of the deployer index storing the SLA, checked by the handler when the SLA is indexed
+    #[account(mut)]
+    pub deployer_index_page: UncheckedAccount<'info>,
+
+    /// CHECK: index of the SLAs of the mint, deserialized by the handler when the SLA is indexed
+    #[account(
+        seeds = [MINT_INDEX_SEED.as_bytes(), mint.key().as_ref()],
+        bump
+    )]
+    pub mint_index: UncheckedAccount<'info>,
+    /// CHECK: page of the mint index storing the SLA, checked by the handler when the SLA is indexed
+    #[account(mut)]
+    pub mint_index_page: UncheckedAccount<'info>,
+
+    #[account(constraint = mint.key() == sla.mint_address)]
+    pub mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        mut,
+        seeds = [POOL_SEED.as_bytes(), sla.key().as_ref()],
+        token::mint = mint,
+        token::authority = sla_authority,
+        bump,
+    )]
+    pub pool: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = governance.protocol_treasury
+    )]
+    pub protocol_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        seeds = [GOVERNANCE_SEED.as_bytes()],
+        bump,
+        constraint = governance.version == Governance::VERSION @ ErrorCode::AccountNotMigrated
+    )]
+    pub governance: Box<Account<'info, Governance>>,
+
+    #[account(
+        mut,
+        seeds = [DSLA_POOL_SEED.as_bytes(), sla.key().as_ref()],
+        token::authority = sla_authority,
+        bump,
+    )]
+    pub dsla_pool: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        seeds = [UT_MINT_SEED.as_bytes(), sla.key().as_ref()],
+        bump,
+    )]
+    pub ut_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        seeds = [PT_MINT_SEED.as_bytes(), sla.key().as_ref()],
+        bump,
+    )]
+    pub pt_mint: Box<Account<'info, Mint>>,
+
+    /// The program for interacting with the token.
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> CloseSla<'info> {
+    fn sweep_context(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            Transfer {
+                from: self.pool.to_account_info(),
+                to: self.protocol_token_account.to_account_info(),
+                authority: self.sla_authority.to_account_info(),
+            },
+        )
+    }
+
+    fn close_context(
+        &self,
+        account: &Account<'info, TokenAccount>,
+    ) -> CpiContext<'_, '_, '_, 'info, CloseAccount<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            CloseAccount {
+                account: account.to_account_info(),
+                destination: self.deployer.to_account_info(),
+                authority: self.sla_authority.to_account_info(),
+            },
+        )
+    }
+}
+
+/// removes `sla` from the page of `index` storing the id `index_id`
+fn unregister_from_index(
+    index: &AccountInfo,
+    page: &AccountInfo,
+    index_id: u64,
+    sla: Pubkey,
+) -> Result<()> {
+    let index_header = Account::<SlaRegistryHeader>::try_from(index)?;
+    let (page_address, _) = Pubkey::find_program_address(
+        &[
+            SLA_INDEX_PAGE_SEED.as_bytes(),
+            index.key.as_ref(),
+            &index_header.page_of(index_id).to_le_bytes(),
+        ],
+        &crate::ID,
+    );
+    require_keys_eq!(page.key(), page_address, ErrorCode::SlaNotRegistered);
+    let mut index_page = Account::<SlaRegistryPage>::try_from(page)?;
+    index_header.unregister(&mut index_page, index_id, sla)?;
+    index_page.exit(&crate::ID)
+}
+
+pub(crate) fn handler(ctx: Context<CloseSla>) -> Result<()> {
+    let sla = &ctx.accounts.sla;
+    require!(sla.status()? == SlaStatus::Ended, ErrorCode::SlaNotEnded);
+    require!(
+        ctx.accounts.status_registry.all_settled(),
+        ErrorCode::UnverifiedPeriods
+    );
+    require!(
+        sla.ut_supply == 0
+            && sla.pt_supply == 0
+            && ctx.accounts.ut_mint.supply == 0
+            && ctx.accounts.pt_mint.supply == 0
+            && ctx.accounts.dsla_pool.amount == 0,
+        ErrorCode::SlaNotDrained
+    );
+
+    let sla_key = sla.key();
+    let entry_info = ctx.accounts.sla_registry_entry.to_account_info();
+    if !entry_info.data_is_empty() {
+        let entry = Account::<SlaRegistryEntry>::try_from(&entry_info)?;
+        unregister_from_index(
+            &ctx.accounts.deployer_index,
+            &ctx.accounts.deployer_index_page,
+            entry.deployer_index_id,
+            sla_key,
+        )?;
+        unregister_from_index(
+            &ctx.accounts.mint_index,
+            &ctx.accounts.mint_index_page,
+            entry.mint_index_id,
+            sla_key,
+        )?;
+    }
+
+    let authority_bump = *ctx
+        .bumps
+        .get("sla_authority")
+        .expect("sla_authority should exists");
+    let seeds = &[
+        SLA_AUTHORITY_SEED.as_bytes(),
+        sla_key.as_ref(),
+        &[authority_bump],
+    ];
+    let signer_seeds = &[&seeds[..]];
+    let leftover = ctx.accounts.pool.amount;
+    if leftover > 0 {
+        token::transfer(
+            ctx.accounts.sweep_context().with_signer(signer_seeds),
+            leftover,
+        )?;
+    }
+    token::close_account(
+        ctx.accounts
+            .close_context(&ctx.accounts.pool)
+            .with_signer(signer_seeds),
+    )?;
+    token::close_account(
+        ctx.accounts
+            .close_context(&ctx.accounts.dsla_pool)
+            .with_signer(signer_seeds),
+    )?;
+
+    emit!(ClosedSlaEvent {
+        sla_account_address: sla_key,
+    });
+    Ok(())
+}
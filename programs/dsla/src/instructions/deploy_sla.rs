@@ -1,24 +1,94 @@
 use anchor_lang::prelude::*;
-use switchboard_v2::{AggregatorAccountData, SWITCHBOARD_PROGRAM_ID};
 
 use crate::constants::*;
 use crate::errors::{ErrorCode, FeedErrorCode};
 use crate::events::*;
 use crate::state::sla::{PeriodGenerator, PeriodLength};
 use crate::state::sla::{Sla, Slo};
-use crate::state::sla_registry::SlaRegistry;
+use crate::state::sla_registry::{SlaRegistryEntry, SlaRegistryHeader, SlaRegistryPage};
 use crate::state::status_registry::StatusRegistry;
-use crate::state::{DslaDecimal, Governance, SlaAuthority};
+use crate::state::{AggregationPolicy, DslaDecimal, Governance, OracleSource, SlaAuthority};
 use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
 
 /// Instruction to deploy a new SLA
+/// the oracle feed accounts or the attesters of the SLA are passed as remaining accounts
 #[derive(Accounts)]
+#[instruction(slo: Slo, leverage: DslaDecimal, start: u128, n_periods: u32)]
 pub struct DeploySla<'info> {
     #[account(mut)]
     pub deployer: Signer<'info>,
 
-    #[account(mut)]
-    pub sla_registry: Account<'info, SlaRegistry>,
+    #[account(
+        mut,
+        seeds = [SLA_REGISTRY_HEADER_SEED.as_bytes()],
+        bump
+    )]
+    pub sla_registry: Account<'info, SlaRegistryHeader>,
+
+    /// the last page of the registry, a new page needs to be added first when it's full
+    #[account(
+        mut,
+        seeds = [
+            SLA_REGISTRY_PAGE_SEED.as_bytes(),
+            &sla_registry.page_of(sla_registry.sla_count).to_le_bytes(),
+        ],
+        bump
+    )]
+    pub sla_registry_page: Account<'info, SlaRegistryPage>,
+
+    /// index of the SLAs of the deployer, created with its pages on demand by the first SLA that needs them
+    #[account(
+        init_if_needed,
+        payer = deployer,
+        space = SlaRegistryHeader::LEN,
+        seeds = [DEPLOYER_INDEX_SEED.as_bytes(), deployer.key().as_ref()],
+        bump
+    )]
+    pub deployer_index: Box<Account<'info, SlaRegistryHeader>>,
+    #[account(
+        init_if_needed,
+        payer = deployer,
+        space = SlaRegistryPage::space(SLAS_PER_INDEX_PAGE),
+        seeds = [
+            SLA_INDEX_PAGE_SEED.as_bytes(),
+            deployer_index.key().as_ref(),
+            &deployer_index.next_page().to_le_bytes(),
+        ],
+        bump
+    )]
+    pub deployer_index_page: Box<Account<'info, SlaRegistryPage>>,
+
+    /// index of the SLAs of the mint, created with its pages on demand by the first SLA that needs them
+    #[account(
+        init_if_needed,
+        payer = deployer,
+        space = SlaRegistryHeader::LEN,
+        seeds = [MINT_INDEX_SEED.as_bytes(), mint.key().as_ref()],
+        bump
+    )]
+    pub mint_index: Box<Account<'info, SlaRegistryHeader>>,
+    #[account(
+        init_if_needed,
+        payer = deployer,
+        space = SlaRegistryPage::space(SLAS_PER_INDEX_PAGE),
+        seeds = [
+            SLA_INDEX_PAGE_SEED.as_bytes(),
+            mint_index.key().as_ref(),
+            &mint_index.next_page().to_le_bytes(),
+        ],
+        bump
+    )]
+    pub mint_index_page: Box<Account<'info, SlaRegistryPage>>,
+
+    /// can only be created once for each SLA
+    #[account(
+        init,
+        payer = deployer,
+        space = SlaRegistryEntry::LEN,
+        seeds = [SLA_REGISTRY_ENTRY_SEED.as_bytes(), sla.key().as_ref()],
+        bump
+    )]
+    pub sla_registry_entry: Account<'info, SlaRegistryEntry>,
 
     #[account(
         init,
@@ -39,13 +109,16 @@ pub struct DeploySla<'info> {
     #[account(
         init,
         payer = deployer,
-        space = 10_000, // @fixme set the correct size depending on n_periods
+        space = StatusRegistry::space(n_periods),
         seeds = [STATUS_REGISTRY_SEED.as_bytes(), sla.key().as_ref()],
         bump
     )]
     pub status_registry: Account<'info, StatusRegistry>,
 
-    #[account(constraint = mint.is_initialized == true)]
+    #[account(
+        constraint = mint.is_initialized,
+        constraint = mint.decimals <= MAX_MINT_DECIMALS @ ErrorCode::InvalidMintDecimals
+    )]
     pub mint: Account<'info, Mint>,
 
     #[account(
@@ -58,8 +131,7 @@ pub struct DeploySla<'info> {
     )]
     pub pool: Box<Account<'info, TokenAccount>>,
 
-    // @todo add constraint to check for correct DSLA mint address
-    #[account(constraint = dsla_mint.is_initialized == true)]
+    #[account(address = governance.dsla_mint @ ErrorCode::InvalidDslaMint)]
     pub dsla_mint: Box<Account<'info, Mint>>,
     #[account(
         init,
@@ -78,9 +150,11 @@ pub struct DeploySla<'info> {
     // keep this here to check that governance account has been initialized before deploying an SLA
     #[account(
         seeds = [GOVERNANCE_SEED.as_bytes()],
-        bump
+        bump,
+        constraint = governance.version == Governance::VERSION @ ErrorCode::AccountNotMigrated
     )]
     pub governance: Account<'info, Governance>,
+    /// the UT have `SHARE_DECIMALS_OFFSET` decimals more than the mint, up to 12
     #[account(
         init,
         payer = deployer,
@@ -88,13 +162,13 @@ pub struct DeploySla<'info> {
             UT_MINT_SEED.as_bytes(),
             sla.key().as_ref(),
         ],
-        // @fixme check that this actually work
-        mint::decimals = mint.decimals,
+        mint::decimals = mint.decimals.saturating_add(SHARE_DECIMALS_OFFSET),
         mint::authority = sla_authority,
         bump,
     )]
     pub ut_mint: Box<Account<'info, Mint>>,
 
+    /// the PT have `SHARE_DECIMALS_OFFSET` decimals more than the mint, up to 12
     #[account(
         init,
         payer = deployer,
@@ -102,17 +176,12 @@ pub struct DeploySla<'info> {
             PT_MINT_SEED.as_bytes(),
             sla.key().as_ref(),
         ],
-        mint::decimals = mint.decimals,
+        mint::decimals = mint.decimals.saturating_add(SHARE_DECIMALS_OFFSET),
         mint::authority = sla_authority,
         bump
     )]
     pub pt_mint: Box<Account<'info, Mint>>,
 
-    #[account(
-        constraint = *aggregator.to_account_info().owner == SWITCHBOARD_PROGRAM_ID @ FeedErrorCode::InvalidSwitchboardAccount
-    )]
-    pub aggregator: AccountLoader<'info, AggregatorAccountData>,
-
     /// The program for interacting with the token.
     pub token_program: Program<'info, Token>,
     pub rent: Sysvar<'info, Rent>,
@@ -132,7 +201,8 @@ impl<'info> DeploySla<'info> {
         )
     }
 }
-pub fn handler(
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn handler(
     ctx: Context<DeploySla>,
     slo: Slo,
     leverage: DslaDecimal,
@@ -141,31 +211,64 @@ pub fn handler(
     period_length: PeriodLength,
     severity: Vec<DslaDecimal>,
     penalty: Vec<DslaDecimal>,
+    oracle_source: OracleSource,
+    aggregation_policy: AggregationPolicy,
+    min_quorum: u8,
+    max_staleness: Option<u32>,
+    max_confidence_interval: Option<DslaDecimal>,
 ) -> Result<()> {
-    // check that the SLA registry still has space
-    // @todo add error for this
-    require_gt!(
-        312499,
-        ctx.accounts.sla_registry.sla_account_addresses.len()
-    );
-    // @todo add error for this
-    require_gte!(9, ctx.accounts.mint.decimals);
-    // @todo add test for this
-    require!(
-        !ctx.accounts
-            .sla_registry
-            .sla_account_addresses
-            .contains(&ctx.accounts.sla.key()),
-        ErrorCode::SLaAlreadyInitialized
-    );
-    require_eq!(severity.len(), penalty.len());
-    require_gte!(10, severity.len());
-
-    ctx.accounts
+    require_gte!(n_periods, 1, ErrorCode::InvalidPeriodCount);
+    require_gte!(MAX_PERIODS, n_periods, ErrorCode::MaxPeriodsExceeded);
+    Sla::check_severity_penalty(&severity, &penalty)?;
+    ctx.accounts.governance.check_leverage(leverage)?;
+
+    // check the oracle feeds, optimistic validation doesn't use any
+    let oracles = ctx.remaining_accounts;
+    if oracle_source == OracleSource::Optimistic {
+        require!(oracles.is_empty(), FeedErrorCode::InvalidOracleCount);
+        require_eq!(min_quorum, 0, FeedErrorCode::InvalidQuorum);
+    } else {
+        require!(
+            !oracles.is_empty() && oracles.len() <= MAX_ORACLES,
+            FeedErrorCode::InvalidOracleCount
+        );
+        require!(
+            min_quorum >= 1 && min_quorum as usize <= oracles.len(),
+            FeedErrorCode::InvalidQuorum
+        );
+        aggregation_policy.check_quorum(min_quorum)?;
+    }
+    let (max_staleness, max_confidence_interval) = ctx
+        .accounts
+        .governance
+        .oracle_settings(max_staleness, max_confidence_interval)?;
+    let mut oracle_addresses = Vec::with_capacity(oracles.len());
+    for oracle in oracles {
+        oracle_source.check_account(oracle)?;
+        require!(
+            !oracle_addresses.contains(oracle.key),
+            FeedErrorCode::DuplicateOracle
+        );
+        oracle_addresses.push(oracle.key());
+    }
+
+    // the SLA registry entry is initialized once per SLA, so it can't be registered twice
+    let sla_key = ctx.accounts.sla.key();
+    let sla_id = ctx
+        .accounts
         .sla_registry
-        .sla_account_addresses
-        .push(ctx.accounts.sla.key());
-    msg!("{}", ctx.accounts.sla_registry.sla_account_addresses[0]);
+        .register(&mut ctx.accounts.sla_registry_page, sla_key)?;
+    let deployer_index = &mut ctx.accounts.deployer_index;
+    deployer_index.open_next_page(SLAS_PER_INDEX_PAGE)?;
+    let deployer_index_id =
+        deployer_index.register(&mut ctx.accounts.deployer_index_page, sla_key)?;
+    let mint_index = &mut ctx.accounts.mint_index;
+    mint_index.open_next_page(SLAS_PER_INDEX_PAGE)?;
+    let mint_index_id = mint_index.register(&mut ctx.accounts.mint_index_page, sla_key)?;
+    let sla_registry_entry = &mut ctx.accounts.sla_registry_entry;
+    sla_registry_entry.sla_id = sla_id;
+    sla_registry_entry.deployer_index_id = deployer_index_id;
+    sla_registry_entry.mint_index_id = mint_index_id;
 
     let transfer_amount = ctx
         .accounts
@@ -177,6 +280,7 @@ pub fn handler(
     let sla = &mut ctx.accounts.sla;
 
     // SLA initialization
+    sla.version = Sla::VERSION;
     sla.leverage = leverage;
     sla.provider_pool_size = 0;
     sla.user_pool_size = 0;
@@ -184,15 +288,26 @@ pub fn handler(
     sla.period_data = PeriodGenerator::new(start, period_length, n_periods);
     sla.mint_address = ctx.accounts.mint.key();
     sla.sla_deployer_address = ctx.accounts.deployer.key();
-    sla.aggregator_address = ctx.accounts.aggregator.key();
-    sla.penalty = severity;
-    sla.severity = penalty;
+    sla.oracle_source = oracle_source;
+    sla.oracle_addresses = oracle_addresses;
+    sla.aggregation_policy = aggregation_policy;
+    sla.min_quorum = min_quorum;
+    sla.max_staleness = max_staleness;
+    sla.max_confidence_interval = max_confidence_interval;
+    sla.severity = severity;
+    sla.penalty = penalty;
+    sla.cancelled = false;
+    sla.paused = false;
 
     // Status registry initialization
-    ctx.accounts.status_registry.status_registry = StatusRegistry::new_vec(n_periods);
+    let status_registry = &mut ctx.accounts.status_registry;
+    status_registry.version = StatusRegistry::VERSION;
+    status_registry.status_registry = StatusRegistry::new_vec(n_periods);
 
     emit!(DeployedSlaEvent {
-        sla_account_address: sla.key()
+        sla_account_address: sla.key(),
+        sla_id,
+        share_decimals: ctx.accounts.ut_mint.decimals,
     });
 
     Ok(())
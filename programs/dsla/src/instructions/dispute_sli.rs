@@ -0,0 +1,111 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+
+use crate::constants::*;
+use crate::errors::{ErrorCode, FeedErrorCode};
+use crate::events::DisputedPeriodEvent;
+use crate::state::sla::Sla;
+use crate::state::status_registry::{Status, StatusRegistry};
+use crate::state::{Governance, OracleSource, Proposal};
+
+/// Instruction to dispute the SLI proposed for a period during the dispute window,
+/// anyone can dispute by bonding the governance `dispute_bond`, the period is then settled by the arbiter
+#[derive(Accounts)]
+#[instruction(period: u64)]
+pub struct DisputeSli<'info> {
+    #[account(mut)]
+    pub challenger: Signer<'info>,
+
+    #[account(
+        constraint = sla.oracle_source == OracleSource::Optimistic @ FeedErrorCode::NotOptimisticValidation,
+        constraint = sla.version == Sla::VERSION @ ErrorCode::AccountNotMigrated
+    )]
+    pub sla: Account<'info, Sla>,
+
+    #[account(
+        mut,
+        seeds = [STATUS_REGISTRY_SEED.as_bytes(), sla.key().as_ref()],
+        bump,
+        constraint = status_registry.is_current(sla.period_data.n_periods) @ ErrorCode::AccountNotMigrated
+    )]
+    pub status_registry: Account<'info, StatusRegistry>,
+
+    #[account(
+        seeds = [GOVERNANCE_SEED.as_bytes()],
+        bump,
+        constraint = governance.version == Governance::VERSION @ ErrorCode::AccountNotMigrated
+    )]
+    pub governance: Account<'info, Governance>,
+
+    #[account(
+        mut,
+        seeds = [PROPOSAL_SEED.as_bytes(), sla.key().as_ref(), &period.to_le_bytes()],
+        bump
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    #[account(address = governance.dsla_mint @ ErrorCode::InvalidDslaMint)]
+    pub dsla_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        mut,
+        seeds = [BOND_VAULT_SEED.as_bytes(), proposal.key().as_ref()],
+        token::mint = dsla_mint,
+        bump,
+    )]
+    pub bond_vault: Box<Account<'info, TokenAccount>>,
+
+    /// The token account to pay the bond from
+    #[account(mut, associated_token::mint=dsla_mint, associated_token::authority=challenger)]
+    pub challenger_dsla_token_account: Box<Account<'info, TokenAccount>>,
+
+    /// The program for interacting with the token.
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> DisputeSli<'info> {
+    fn transfer_context(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            Transfer {
+                from: self.challenger_dsla_token_account.to_account_info(),
+                to: self.bond_vault.to_account_info(),
+                authority: self.challenger.to_account_info(),
+            },
+        )
+    }
+}
+
+pub(crate) fn handler(ctx: Context<DisputeSli>, period: u64) -> Result<()> {
+    let status_registry = &mut ctx.accounts.status_registry.status_registry;
+    let period = period as usize;
+    require_gt!(status_registry.len(), period, ErrorCode::InvalidPeriodId);
+    require!(
+        status_registry[period] == Status::Proposed,
+        ErrorCode::PeriodNotProposed
+    );
+    require!(
+        ctx.accounts
+            .proposal
+            .is_disputable(Clock::get()?.unix_timestamp),
+        ErrorCode::DisputeWindowEnded
+    );
+    status_registry[period] = Status::Disputed;
+
+    let dispute_bond = ctx.accounts.governance.dispute_bond;
+    let proposal = &mut ctx.accounts.proposal;
+    proposal.challenger = Some(ctx.accounts.challenger.key());
+    proposal.challenger_bond = dispute_bond;
+
+    if dispute_bond > 0 {
+        token::transfer(ctx.accounts.transfer_context(), dispute_bond)?;
+    }
+
+    emit!(DisputedPeriodEvent {
+        sla_account_address: ctx.accounts.sla.key(),
+        period: period as u64,
+        challenger: ctx.accounts.challenger.key(),
+    });
+
+    Ok(())
+}
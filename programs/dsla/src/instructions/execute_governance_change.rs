@@ -0,0 +1,44 @@
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::events::ExecutedGovernanceChangeEvent;
+use crate::state::governance::{Governance, GovernanceChange};
+use anchor_lang::prelude::*;
+
+/// Instruction to apply the pending governance change once its timelock ended, anyone can execute it
+#[derive(Accounts)]
+pub struct ExecuteGovernanceChange<'info> {
+    #[account(
+        mut,
+        seeds = [GOVERNANCE_SEED.as_bytes()],
+        bump,
+        constraint = governance.version == Governance::VERSION @ ErrorCode::AccountNotMigrated
+    )]
+    pub governance: Account<'info, Governance>,
+    #[account(
+        mut,
+        close = proposer,
+        seeds = [GOVERNANCE_CHANGE_SEED.as_bytes()],
+        bump
+    )]
+    pub governance_change: Account<'info, GovernanceChange>,
+    /// the proposer gets back the rent of the governance change
+    #[account(mut, address = governance_change.proposer)]
+    pub proposer: SystemAccount<'info>,
+}
+
+pub(crate) fn handler(ctx: Context<ExecuteGovernanceChange>) -> Result<()> {
+    let governance_change = &ctx.accounts.governance_change;
+    require_gte!(
+        Clock::get()?.unix_timestamp,
+        governance_change.executable_at,
+        ErrorCode::GovernanceChangeTimelocked
+    );
+    ctx.accounts
+        .governance
+        .set_parameters(&governance_change.parameters)?;
+
+    emit!(ExecutedGovernanceChangeEvent {
+        parameters: governance_change.parameters.clone(),
+    });
+    Ok(())
+}
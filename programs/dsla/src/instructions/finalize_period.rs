@@ -0,0 +1,206 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, CloseAccount, Mint, Token, TokenAccount, Transfer};
+
+use crate::constants::*;
+use crate::errors::{ErrorCode, FeedErrorCode};
+use crate::events::ValidatedPeriodEvent;
+use crate::instructions::validate_period::pay_verification_rewards;
+use crate::state::sla::Sla;
+use crate::state::status_registry::{Status, StatusRegistry};
+use crate::state::{Governance, OracleSource, PeriodCheckpoint, Proposal, SlaAuthority};
+
+/// Instruction to settle a period with its proposed SLI once the dispute window ended without dispute,
+/// anyone can finalize, the proposer gets back its bond and the validator reward
+#[derive(Accounts)]
+#[instruction(period: u64)]
+pub struct FinalizePeriod<'info> {
+    #[account(
+        mut,
+        constraint = sla.oracle_source == OracleSource::Optimistic @ FeedErrorCode::NotOptimisticValidation,
+        constraint = sla.version == Sla::VERSION @ ErrorCode::AccountNotMigrated
+    )]
+    pub sla: Account<'info, Sla>,
+
+    #[account(
+        seeds = [SLA_AUTHORITY_SEED.as_bytes(), sla.key().as_ref()],
+        bump
+    )]
+    pub sla_authority: Account<'info, SlaAuthority>,
+
+    #[account(
+        mut,
+        seeds = [STATUS_REGISTRY_SEED.as_bytes(), sla.key().as_ref()],
+        bump,
+        constraint = status_registry.is_current(sla.period_data.n_periods) @ ErrorCode::AccountNotMigrated
+    )]
+    pub status_registry: Account<'info, StatusRegistry>,
+
+    /// the checkpoint of the period, see `checkpoint_period`
+    #[account(
+        seeds = [CHECKPOINT_SEED.as_bytes(), sla.key().as_ref(), &period.to_le_bytes()],
+        bump
+    )]
+    pub checkpoint: Account<'info, PeriodCheckpoint>,
+
+    #[account(
+        seeds = [GOVERNANCE_SEED.as_bytes()],
+        bump,
+        constraint = governance.version == Governance::VERSION @ ErrorCode::AccountNotMigrated
+    )]
+    pub governance: Account<'info, Governance>,
+
+    #[account(
+        mut,
+        close = proposer,
+        seeds = [PROPOSAL_SEED.as_bytes(), sla.key().as_ref(), &period.to_le_bytes()],
+        bump
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    /// the proposer gets back the rent of the proposal accounts
+    #[account(mut, address = proposal.proposer)]
+    pub proposer: SystemAccount<'info>,
+
+    #[account(mut, address = governance.dsla_mint @ ErrorCode::InvalidDslaMint)]
+    pub dsla_mint: Box<Account<'info, Mint>>,
+    #[account(
+        mut,
+        seeds = [DSLA_POOL_SEED.as_bytes(), sla.key().as_ref()],
+        token::mint = dsla_mint,
+        token::authority = sla_authority,
+        bump,
+    )]
+    pub dsla_pool: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [BOND_VAULT_SEED.as_bytes(), proposal.key().as_ref()],
+        token::mint = dsla_mint,
+        token::authority = sla_authority,
+        bump,
+    )]
+    pub bond_vault: Box<Account<'info, TokenAccount>>,
+
+    /// The proposer token account to pay the bond and the DSLA reward to
+    #[account(mut, associated_token::mint=dsla_mint, associated_token::authority=proposer)]
+    pub proposer_dsla_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = dsla_mint,
+        associated_token::authority = governance.protocol_treasury
+    )]
+    pub protocol_dsla_token_account: Box<Account<'info, TokenAccount>>,
+    /// The program for interacting with the token.
+    pub token_program: Program<'info, Token>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// pays all the bonds held in the `bond_vault` of a proposal to `to` and closes the vault
+///
+/// # Arguments
+///
+/// * `to` - the token account of the account the bonds are returned or slashed to
+/// * `rent_destination` - the account that paid the rent of the vault
+/// * `signer_seeds` - the seeds of the `sla_authority` of the SLA
+pub fn release_bonds<'info>(
+    token_program: AccountInfo<'info>,
+    bond_vault: &Account<'info, TokenAccount>,
+    to: AccountInfo<'info>,
+    rent_destination: AccountInfo<'info>,
+    sla_authority: AccountInfo<'info>,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    if bond_vault.amount > 0 {
+        let transfer_context = CpiContext::new_with_signer(
+            token_program.clone(),
+            Transfer {
+                from: bond_vault.to_account_info(),
+                to,
+                authority: sla_authority.clone(),
+            },
+            signer_seeds,
+        );
+        token::transfer(transfer_context, bond_vault.amount)?;
+    }
+    let close_context = CpiContext::new_with_signer(
+        token_program,
+        CloseAccount {
+            account: bond_vault.to_account_info(),
+            destination: rent_destination,
+            authority: sla_authority,
+        },
+        signer_seeds,
+    );
+    token::close_account(close_context)
+}
+
+pub(crate) fn handler(ctx: Context<FinalizePeriod>, period: u64) -> Result<()> {
+    let authority_bump = *ctx
+        .bumps
+        .get("sla_authority")
+        .expect("sla_authority should exists");
+
+    let period = period as usize;
+    let status_registry = &mut ctx.accounts.status_registry.status_registry;
+    require_gt!(status_registry.len(), period, ErrorCode::InvalidPeriodId);
+    require!(
+        status_registry[period] == Status::Proposed,
+        ErrorCode::PeriodNotProposed
+    );
+    require_gt!(
+        Clock::get()?.unix_timestamp,
+        ctx.accounts.proposal.dispute_deadline,
+        ErrorCode::DisputeWindowNotEnded
+    );
+
+    // UPDATE STATUS
+    let sli = ctx.accounts.proposal.value;
+    let periods_left = status_registry.len().checked_sub(period).unwrap();
+    let checkpoint = &ctx.accounts.checkpoint;
+    let respected = ctx
+        .accounts
+        .sla
+        .settle_period(sli, periods_left, checkpoint.user_pool_size)?;
+    let status = Status::settled(respected, sli, 0);
+    status_registry[period] = status.clone();
+    ctx.accounts
+        .sla
+        .release_pending_transfer(checkpoint.max_transfer, status_registry);
+
+    // REWARD PROPOSER
+    let sla_key = ctx.accounts.sla.key();
+    let seeds = &[
+        SLA_AUTHORITY_SEED.as_bytes(),
+        sla_key.as_ref(),
+        &[authority_bump],
+    ];
+    let accounts = &ctx.accounts;
+    pay_verification_rewards(
+        &accounts.governance,
+        accounts.token_program.to_account_info(),
+        accounts.dsla_mint.to_account_info(),
+        accounts.dsla_pool.to_account_info(),
+        accounts.sla_authority.to_account_info(),
+        accounts.protocol_dsla_token_account.to_account_info(),
+        accounts.proposer_dsla_token_account.to_account_info(),
+        &[&seeds[..]],
+    )?;
+    release_bonds(
+        accounts.token_program.to_account_info(),
+        &accounts.bond_vault,
+        accounts.proposer_dsla_token_account.to_account_info(),
+        accounts.proposer.to_account_info(),
+        accounts.sla_authority.to_account_info(),
+        &[&seeds[..]],
+    )?;
+
+    emit!(ValidatedPeriodEvent {
+        sla_account_address: sla_key,
+        period: period as u64,
+        status,
+    });
+
+    Ok(())
+}
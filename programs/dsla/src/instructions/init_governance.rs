@@ -1,9 +1,8 @@
 use crate::constants::*;
-use crate::errors::ErrorCode;
 use crate::program::Dsla;
-use crate::state::governance::Governance;
-use crate::state::DslaDecimal;
+use crate::state::governance::{Governance, GovernanceParameters};
 use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
 
 /// Instruction to initialize the SLARegistry
 #[derive(Accounts)]
@@ -20,6 +19,10 @@ pub struct InitGovernance<'info> {
     )]
     pub governance: Account<'info, Governance>,
 
+    /// the DSLA mint all the SLAs pay their deposits, rewards and bonds in, it can't be changed afterwards
+    #[account(constraint = dsla_mint.is_initialized)]
+    pub dsla_mint: Account<'info, Mint>,
+
     #[account(address = crate::ID)]
     pub program: Program<'info, Dsla>,
     #[account(constraint = program_data.upgrade_authority_address == Some(program_upgrade_authority.key()))]
@@ -27,30 +30,16 @@ pub struct InitGovernance<'info> {
     pub system_program: Program<'info, System>,
 }
 
-pub fn handler(
+pub(crate) fn handler(
     ctx: Context<InitGovernance>,
-    dsla_deposit_by_period: u64,
-    dsla_protocol_reward: u64,
-    dsla_validator_reward: u64,
-    dsla_burned_by_verification: u64,
-    sla_deployer_rewards_rate: DslaDecimal,
-    protocol_rewards_rate: DslaDecimal,
-    max_leverage: DslaDecimal,
+    parameters: GovernanceParameters,
 ) -> Result<()> {
-    require!(
-        dsla_deposit_by_period
-            == (dsla_burned_by_verification + dsla_validator_reward + dsla_protocol_reward),
-        ErrorCode::NonValidGovernanceParameters
-    );
-    let governance = &mut ctx.accounts.governance;
-
-    governance.dsla_deposit_by_period = dsla_deposit_by_period;
-    governance.dsla_protocol_reward = dsla_protocol_reward;
-    governance.dsla_validator_reward = dsla_validator_reward;
-    governance.dsla_burned_by_verification = dsla_burned_by_verification;
-    governance.sla_deployer_rewards_rate = sla_deployer_rewards_rate;
-    governance.protocol_rewards_rate = protocol_rewards_rate;
-    governance.max_leverage = max_leverage;
+    // the upgrade authority only bootstraps the governance, it can transfer its management afterwards
+    ctx.accounts.governance.set_inner(Governance::new(
+        &parameters,
+        ctx.accounts.program_upgrade_authority.key(),
+        ctx.accounts.dsla_mint.key(),
+    )?);
 
     msg!("Governance Initialised successfully");
     Ok(())
@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
 
 use crate::constants::*;
+use crate::errors::ErrorCode;
 use crate::state::{Lockup, Sla};
 
 /// Instruction to initialize the SLARegistry
@@ -9,7 +10,9 @@ pub struct InitLockupAccounts<'info> {
     #[account(mut)]
     pub user_provider: Signer<'info>,
 
-    #[account()]
+    #[account(
+        constraint = sla.version == Sla::VERSION @ ErrorCode::AccountNotMigrated
+    )]
     pub sla: Account<'info, Sla>,
 
     #[account(
@@ -41,7 +44,7 @@ pub struct InitLockupAccounts<'info> {
     pub system_program: Program<'info, System>,
 }
 
-pub fn handler(ctx: Context<InitLockupAccounts>) -> Result<()> {
+pub(crate) fn handler(ctx: Context<InitLockupAccounts>) -> Result<()> {
     ctx.accounts.pt_lockup.set_inner(Lockup::new());
     ctx.accounts.ut_lockup.set_inner(Lockup::new());
     Ok(())
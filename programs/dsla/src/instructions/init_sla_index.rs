@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::state::sla_registry::{SlaIndexKind, SlaRegistryHeader, SlaRegistryPage};
+
+/// Instruction to initialize the index of the SLAs of a deployer or a mint with its first page,
+/// anyone can initialize it ahead of time, `deploy_sla` also creates it on demand
+#[derive(Accounts)]
+#[instruction(kind: SlaIndexKind)]
+pub struct InitSlaIndex<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// CHECK: the deployer or the mint the SLAs are indexed by, only used as a seed
+    pub indexed_key: UncheckedAccount<'info>,
+    #[account(
+        init,
+        payer = payer,
+        space = SlaRegistryHeader::LEN,
+        seeds = [kind.seed().as_bytes(), indexed_key.key().as_ref()],
+        bump
+    )]
+    pub sla_index: Account<'info, SlaRegistryHeader>,
+    #[account(
+        init,
+        payer = payer,
+        space = SlaRegistryPage::space(SLAS_PER_INDEX_PAGE),
+        seeds = [SLA_INDEX_PAGE_SEED.as_bytes(), sla_index.key().as_ref(), &0u64.to_le_bytes()],
+        bump
+    )]
+    pub sla_index_page: Account<'info, SlaRegistryPage>,
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(ctx: Context<InitSlaIndex>, _kind: SlaIndexKind) -> Result<()> {
+    let sla_index = &mut ctx.accounts.sla_index;
+    sla_index.sla_count = 0;
+    sla_index.page_count = 1;
+    sla_index.page_size = SLAS_PER_INDEX_PAGE;
+    Ok(())
+}
@@ -1,18 +1,39 @@
 use anchor_lang::prelude::*;
 
-use crate::state::sla_registry::SlaRegistry;
+use crate::constants::*;
+use crate::state::sla_registry::{SlaRegistryHeader, SlaRegistryPage};
 
-/// Instruction to initialize the SLARegistry
+/// Instruction to initialize the SLARegistry with its first page
+///
+/// the registry used to be a keypair account with the public keys of all the SLAs, it is abandoned
+/// and not migrated, the SLAs deployed before the paged registry are not part of it
 #[derive(Accounts)]
 pub struct InitSlaRegistry<'info> {
     #[account(mut)]
     pub deployer: Signer<'info>,
-    #[account(zero)]
-    pub sla_registry: Account<'info, SlaRegistry>,
+    #[account(
+        init,
+        payer = deployer,
+        space = SlaRegistryHeader::LEN,
+        seeds = [SLA_REGISTRY_HEADER_SEED.as_bytes()],
+        bump
+    )]
+    pub sla_registry: Account<'info, SlaRegistryHeader>,
+    #[account(
+        init,
+        payer = deployer,
+        space = SlaRegistryPage::space(SLAS_PER_PAGE),
+        seeds = [SLA_REGISTRY_PAGE_SEED.as_bytes(), &0u64.to_le_bytes()],
+        bump
+    )]
+    pub sla_registry_page: Account<'info, SlaRegistryPage>,
     pub system_program: Program<'info, System>,
 }
 
-pub fn handler(_ctx: Context<InitSlaRegistry>) -> Result<()> {
+pub(crate) fn handler(ctx: Context<InitSlaRegistry>) -> Result<()> {
+    ctx.accounts.sla_registry.sla_count = 0;
+    ctx.accounts.sla_registry.page_count = 1;
+    ctx.accounts.sla_registry.page_size = SLAS_PER_PAGE;
     msg!("SLA registry Initialized");
     Ok(())
 }
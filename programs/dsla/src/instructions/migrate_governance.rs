@@ -0,0 +1,90 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+
+use crate::constants::*;
+use crate::events::MigratedGovernanceEvent;
+use crate::program::Dsla;
+use crate::state::governance::{Governance, GovernanceParameters};
+use anchor_spl::token::Mint;
+
+/// Instruction for the program upgrade authority, that managed the unversioned governance,
+/// to upgrade the governance account to the current layout,
+/// the account is reallocated in place and the authority pays for the extra rent
+#[derive(Accounts)]
+pub struct MigrateGovernance<'info> {
+    #[account(mut)]
+    pub program_upgrade_authority: Signer<'info>,
+
+    /// CHECK: can't be deserialized before its migration, `Governance::migrate` checks the layout
+    #[account(
+        mut,
+        owner = crate::ID,
+        seeds = [GOVERNANCE_SEED.as_bytes()],
+        bump
+    )]
+    pub governance: UncheckedAccount<'info>,
+
+    /// the DSLA mint all the SLAs pay their deposits, rewards and bonds in, the unversioned governance doesn't have it
+    #[account(constraint = dsla_mint.is_initialized)]
+    pub dsla_mint: Account<'info, Mint>,
+
+    #[account(address = crate::ID)]
+    pub program: Program<'info, Dsla>,
+    #[account(constraint = program_data.upgrade_authority_address == Some(program_upgrade_authority.key()))]
+    pub program_data: Account<'info, ProgramData>,
+    pub system_program: Program<'info, System>,
+}
+
+/// reallocates `account` to `len` bytes and writes the `migrated` layout into it,
+/// the `payer` pays the rent of the extra space
+pub fn write_migrated_account<'info, T: AccountSerialize>(
+    account: &AccountInfo<'info>,
+    migrated: &T,
+    len: usize,
+    payer: AccountInfo<'info>,
+    system_program: AccountInfo<'info>,
+) -> Result<()> {
+    let rent_exempt_lamports = Rent::get()?.minimum_balance(len);
+    let missing_lamports = rent_exempt_lamports.saturating_sub(account.lamports());
+    if missing_lamports > 0 {
+        let transfer_context = CpiContext::new(
+            system_program,
+            Transfer {
+                from: payer,
+                to: account.clone(),
+            },
+        );
+        system_program::transfer(transfer_context, missing_lamports)?;
+    }
+    account.realloc(len, true)?;
+
+    let mut data = account.try_borrow_mut_data()?;
+    migrated.try_serialize(&mut &mut data[..])
+}
+
+/// the `parameters` give the values of the parameters the unversioned governance doesn't have
+pub(crate) fn handler(
+    ctx: Context<MigrateGovernance>,
+    parameters: GovernanceParameters,
+) -> Result<()> {
+    let governance_info = ctx.accounts.governance.to_account_info();
+    let governance = Governance::migrate(
+        &governance_info.try_borrow_data()?,
+        &parameters,
+        ctx.accounts.program_upgrade_authority.key(),
+        ctx.accounts.dsla_mint.key(),
+    )?;
+
+    write_migrated_account(
+        &governance_info,
+        &governance,
+        Governance::LEN,
+        ctx.accounts.program_upgrade_authority.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+    )?;
+
+    emit!(MigratedGovernanceEvent {
+        version: governance.version,
+    });
+    Ok(())
+}
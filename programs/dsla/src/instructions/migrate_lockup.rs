@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+use crate::events::MigratedLockupEvent;
+use crate::instructions::migrate_governance::write_migrated_account;
+use crate::state::Lockup;
+
+/// Instruction to upgrade a user or provider lockup account to the current layout, anyone can migrate a lockup
+/// as the migration keeps all its data, the account is reallocated in place and the payer pays for the extra rent
+#[derive(Accounts)]
+pub struct MigrateLockup<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: can't be deserialized before its migration, `Lockup::migrate` checks the layout
+    #[account(mut, owner = crate::ID)]
+    pub lockup: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(ctx: Context<MigrateLockup>) -> Result<()> {
+    let lockup_info = ctx.accounts.lockup.to_account_info();
+    let lockup = Lockup::migrate(&lockup_info.try_borrow_data()?)?;
+
+    write_migrated_account(
+        &lockup_info,
+        &lockup,
+        Lockup::LEN,
+        ctx.accounts.payer.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+    )?;
+
+    emit!(MigratedLockupEvent {
+        lockup_account_address: lockup_info.key(),
+        version: lockup.version,
+    });
+    Ok(())
+}
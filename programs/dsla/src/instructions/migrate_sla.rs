@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+use crate::events::MigratedSlaEvent;
+use crate::instructions::migrate_governance::write_migrated_account;
+use crate::state::sla::Sla;
+
+/// Instruction to upgrade an SLA account to the current layout, anyone can migrate an SLA
+/// as the migration keeps all its data, the account is reallocated in place and the payer pays for the extra rent
+#[derive(Accounts)]
+pub struct MigrateSla<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: can't be deserialized before its migration, `Sla::migrate` checks the layout
+    #[account(mut, owner = crate::ID)]
+    pub sla: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(ctx: Context<MigrateSla>) -> Result<()> {
+    let sla_info = ctx.accounts.sla.to_account_info();
+    let sla = Sla::migrate(&sla_info.try_borrow_data()?)?;
+
+    write_migrated_account(
+        &sla_info,
+        &sla,
+        Sla::LEN,
+        ctx.accounts.payer.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+    )?;
+
+    emit!(MigratedSlaEvent {
+        sla_account_address: sla_info.key(),
+        version: sla.version,
+    });
+    Ok(())
+}
@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+use crate::events::MigratedStatusRegistryEvent;
+use crate::instructions::migrate_governance::write_migrated_account;
+use crate::state::status_registry::StatusRegistry;
+
+/// Instruction to upgrade the status registry of an SLA to the current layout, anyone can migrate a registry
+/// as the migration keeps all its statuses, the account is reallocated in place to the size of its periods
+/// and the payer pays for the extra rent
+#[derive(Accounts)]
+pub struct MigrateStatusRegistry<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: can't be deserialized before its migration, `StatusRegistry::migrate` checks the layout
+    #[account(mut, owner = crate::ID)]
+    pub status_registry: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(ctx: Context<MigrateStatusRegistry>) -> Result<()> {
+    let status_registry_info = ctx.accounts.status_registry.to_account_info();
+    let status_registry = StatusRegistry::migrate(&status_registry_info.try_borrow_data()?)?;
+
+    write_migrated_account(
+        &status_registry_info,
+        &status_registry,
+        StatusRegistry::space(status_registry.status_registry.len() as u32),
+        ctx.accounts.payer.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+    )?;
+
+    emit!(MigratedStatusRegistryEvent {
+        status_registry_account_address: status_registry_info.key(),
+        version: status_registry.version,
+    });
+    Ok(())
+}
@@ -1,21 +1,74 @@
+pub mod accept_governance_authority;
+pub mod add_sla_index_page;
+pub mod add_sla_registry_page;
+pub mod cancel_governance_change;
+pub mod cancel_sla;
+pub mod checkpoint_period;
+pub mod close_checkpoint;
+pub mod close_lockup_accounts;
+pub mod close_pyth_sample;
+pub mod close_sla;
 pub mod deploy_sla;
+pub mod dispute_sli;
+pub mod execute_governance_change;
+pub mod finalize_period;
 pub mod init_governance;
 pub mod init_lockup_accounts;
+pub mod init_sla_index;
 pub mod init_sla_registry;
-pub mod modify_governance;
+pub mod migrate_governance;
+pub mod migrate_lockup;
+pub mod migrate_sla;
+pub mod migrate_status_registry;
+pub mod propose_governance_change;
+pub mod propose_sli;
+pub mod reclaim_dsla_deposit;
+pub mod record_pyth_sample;
+pub mod resize_status_registry;
+pub mod resolve_dispute;
+pub mod set_protocol_paused;
+pub mod set_sla_paused;
 pub mod stake_provider;
 pub mod stake_user;
+pub mod transfer_governance_authority;
+pub mod validate_pending_periods;
 pub mod validate_period;
 pub mod withdraw_provider;
 pub mod withdraw_user;
 
+pub use accept_governance_authority::*;
+pub use add_sla_index_page::*;
+pub use add_sla_registry_page::*;
+pub use cancel_governance_change::*;
+pub use cancel_sla::*;
+pub use checkpoint_period::*;
+pub use close_checkpoint::*;
+pub use close_lockup_accounts::*;
+pub use close_pyth_sample::*;
+pub use close_sla::*;
 pub use deploy_sla::*;
+pub use dispute_sli::*;
+pub use execute_governance_change::*;
+pub use finalize_period::*;
 pub use init_governance::*;
 pub use init_lockup_accounts::*;
+pub use init_sla_index::*;
 pub use init_sla_registry::*;
-pub use modify_governance::*;
+pub use migrate_governance::*;
+pub use migrate_lockup::*;
+pub use migrate_sla::*;
+pub use migrate_status_registry::*;
+pub use propose_governance_change::*;
+pub use propose_sli::*;
+pub use reclaim_dsla_deposit::*;
+pub use record_pyth_sample::*;
+pub use resize_status_registry::*;
+pub use resolve_dispute::*;
+pub use set_protocol_paused::*;
+pub use set_sla_paused::*;
 pub use stake_provider::*;
 pub use stake_user::*;
+pub use transfer_governance_authority::*;
 pub use validate_period::*;
 pub use withdraw_provider::*;
 pub use withdraw_user::*;
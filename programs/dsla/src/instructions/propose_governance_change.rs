@@ -0,0 +1,50 @@
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::events::ProposedGovernanceChangeEvent;
+use crate::state::governance::{Governance, GovernanceChange, GovernanceParameters};
+use anchor_lang::prelude::*;
+
+/// Instruction to propose new governance parameters, they can be executed after `GOVERNANCE_CHANGE_DELAY`
+#[derive(Accounts)]
+pub struct ProposeGovernanceChange<'info> {
+    #[account(mut, address = governance.governance_authority @ ErrorCode::UnauthorizedGovernanceAuthority)]
+    pub governance_authority: Signer<'info>,
+    #[account(
+        seeds = [GOVERNANCE_SEED.as_bytes()],
+        bump,
+        constraint = governance.version == Governance::VERSION @ ErrorCode::AccountNotMigrated
+    )]
+    pub governance: Account<'info, Governance>,
+    #[account(
+        init,
+        payer = governance_authority,
+        space = GovernanceChange::LEN,
+        seeds = [GOVERNANCE_CHANGE_SEED.as_bytes()],
+        bump
+    )]
+    pub governance_change: Account<'info, GovernanceChange>,
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(
+    ctx: Context<ProposeGovernanceChange>,
+    parameters: GovernanceParameters,
+) -> Result<()> {
+    parameters.validate()?;
+    let proposed_at = Clock::get()?.unix_timestamp;
+    let executable_at = proposed_at
+        .checked_add(GOVERNANCE_CHANGE_DELAY)
+        .ok_or(ErrorCode::CheckedOperationOverflow)?;
+
+    let governance_change = &mut ctx.accounts.governance_change;
+    governance_change.proposer = ctx.accounts.governance_authority.key();
+    governance_change.parameters = parameters.clone();
+    governance_change.proposed_at = proposed_at;
+    governance_change.executable_at = executable_at;
+
+    emit!(ProposedGovernanceChangeEvent {
+        parameters,
+        executable_at,
+    });
+    Ok(())
+}
@@ -0,0 +1,130 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+
+use crate::constants::*;
+use crate::errors::{ErrorCode, FeedErrorCode};
+use crate::events::ProposedPeriodEvent;
+use crate::state::sla::{DslaDecimal, Sla};
+use crate::state::status_registry::{Status, StatusRegistry};
+use crate::state::{Governance, OracleSource, Proposal, SlaAuthority};
+
+/// Instruction to propose the SLI of a finished period of an SLA using optimistic validation,
+/// anyone can propose by bonding the governance `proposal_bond`
+#[derive(Accounts)]
+#[instruction(period: u64)]
+pub struct ProposeSli<'info> {
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+
+    #[account(
+        constraint = sla.oracle_source == OracleSource::Optimistic @ FeedErrorCode::NotOptimisticValidation,
+        constraint = sla.version == Sla::VERSION @ ErrorCode::AccountNotMigrated
+    )]
+    pub sla: Account<'info, Sla>,
+
+    #[account(
+        seeds = [SLA_AUTHORITY_SEED.as_bytes(), sla.key().as_ref()],
+        bump
+    )]
+    pub sla_authority: Account<'info, SlaAuthority>,
+
+    #[account(
+        mut,
+        seeds = [STATUS_REGISTRY_SEED.as_bytes(), sla.key().as_ref()],
+        bump,
+        constraint = status_registry.is_current(sla.period_data.n_periods) @ ErrorCode::AccountNotMigrated
+    )]
+    pub status_registry: Account<'info, StatusRegistry>,
+
+    #[account(
+        seeds = [GOVERNANCE_SEED.as_bytes()],
+        bump,
+        constraint = governance.version == Governance::VERSION @ ErrorCode::AccountNotMigrated
+    )]
+    pub governance: Account<'info, Governance>,
+
+    #[account(
+        init,
+        payer = proposer,
+        space = Proposal::LEN,
+        seeds = [PROPOSAL_SEED.as_bytes(), sla.key().as_ref(), &period.to_le_bytes()],
+        bump
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    #[account(address = governance.dsla_mint @ ErrorCode::InvalidDslaMint)]
+    pub dsla_mint: Box<Account<'info, Mint>>,
+
+    /// holds the bonds of the proposal until the period is final
+    #[account(
+        init,
+        payer = proposer,
+        seeds = [BOND_VAULT_SEED.as_bytes(), proposal.key().as_ref()],
+        token::mint = dsla_mint,
+        token::authority = sla_authority,
+        bump,
+    )]
+    pub bond_vault: Box<Account<'info, TokenAccount>>,
+
+    /// The token account to pay the bond from
+    #[account(mut, associated_token::mint=dsla_mint, associated_token::authority=proposer)]
+    pub proposer_dsla_token_account: Box<Account<'info, TokenAccount>>,
+
+    /// The program for interacting with the token.
+    pub token_program: Program<'info, Token>,
+    pub rent: Sysvar<'info, Rent>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ProposeSli<'info> {
+    fn transfer_context(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            Transfer {
+                from: self.proposer_dsla_token_account.to_account_info(),
+                to: self.bond_vault.to_account_info(),
+                authority: self.proposer.to_account_info(),
+            },
+        )
+    }
+}
+
+pub(crate) fn handler(ctx: Context<ProposeSli>, period: u64, sli: DslaDecimal) -> Result<()> {
+    let status_registry = &mut ctx.accounts.status_registry.status_registry;
+    let period = period as usize;
+    require_gt!(status_registry.len(), period, ErrorCode::InvalidPeriodId);
+    require!(
+        status_registry[period] == Status::NotVerified,
+        ErrorCode::AlreadyVerifiedPeriod
+    );
+    require!(
+        ctx.accounts.sla.period_data.has_finished(period)?,
+        ErrorCode::InvalidPeriodId
+    );
+    status_registry[period] = Status::Proposed;
+
+    let governance = &ctx.accounts.governance;
+    let proposed_at = Clock::get()?.unix_timestamp;
+    let proposal = &mut ctx.accounts.proposal;
+    proposal.proposer = ctx.accounts.proposer.key();
+    proposal.value = sli;
+    proposal.proposer_bond = governance.proposal_bond;
+    proposal.dispute_deadline = governance.dispute_deadline(proposed_at)?;
+    proposal.challenger = None;
+    proposal.challenger_bond = 0;
+
+    if governance.proposal_bond > 0 {
+        token::transfer(ctx.accounts.transfer_context(), governance.proposal_bond)?;
+    }
+
+    emit!(ProposedPeriodEvent {
+        sla_account_address: ctx.accounts.sla.key(),
+        period: period as u64,
+        proposer: ctx.accounts.proposal.proposer,
+        value: sli,
+        dispute_deadline: ctx.accounts.proposal.dispute_deadline,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,136 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::events::ReclaimedDslaDepositEvent;
+use crate::state::sla::Sla;
+use crate::state::status_registry::StatusRegistry;
+use crate::state::{Governance, SlaAuthority, SlaStatus};
+
+/// Instruction to reclaim the DSLA deposit of the periods of an ended SLA that were never verified,
+/// once the grace period of the governance has passed the periods expire and their deposit goes
+/// to the deployer or to the protocol treasury depending on the governance policy, anyone can reclaim
+#[derive(Accounts)]
+pub struct ReclaimDslaDeposit<'info> {
+    #[account(
+        mut,
+        constraint = sla.version == Sla::VERSION @ ErrorCode::AccountNotMigrated
+    )]
+    pub sla: Account<'info, Sla>,
+
+    #[account(
+        seeds = [SLA_AUTHORITY_SEED.as_bytes(), sla.key().as_ref()],
+        bump
+    )]
+    pub sla_authority: Account<'info, SlaAuthority>,
+
+    #[account(
+        mut,
+        seeds = [STATUS_REGISTRY_SEED.as_bytes(), sla.key().as_ref()],
+        bump,
+        constraint = status_registry.is_current(sla.period_data.n_periods) @ ErrorCode::AccountNotMigrated
+    )]
+    pub status_registry: Account<'info, StatusRegistry>,
+
+    #[account(
+        seeds = [GOVERNANCE_SEED.as_bytes()],
+        bump,
+        constraint = governance.version == Governance::VERSION @ ErrorCode::AccountNotMigrated
+    )]
+    pub governance: Account<'info, Governance>,
+
+    #[account(address = governance.dsla_mint @ ErrorCode::InvalidDslaMint)]
+    pub dsla_mint: Box<Account<'info, Mint>>,
+    #[account(
+        mut,
+        seeds = [DSLA_POOL_SEED.as_bytes(), sla.key().as_ref()],
+        token::mint = dsla_mint,
+        token::authority = sla_authority,
+        bump,
+    )]
+    pub dsla_pool: Box<Account<'info, TokenAccount>>,
+
+    /// The token account of the deployer or of the protocol treasury depending on the governance policy
+    #[account(
+        mut,
+        associated_token::mint = dsla_mint,
+        associated_token::authority = governance.deposit_reclaim_destination(sla.sla_deployer_address)
+    )]
+    pub destination_dsla_token_account: Box<Account<'info, TokenAccount>>,
+
+    /// The program for interacting with the token.
+    pub token_program: Program<'info, Token>,
+}
+
+pub(crate) fn handler(ctx: Context<ReclaimDslaDeposit>) -> Result<()> {
+    let period_data = &ctx.accounts.sla.period_data;
+    require!(
+        period_data.get_current_period_id()? == SlaStatus::Ended,
+        ErrorCode::SlaNotEnded
+    );
+    let sla_end = period_data.get_end(period_data.n_periods.saturating_sub(1) as usize)?;
+    let reclaimable_after = sla_end
+        .checked_add(ctx.accounts.governance.deposit_reclaim_grace_period as u128)
+        .ok_or(ErrorCode::CheckedOperationOverflow)?;
+    require_gt!(
+        Clock::get()?.unix_timestamp as u128,
+        reclaimable_after,
+        ErrorCode::DepositReclaimGracePeriod
+    );
+
+    // EXPIRE THE UNVERIFIED PERIODS
+    let status_registry = &mut ctx.accounts.status_registry;
+    let periods = status_registry.expire_unverified();
+    require_gt!(periods, 0, ErrorCode::NoPendingPeriods);
+    ctx.accounts
+        .sla
+        .release_pending_transfer(0, &status_registry.status_registry);
+
+    // the deposit by period may have changed since the deployment,
+    // the whole pool is reclaimed once no period can be paid from it anymore
+    let dsla_pool_amount = ctx.accounts.dsla_pool.amount;
+    let amount = if status_registry.all_settled() {
+        dsla_pool_amount
+    } else {
+        ctx.accounts
+            .governance
+            .dsla_deposit_by_period
+            .checked_mul(periods as u64)
+            .ok_or(ErrorCode::CheckedOperationOverflow)?
+            .min(dsla_pool_amount)
+    };
+
+    let sla_key = ctx.accounts.sla.key();
+    let authority_bump = *ctx
+        .bumps
+        .get("sla_authority")
+        .expect("sla_authority should exists");
+    let seeds = &[
+        SLA_AUTHORITY_SEED.as_bytes(),
+        sla_key.as_ref(),
+        &[authority_bump],
+    ];
+    let signer_seeds = &[&seeds[..]];
+    let transfer_context = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        Transfer {
+            from: ctx.accounts.dsla_pool.to_account_info(),
+            to: ctx
+                .accounts
+                .destination_dsla_token_account
+                .to_account_info(),
+            authority: ctx.accounts.sla_authority.to_account_info(),
+        },
+        signer_seeds,
+    );
+    token::transfer(transfer_context, amount)?;
+
+    emit!(ReclaimedDslaDepositEvent {
+        sla_account_address: sla_key,
+        periods,
+        amount,
+        destination: ctx.accounts.destination_dsla_token_account.owner,
+    });
+    Ok(())
+}
@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::{ErrorCode, FeedErrorCode};
+use crate::state::sla::Sla;
+use crate::state::{OracleSource, PythSample, SampleWindow};
+
+/// Instruction to record the prices of the pyth feeds of an SLA during the sample window of a period,
+/// anyone can record once per period by paying the rent of the sample,
+/// every pyth feed of the SLA is passed in its order as remaining accounts
+///
+/// the window ends with the period, so the sample needs to be recorded in the `max_staleness` of the SLA
+/// before the end of the period, `validate_period` and `validate_pending_periods` read it afterwards
+#[derive(Accounts)]
+#[instruction(period: u64)]
+pub struct RecordPythSample<'info> {
+    #[account(mut)]
+    pub recorder: Signer<'info>,
+
+    #[account(
+        constraint = sla.oracle_source == OracleSource::Pyth @ FeedErrorCode::NotPythValidation,
+        constraint = sla.version == Sla::VERSION @ ErrorCode::AccountNotMigrated
+    )]
+    pub sla: Account<'info, Sla>,
+
+    #[account(
+        init,
+        payer = recorder,
+        space = PythSample::LEN,
+        seeds = [PYTH_SAMPLE_SEED.as_bytes(), sla.key().as_ref(), &period.to_le_bytes()],
+        bump
+    )]
+    pub pyth_sample: Account<'info, PythSample>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(ctx: Context<RecordPythSample>, period: u64) -> Result<()> {
+    let sla = &ctx.accounts.sla;
+    let period = period as usize;
+    let window = SampleWindow::new(
+        sla.period_data.get_start(period)?,
+        sla.period_data.get_end(period)?,
+        sla.max_staleness,
+    )?;
+    let results = sla.oracle_source.read_all(
+        &sla.oracle_addresses,
+        ctx.remaining_accounts,
+        &window,
+        sla.max_confidence_interval.to_decimal(),
+    )?;
+    ctx.accounts.pyth_sample.set_inner(PythSample::new(
+        ctx.accounts.recorder.key(),
+        &results,
+        sla.min_quorum,
+    )?);
+    Ok(())
+}
@@ -0,0 +1,100 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::state::sla::Sla;
+use crate::state::status_registry::{Status, StatusRegistry};
+use crate::state::{Governance, SlaStatus};
+
+/// Instruction for the deployer of an SLA to extend it to `n_periods`,
+/// the status registry grows to fit the new periods and the DSLA deposit is paid for them
+#[derive(Accounts)]
+#[instruction(n_periods: u32)]
+pub struct ResizeStatusRegistry<'info> {
+    #[account(mut)]
+    pub deployer: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = sla.sla_deployer_address == deployer.key() @ ErrorCode::UnauthorizedSlaDeployer,
+        constraint = n_periods <= MAX_PERIODS @ ErrorCode::MaxPeriodsExceeded,
+        constraint = sla.version == Sla::VERSION @ ErrorCode::AccountNotMigrated,
+    )]
+    pub sla: Account<'info, Sla>,
+
+    #[account(
+        mut,
+        seeds = [STATUS_REGISTRY_SEED.as_bytes(), sla.key().as_ref()],
+        bump,
+        constraint = status_registry.is_current(sla.period_data.n_periods) @ ErrorCode::AccountNotMigrated,
+        realloc = StatusRegistry::space(n_periods),
+        realloc::payer = deployer,
+        realloc::zero = false,
+    )]
+    pub status_registry: Account<'info, StatusRegistry>,
+
+    #[account(
+        seeds = [GOVERNANCE_SEED.as_bytes()],
+        bump,
+        constraint = governance.version == Governance::VERSION @ ErrorCode::AccountNotMigrated
+    )]
+    pub governance: Account<'info, Governance>,
+
+    #[account(address = governance.dsla_mint @ ErrorCode::InvalidDslaMint)]
+    pub dsla_mint: Box<Account<'info, Mint>>,
+    #[account(
+        mut,
+        seeds = [DSLA_POOL_SEED.as_bytes(), sla.key().as_ref()],
+        token::mint = dsla_mint,
+        bump,
+    )]
+    pub dsla_pool: Box<Account<'info, TokenAccount>>,
+
+    /// The token account to pay the DSLA fee of the new periods from
+    #[account(mut, associated_token::mint=dsla_mint, associated_token::authority=deployer)]
+    pub deployer_dsla_token_account: Box<Account<'info, TokenAccount>>,
+
+    /// The program for interacting with the token.
+    pub token_program: Program<'info, Token>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ResizeStatusRegistry<'info> {
+    fn transfer_context(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            Transfer {
+                from: self.deployer_dsla_token_account.to_account_info(),
+                to: self.dsla_pool.to_account_info(),
+                authority: self.deployer.to_account_info(),
+            },
+        )
+    }
+}
+
+pub(crate) fn handler(ctx: Context<ResizeStatusRegistry>, n_periods: u32) -> Result<()> {
+    let current_n_periods = ctx.accounts.sla.period_data.n_periods;
+    require_gt!(n_periods, current_n_periods, ErrorCode::InvalidPeriodCount);
+    require!(
+        ctx.accounts.sla.status()? != SlaStatus::Ended,
+        ErrorCode::SlaAlreadyEnded
+    );
+
+    let transfer_amount = ctx
+        .accounts
+        .governance
+        .dsla_deposit_by_period
+        .checked_mul((n_periods - current_n_periods) as u64)
+        .ok_or(ErrorCode::CheckedOperationOverflow)?;
+    token::transfer(ctx.accounts.transfer_context(), transfer_amount)?;
+
+    ctx.accounts.sla.period_data.n_periods = n_periods;
+    ctx.accounts
+        .status_registry
+        .status_registry
+        .resize(n_periods as usize, Status::NotVerified);
+
+    Ok(())
+}
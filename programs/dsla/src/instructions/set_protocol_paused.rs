@@ -0,0 +1,27 @@
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::events::SetProtocolPausedEvent;
+use crate::state::governance::Governance;
+use anchor_lang::prelude::*;
+
+/// Instruction for the guardian to pause or unpause staking and validation of all SLAs,
+/// withdrawals of available tokens stay allowed
+#[derive(Accounts)]
+pub struct SetProtocolPaused<'info> {
+    #[account(address = governance.guardian @ ErrorCode::UnauthorizedGuardian)]
+    pub guardian: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [GOVERNANCE_SEED.as_bytes()],
+        bump,
+        constraint = governance.version == Governance::VERSION @ ErrorCode::AccountNotMigrated
+    )]
+    pub governance: Account<'info, Governance>,
+}
+
+pub(crate) fn handler(ctx: Context<SetProtocolPaused>, paused: bool) -> Result<()> {
+    ctx.accounts.governance.paused = paused;
+
+    emit!(SetProtocolPausedEvent { paused });
+    Ok(())
+}
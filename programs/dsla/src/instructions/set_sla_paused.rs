@@ -0,0 +1,35 @@
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::events::SetSlaPausedEvent;
+use crate::state::governance::Governance;
+use crate::state::sla::Sla;
+use anchor_lang::prelude::*;
+
+/// Instruction for the guardian to pause or unpause staking and validation of an SLA,
+/// withdrawals of available tokens stay allowed
+#[derive(Accounts)]
+pub struct SetSlaPaused<'info> {
+    #[account(address = governance.guardian @ ErrorCode::UnauthorizedGuardian)]
+    pub guardian: Signer<'info>,
+    #[account(
+        seeds = [GOVERNANCE_SEED.as_bytes()],
+        bump,
+        constraint = governance.version == Governance::VERSION @ ErrorCode::AccountNotMigrated
+    )]
+    pub governance: Account<'info, Governance>,
+    #[account(
+        mut,
+        constraint = sla.version == Sla::VERSION @ ErrorCode::AccountNotMigrated
+    )]
+    pub sla: Account<'info, Sla>,
+}
+
+pub(crate) fn handler(ctx: Context<SetSlaPaused>, paused: bool) -> Result<()> {
+    ctx.accounts.sla.paused = paused;
+
+    emit!(SetSlaPausedEvent {
+        sla_account_address: ctx.accounts.sla.key(),
+        paused,
+    });
+    Ok(())
+}
@@ -1,23 +1,39 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Mint, MintTo, Token, TokenAccount, Transfer};
-use rust_decimal::prelude::*;
 
 use crate::constants::*;
 use crate::errors::ErrorCode;
 use crate::events::StakedProviderSideEvent;
+use crate::quote;
 use crate::state::sla::Sla;
-use crate::state::{Lockup, SlaAuthority, SlaStatus};
+use crate::state::{Governance, Lockup, SlaAuthority, SlaStatus};
 
 /// Instruction to stake on both sides
+/// it fails if less than `min_tokens_out` PT would be minted, see `quote`,
+/// exactly `token_amount` tokens are staked so `min_tokens_out` also bounds the price paid for each PT,
+/// and until every finished period is checkpointed, see `checkpoint_period`
 #[derive(Accounts)]
 pub struct StakeProvider<'info> {
     // provide or user
     #[account(mut)]
     pub staker: Signer<'info>,
 
-    #[account(mut)]
+    #[account(
+        mut,
+        constraint = !sla.cancelled @ ErrorCode::SlaCancelled,
+        constraint = !sla.paused @ ErrorCode::SlaPaused,
+        constraint = sla.version == Sla::VERSION @ ErrorCode::AccountNotMigrated,
+    )]
     pub sla: Account<'info, Sla>,
 
+    #[account(
+        seeds = [GOVERNANCE_SEED.as_bytes()],
+        bump,
+        constraint = !governance.paused @ ErrorCode::ProtocolPaused,
+        constraint = governance.version == Governance::VERSION @ ErrorCode::AccountNotMigrated,
+    )]
+    pub governance: Account<'info, Governance>,
+
     #[account(
         mut,
         seeds = [SLA_AUTHORITY_SEED.as_bytes(),sla.key().as_ref()],
@@ -27,7 +43,7 @@ pub struct StakeProvider<'info> {
 
     // @fixme make sure mint is same as defined in initialization
     #[account(
-        constraint = mint.is_initialized == true,
+        constraint = mint.is_initialized,
         constraint = mint.key() == sla.mint_address,
     )]
     pub mint: Account<'info, Mint>,
@@ -47,7 +63,7 @@ pub struct StakeProvider<'info> {
             PT_MINT_SEED.as_bytes(),
             sla.key().as_ref(),
         ],
-        constraint = pt_mint.is_initialized == true,
+        constraint = pt_mint.is_initialized,
         bump,
     )]
     pub pt_mint: Box<Account<'info, Mint>>,
@@ -72,6 +88,7 @@ pub struct StakeProvider<'info> {
             sla.key().as_ref(),
         ],
         bump,
+        constraint = pt_lockup.version == Lockup::VERSION @ ErrorCode::AccountNotMigrated,
     )]
     pub pt_lockup: Box<Account<'info, Lockup>>,
 
@@ -93,26 +110,22 @@ impl<'info> StakeProvider<'info> {
     }
 }
 
-pub fn handler(ctx: Context<StakeProvider>, token_amount: u64) -> Result<()> {
+pub(crate) fn handler(
+    ctx: Context<StakeProvider>,
+    token_amount: u64,
+    min_tokens_out: u64,
+) -> Result<()> {
     require!(
         ctx.accounts.sla.period_data.get_current_period_id()? != SlaStatus::Ended,
         ErrorCode::CannotStakeAfterSlaEnded
     );
+    // the stake is priced with the periods that finished before it, see `quote`
+    ctx.accounts
+        .sla
+        .check_checkpointed(ctx.accounts.sla.status()?)?;
 
-    let token_amount_dec = Decimal::from_u64(token_amount).unwrap();
-    let provider_pool_size_dec = Decimal::from_u128(ctx.accounts.sla.provider_pool_size).unwrap();
-    let pt_supply_dec = Decimal::from_u128(ctx.accounts.sla.pt_supply).unwrap();
-
-    let mut tokens_to_mint = token_amount;
-    // @todo add test for this
-    if provider_pool_size_dec != pt_supply_dec {
-        tokens_to_mint = token_amount_dec
-            .checked_div(provider_pool_size_dec.checked_div(pt_supply_dec).unwrap())
-            .unwrap()
-            .floor()
-            .to_u64()
-            .unwrap();
-    }
+    let tokens_to_mint = quote::stake_provider(&ctx.accounts.sla, token_amount)?;
+    require_gte!(tokens_to_mint, min_tokens_out, ErrorCode::SlippageExceeded);
 
     token::transfer(ctx.accounts.transfer_context(), token_amount)?;
     let sla = &mut ctx.accounts.sla;
@@ -121,9 +134,9 @@ pub fn handler(ctx: Context<StakeProvider>, token_amount: u64) -> Result<()> {
     sla.provider_pool_size = sla
         .provider_pool_size
         .checked_add(token_amount as u128)
-        .unwrap();
+        .ok_or(ErrorCode::CheckedOperationOverflow)?;
 
-    let sla_key = sla.key().clone();
+    let sla_key = sla.key();
     let authority_bump = *ctx
         .bumps
         .get("sla_authority")
@@ -148,7 +161,10 @@ pub fn handler(ctx: Context<StakeProvider>, token_amount: u64) -> Result<()> {
 
     token::mint_to(mint_context, tokens_to_mint)?;
     // @todo add test for this
-    sla.pt_supply = sla.pt_supply.checked_add(tokens_to_mint as u128).unwrap();
+    sla.pt_supply = sla
+        .pt_supply
+        .checked_add(tokens_to_mint as u128)
+        .ok_or(ErrorCode::CheckedOperationOverflow)?;
 
     let lockup = &mut ctx.accounts.pt_lockup;
     let period_id = ctx.accounts.sla.period_data.get_current_period_id()?;
@@ -1,21 +1,38 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Mint, MintTo, Token, TokenAccount, Transfer};
-use rust_decimal::prelude::*;
 
 use crate::constants::*;
+use crate::errors::ErrorCode;
 use crate::events::StakedUserSideEvent;
+use crate::quote;
 use crate::state::sla::Sla;
-use crate::state::{Lockup, SlaAuthority};
+use crate::state::{Governance, Lockup, SlaAuthority};
 
 /// Instruction to stake on both sides
+/// it fails if less than `min_tokens_out` UT would be minted, see `quote`,
+/// exactly `token_amount` tokens are staked so `min_tokens_out` also bounds the price paid for each UT,
+/// and until every finished period is checkpointed, see `checkpoint_period`
 #[derive(Accounts)]
 pub struct StakeUser<'info> {
     // provide or user
     #[account(mut)]
     pub staker: Signer<'info>,
-    #[account(mut)]
+    #[account(
+        mut,
+        constraint = !sla.cancelled @ ErrorCode::SlaCancelled,
+        constraint = !sla.paused @ ErrorCode::SlaPaused,
+        constraint = sla.version == Sla::VERSION @ ErrorCode::AccountNotMigrated,
+    )]
     pub sla: Account<'info, Sla>,
 
+    #[account(
+        seeds = [GOVERNANCE_SEED.as_bytes()],
+        bump,
+        constraint = !governance.paused @ ErrorCode::ProtocolPaused,
+        constraint = governance.version == Governance::VERSION @ ErrorCode::AccountNotMigrated,
+    )]
+    pub governance: Account<'info, Governance>,
+
     #[account(
         mut,
         seeds = [SLA_AUTHORITY_SEED.as_bytes(),sla.key().as_ref()],
@@ -25,7 +42,7 @@ pub struct StakeUser<'info> {
 
     // @fixme make sure mint is same as defined in initialization
     #[account(
-        constraint = mint.is_initialized == true,
+        constraint = mint.is_initialized,
         constraint = mint.key() == sla.mint_address,
 )]
     pub mint: Account<'info, Mint>,
@@ -45,7 +62,7 @@ pub struct StakeUser<'info> {
             UT_MINT_SEED.as_bytes(),
             sla.key().as_ref(),
         ],
-        constraint = ut_mint.is_initialized == true,
+        constraint = ut_mint.is_initialized,
         bump,
     )]
     pub ut_mint: Box<Account<'info, Mint>>,
@@ -58,6 +75,7 @@ pub struct StakeUser<'info> {
             sla.key().as_ref(),
         ],
         bump,
+        constraint = ut_lockup.version == Lockup::VERSION @ ErrorCode::AccountNotMigrated,
     )]
     pub ut_lockup: Box<Account<'info, Lockup>>,
 
@@ -87,32 +105,17 @@ impl<'info> StakeUser<'info> {
     }
 }
 
-pub fn handler(ctx: Context<StakeUser>, token_amount: u64) -> Result<()> {
-    let leverage = &ctx.accounts.sla.leverage.to_decimal();
-    let token_amount_dec = Decimal::from_u64(token_amount).unwrap();
-    let provider_pool_size_dec = Decimal::from_u128(ctx.accounts.sla.provider_pool_size).unwrap();
-    let user_pool_size_dec = Decimal::from_u128(ctx.accounts.sla.user_pool_size).unwrap();
-    let ut_supply_dec = Decimal::from_u128(ctx.accounts.sla.ut_supply).unwrap();
-
-    // @todo add test for this
-    let leverage_adjusted_user_pool = leverage
-        .checked_mul(user_pool_size_dec.checked_add(token_amount_dec).unwrap())
-        .unwrap();
-
-    // @todo add test and error for this
-    require_gte!(provider_pool_size_dec, leverage_adjusted_user_pool);
-
-    let mut tokens_to_mint = token_amount;
-    // @todo add test for this
-    if user_pool_size_dec != ut_supply_dec {
-        // @todo add test for this
-        tokens_to_mint = token_amount_dec
-            .checked_div(user_pool_size_dec.checked_div(ut_supply_dec).unwrap())
-            .unwrap()
-            .floor()
-            .to_u64()
-            .unwrap();
-    }
+pub(crate) fn handler(
+    ctx: Context<StakeUser>,
+    token_amount: u64,
+    min_tokens_out: u64,
+) -> Result<()> {
+    // the stake is priced with the periods that finished before it, see `quote`
+    ctx.accounts
+        .sla
+        .check_checkpointed(ctx.accounts.sla.status()?)?;
+    let tokens_to_mint = quote::stake_user(&ctx.accounts.sla, token_amount)?;
+    require_gte!(tokens_to_mint, min_tokens_out, ErrorCode::SlippageExceeded);
 
     token::transfer(ctx.accounts.transfer_context(), token_amount)?;
     let sla = &mut ctx.accounts.sla;
@@ -121,9 +124,9 @@ pub fn handler(ctx: Context<StakeUser>, token_amount: u64) -> Result<()> {
     sla.user_pool_size = sla
         .user_pool_size
         .checked_add(token_amount as u128)
-        .unwrap();
+        .ok_or(ErrorCode::CheckedOperationOverflow)?;
 
-    let sla_key = sla.key().clone();
+    let sla_key = sla.key();
     let authority_bump = *ctx
         .bumps
         .get("sla_authority")
@@ -147,7 +150,10 @@ pub fn handler(ctx: Context<StakeUser>, token_amount: u64) -> Result<()> {
 
     token::mint_to(mint_context, tokens_to_mint)?;
     // @todo add test for this
-    sla.ut_supply = sla.ut_supply.checked_add(tokens_to_mint as u128).unwrap();
+    sla.ut_supply = sla
+        .ut_supply
+        .checked_add(tokens_to_mint as u128)
+        .ok_or(ErrorCode::CheckedOperationOverflow)?;
 
     let lockup = &mut ctx.accounts.ut_lockup;
     let period_id = ctx.accounts.sla.period_data.get_current_period_id()?;
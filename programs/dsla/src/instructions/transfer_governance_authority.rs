@@ -0,0 +1,33 @@
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::events::TransferredGovernanceAuthorityEvent;
+use crate::state::governance::Governance;
+use anchor_lang::prelude::*;
+
+/// Instruction to start the transfer of the governance authority,
+/// `new_authority` needs to accept it to become the governance authority
+#[derive(Accounts)]
+pub struct TransferGovernanceAuthority<'info> {
+    #[account(address = governance.governance_authority @ ErrorCode::UnauthorizedGovernanceAuthority)]
+    pub governance_authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [GOVERNANCE_SEED.as_bytes()],
+        bump,
+        constraint = governance.version == Governance::VERSION @ ErrorCode::AccountNotMigrated
+    )]
+    pub governance: Account<'info, Governance>,
+}
+
+pub(crate) fn handler(
+    ctx: Context<TransferGovernanceAuthority>,
+    new_authority: Pubkey,
+) -> Result<()> {
+    ctx.accounts.governance.pending_governance_authority = Some(new_authority);
+
+    emit!(TransferredGovernanceAuthorityEvent {
+        authority: ctx.accounts.governance_authority.key(),
+        new_authority,
+    });
+    Ok(())
+}
@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::{ErrorCode, FeedErrorCode};
+use crate::instructions::validate_period::ValidatePeriod;
+use crate::state::{OracleSource, SlaStatus};
+
+/// Instruction to validate all the finished periods that have not been validated yet, anyone can validate
+/// it takes the same accounts as `validate_period`, with the checkpoint accounts of all the pending periods
+/// it can validate, up to `max_periods`, before the oracle feeds
+pub(crate) fn handler(ctx: Context<ValidatePeriod>, max_periods: u32) -> Result<()> {
+    let authority_bump = *ctx
+        .bumps
+        .get("sla_authority")
+        .expect("sla_authority should exists");
+    require!(
+        ctx.accounts.sla.oracle_source != OracleSource::Optimistic,
+        FeedErrorCode::OptimisticValidation
+    );
+
+    let finished_periods = match ctx.accounts.sla.period_data.get_current_period_id()? {
+        SlaStatus::NotStarted => return err!(ErrorCode::SlaNotStarted),
+        SlaStatus::Active { period_id } => period_id as usize,
+        SlaStatus::Ended => ctx.accounts.status_registry.status_registry.len(),
+    };
+    let pending_periods = ctx
+        .accounts
+        .status_registry
+        .pending_periods(finished_periods, max_periods as usize);
+    require!(!pending_periods.is_empty(), ErrorCode::NoPendingPeriods);
+    require_gte!(
+        ctx.remaining_accounts.len(),
+        pending_periods.len(),
+        anchor_lang::error::ErrorCode::AccountNotEnoughKeys
+    );
+    let (checkpoints, oracles) = ctx.remaining_accounts.split_at(pending_periods.len());
+
+    let mut validated_periods = 0;
+    for (period, checkpoint) in pending_periods.into_iter().zip(checkpoints) {
+        // stop at the first period without enough oracle data, the next ones can be validated later
+        let (sli, contributors) = match ctx.accounts.read_sli(period, oracles)? {
+            Some(sli) => sli,
+            None => {
+                msg!("quorum not reached for period {}", period);
+                break;
+            }
+        };
+        ctx.accounts
+            .settle_period(period, sli, contributors, checkpoint, authority_bump)?;
+        validated_periods += 1;
+    }
+    require_gt!(validated_periods, 0, FeedErrorCode::QuorumNotReached);
+
+    Ok(())
+}
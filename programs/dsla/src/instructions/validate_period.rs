@@ -1,18 +1,22 @@
-use std::ops::Mul;
-
 use crate::constants::*;
 use crate::errors::{ErrorCode, FeedErrorCode};
-use crate::program::Dsla;
+use crate::events::ValidatedPeriodEvent;
 use crate::state::sla::{DslaDecimal, Sla};
 use crate::state::status_registry::{Status, StatusRegistry};
-use crate::state::{Governance, SlaAuthority, SlaStatus};
+use crate::state::{
+    Attestation, Governance, OracleSource, PeriodCheckpoint, PythSample, SampleWindow,
+    SlaAuthority, SlaStatus,
+};
 use anchor_lang::prelude::*;
-use anchor_lang::solana_program::clock;
 use anchor_spl::token::{self, Burn, Mint, Token, TokenAccount, Transfer};
-use rust_decimal::prelude::*;
-use switchboard_v2::{AggregatorAccountData, SwitchboardDecimal, SWITCHBOARD_PROGRAM_ID};
+use rust_decimal::Decimal;
 
 /// Instruction to validate a period x, anyone can validate
+/// the checkpoint of the period is passed first as remaining account, see `checkpoint_period`, then
+/// every oracle feed of the SLA is passed in its order as remaining accounts to read the SLI from,
+/// each feed with a history buffer is followed by it to validate periods that ended before its latest update,
+/// SLAs using attestations take the instructions sysvar instead, see `Attestation`,
+/// SLAs using pyth feeds take the pyth sample of the period, see `record_pyth_sample`
 #[derive(Accounts)]
 pub struct ValidatePeriod<'info> {
     #[account(mut)]
@@ -26,26 +30,26 @@ pub struct ValidatePeriod<'info> {
     #[account(
         mut,
         seeds = [STATUS_REGISTRY_SEED.as_bytes(), sla.key().as_ref()],
-        bump
+        bump,
+        constraint = status_registry.is_current(sla.period_data.n_periods) @ ErrorCode::AccountNotMigrated
     )]
     pub status_registry: Account<'info, StatusRegistry>,
 
-    #[account(mut)]
-    pub sla: Account<'info, Sla>,
-
     #[account(
-        constraint = *aggregator.to_account_info().owner == SWITCHBOARD_PROGRAM_ID @ FeedErrorCode::InvalidSwitchboardAccount,
-        constraint = aggregator.key() == sla.aggregator_address
+        mut,
+        constraint = !sla.paused @ ErrorCode::SlaPaused,
+        constraint = sla.version == Sla::VERSION @ ErrorCode::AccountNotMigrated
     )]
-    pub aggregator: AccountLoader<'info, AggregatorAccountData>,
+    pub sla: Account<'info, Sla>,
 
     #[account(
         seeds = [GOVERNANCE_SEED.as_bytes()],
-        bump
+        bump,
+        constraint = !governance.paused @ ErrorCode::ProtocolPaused,
+        constraint = governance.version == Governance::VERSION @ ErrorCode::AccountNotMigrated,
     )]
     pub governance: Account<'info, Governance>,
-    // @todo add constraint to check for correct DSLA mint address
-    #[account(mut, constraint = dsla_mint.is_initialized == true)]
+    #[account(mut, address = governance.dsla_mint @ ErrorCode::InvalidDslaMint)]
     pub dsla_mint: Box<Account<'info, Mint>>,
     #[account(
             mut,
@@ -60,15 +64,10 @@ pub struct ValidatePeriod<'info> {
     #[account(mut, associated_token::mint=dsla_mint, associated_token::authority=validator)]
     pub validator_dsla_token_account: Box<Account<'info, TokenAccount>>,
 
-    #[account(address = crate::ID)]
-    pub program: Program<'info, Dsla>,
-    // @fixme this need to be checked, that only allowed program_data is the one linked to the program
-    pub program_data: Account<'info, ProgramData>,
-
     #[account(
         mut,
         associated_token::mint = dsla_mint,
-        associated_token::authority = program_data.upgrade_authority_address.unwrap()
+        associated_token::authority = governance.protocol_treasury
     )]
     pub protocol_dsla_token_account: Box<Account<'info, TokenAccount>>,
     /// The program for interacting with the token.
@@ -78,152 +77,290 @@ pub struct ValidatePeriod<'info> {
     pub system_program: Program<'info, System>,
 }
 
-pub fn handler(ctx: Context<ValidatePeriod>, period: usize) -> Result<()> {
-    let status_registry = &mut ctx.accounts.status_registry.status_registry;
-    let slo = ctx.accounts.sla.slo.clone();
-    let sla_status = ctx.accounts.sla.period_data.get_current_period_id()?;
-
-    require_gt!(status_registry.len(), period);
-    match status_registry[period] {
-        // check that period can be validated
-        Status::NotVerified => {
-            match sla_status {
-                SlaStatus::NotStarted => return err!(ErrorCode::SlaNotStarted),
-                SlaStatus::Ended => {}
-                SlaStatus::Active { period_id } => {
-                    require_gt!(period_id, period as u32);
-                }
+impl<'info> ValidatePeriod<'info> {
+    /// checks that `period` has finished and has not been verified yet
+    pub fn check_period(&self, period: usize) -> Result<()> {
+        require!(
+            self.sla.oracle_source != OracleSource::Optimistic,
+            FeedErrorCode::OptimisticValidation
+        );
+        let status_registry = &self.status_registry.status_registry;
+        require_gt!(status_registry.len(), period);
+        require!(
+            status_registry[period] == Status::NotVerified,
+            ErrorCode::AlreadyVerifiedPeriod
+        );
+        match self.sla.period_data.get_current_period_id()? {
+            SlaStatus::NotStarted => err!(ErrorCode::SlaNotStarted),
+            SlaStatus::Ended => Ok(()),
+            SlaStatus::Active { period_id } => {
+                require_gt!(period_id, period as u32);
+                Ok(())
             }
-            let max_confidence_interval = Some(100.0); // @todo change this to a protocol governance const or sla level const
-            let max_staleness = 300; // @todo change this to a protocol governance variable or sla level variable
+        }
+    }
 
-            // 1. GET THE DATA
+    /// Returns the SLI of `period` aggregated from the `oracles` feeds with the bitmask of the
+    /// feeds that contributed, or `None` if not enough feeds have a trusted result for the period
+    ///
+    /// # Arguments
+    ///
+    /// * `period` - the period to get the SLI of
+    /// * `oracles` - every oracle feed of the SLA in its order, each feed with a history buffer followed by it
+    pub fn read_sli(
+        &self,
+        period: usize,
+        oracles: &[AccountInfo],
+    ) -> Result<Option<(DslaDecimal, u8)>> {
+        let sla = &self.sla;
+        match sla.oracle_source {
+            OracleSource::Attestation => return self.read_attested_sli(period, oracles),
+            OracleSource::Pyth => return self.read_pyth_sample(period, oracles),
+            OracleSource::Switchboard | OracleSource::Optimistic => {}
+        }
+        let max_confidence_interval = sla.max_confidence_interval.to_decimal();
 
-            let feed = &ctx.accounts.aggregator.load()?;
+        // the SLI needs to be observed during the period, not at the time of the validation
+        let window = SampleWindow::new(
+            sla.period_data.get_start(period)?,
+            sla.period_data.get_end(period)?,
+            sla.max_staleness,
+        )?;
+        let results = sla.oracle_source.read_all(
+            &sla.oracle_addresses,
+            oracles,
+            &window,
+            max_confidence_interval,
+        )?;
+        self.aggregate(results)
+    }
 
-            // get result
-            let data: f64 = feed.get_result()?.try_into()?;
-            let sli_decimal = Decimal::from_f64(data).ok_or(ErrorCode::DecimalConversionError)?;
-            let sli_dsla_decimal = DslaDecimal::from_decimal(sli_decimal);
+    /// Returns the SLI of `period` aggregated from the attestations signed by the attesters of the SLA
+    /// with the bitmask of the attesters that contributed, or `None` if not enough attesters signed
+    /// or if some attesters are missing before the end of the attestation window of the governance
+    ///
+    /// # Arguments
+    ///
+    /// * `period` - the period to get the SLI of
+    /// * `accounts` - the instructions sysvar, the attestations are ed25519 instructions of the transaction
+    pub fn read_attested_sli(
+        &self,
+        period: usize,
+        accounts: &[AccountInfo],
+    ) -> Result<Option<(DslaDecimal, u8)>> {
+        let sla = &self.sla;
+        let instructions_sysvar = match accounts {
+            [instructions_sysvar] => instructions_sysvar,
+            _ => return err!(FeedErrorCode::InvalidInstructionsSysvar),
+        };
+        // attestations need to be signed after the end of the period
+        let period_end = sla.period_data.get_end(period)?;
+        let now = Clock::get()?.unix_timestamp;
 
-            // check whether the feed has been updated in the last max_staleness seconds
-            feed.check_staleness(clock::Clock::get().unwrap().unix_timestamp, max_staleness)
-                .map_err(|_| error!(FeedErrorCode::StaleFeed))?;
+        let results = Attestation::results(
+            &Attestation::load_verified(instructions_sysvar)?,
+            sla.key(),
+            period as u64,
+            &sla.oracle_addresses,
+            period_end,
+            now,
+        )?;
 
-            // check feed does not exceed max_confidence_interval
-            if let Some(max_confidence_interval) = max_confidence_interval {
-                feed.check_confidence_interval(SwitchboardDecimal::from_f64(
-                    max_confidence_interval,
-                ))
-                .map_err(|_| error!(FeedErrorCode::ConfidenceIntervalExceeded))?;
-            }
-
-            // 2. COMPARE SLO TO SLI
-            let respected = slo.is_respected(sli_dsla_decimal)?;
-
-            // 3. UPDATE STATUS
-            let sla = &mut ctx.accounts.sla;
-            let periods_left = status_registry.len().checked_sub(period).unwrap();
-
-            let leverage_adjusted_pool = Decimal::from_u128(sla.user_pool_size)
-                .unwrap()
-                .mul(sla.leverage.to_decimal());
-
-            let reward = leverage_adjusted_pool
-                .checked_div(Decimal::from_usize(periods_left).unwrap())
-                .unwrap()
-                .checked_mul(sla.get_deviation(&sli_dsla_decimal.to_decimal())?)
-                .unwrap()
-                .floor()
-                .to_u64()
-                .unwrap();
-
-            require_gte!(
-                sla.provider_pool_size,
-                leverage_adjusted_pool.to_u128().unwrap()
-            );
-
-            if respected {
-                sla.user_pool_size = sla.user_pool_size.checked_sub(reward as u128).unwrap();
-                sla.provider_pool_size =
-                    sla.provider_pool_size.checked_add(reward as u128).unwrap();
-                status_registry[period] = Status::Respected {
-                    value: sli_dsla_decimal,
-                };
-            } else {
-                sla.provider_pool_size =
-                    sla.provider_pool_size.checked_sub(reward as u128).unwrap();
-                sla.user_pool_size = sla.user_pool_size.checked_add(reward as u128).unwrap();
-                status_registry[period] = Status::NotRespected {
-                    value: sli_dsla_decimal,
-                };
-            }
+        // every attester needs to attest the SLI until the attestation window has passed since the end of the period,
+        // so that the validator can't leave out the attestations it dislikes, the attesters still missing
+        // after it count against the quorum
+        let attestation_deadline = self.governance.attestation_deadline(period_end)?;
+        if (now as u128) <= attestation_deadline && results.iter().any(Option::is_none) {
+            msg!("missing attestations until {}", attestation_deadline);
+            return Ok(None);
+        }
+        self.aggregate(results)
+    }
 
-            // 4. REWARD VALIDATOR
-            let sla_key = sla.key().clone();
-            let authority_bump = *ctx
-                .bumps
-                .get("sla_authority")
-                .expect("sla_authority should exists");
-            let seeds = &[
-                SLA_AUTHORITY_SEED.as_bytes(),
+    /// Returns the SLI of `period` aggregated from the prices of its pyth sample with the bitmask
+    /// of the feeds that contributed, or `None` if no sample was recorded for the period
+    ///
+    /// # Arguments
+    ///
+    /// * `period` - the period to get the SLI of
+    /// * `accounts` - the pyth samples of the periods to validate, see `record_pyth_sample`
+    pub fn read_pyth_sample(
+        &self,
+        period: usize,
+        accounts: &[AccountInfo],
+    ) -> Result<Option<(DslaDecimal, u8)>> {
+        let sla_key = self.sla.key();
+        let (address, _) = Pubkey::find_program_address(
+            &[
+                PYTH_SAMPLE_SEED.as_bytes(),
                 sla_key.as_ref(),
-                &[authority_bump],
-            ];
-
-            let signer_seeds = &[&seeds[..]];
-
-            let burn_context = CpiContext::new_with_signer(
-                ctx.accounts.token_program.to_account_info(),
-                Burn {
-                    mint: ctx.accounts.dsla_mint.to_account_info(),
-                    from: ctx.accounts.dsla_pool.to_account_info(),
-                    authority: ctx.accounts.sla_authority.to_account_info(),
-                },
-                signer_seeds,
-            );
-
-            let protocol_transfer_context = CpiContext::new_with_signer(
-                ctx.accounts.token_program.to_account_info(),
-                Transfer {
-                    from: ctx.accounts.dsla_pool.to_account_info(),
-                    to: ctx.accounts.protocol_dsla_token_account.to_account_info(),
-                    authority: ctx.accounts.sla_authority.to_account_info(),
-                },
-                signer_seeds,
-            );
-
-            let validator_transfer_context = CpiContext::new_with_signer(
-                ctx.accounts.token_program.to_account_info(),
-                Transfer {
-                    from: ctx.accounts.dsla_pool.to_account_info(),
-                    to: ctx.accounts.validator_dsla_token_account.to_account_info(),
-                    authority: ctx.accounts.sla_authority.to_account_info(),
-                },
-                signer_seeds,
-            );
-
-            if ctx.accounts.governance.dsla_protocol_reward > 0 {
-                token::transfer(
-                    protocol_transfer_context,
-                    ctx.accounts.governance.dsla_protocol_reward,
-                )?;
-            }
-            if ctx.accounts.governance.dsla_validator_reward > 0 {
-                token::transfer(
-                    validator_transfer_context,
-                    ctx.accounts.governance.dsla_validator_reward,
-                )?;
-            }
-            if ctx.accounts.governance.dsla_burned_by_verification > 0 {
-                token::burn(
-                    burn_context,
-                    ctx.accounts.governance.dsla_burned_by_verification,
-                )?;
+                &(period as u64).to_le_bytes(),
+            ],
+            &crate::ID,
+        );
+        let sample = match accounts.iter().find(|account| *account.key == address) {
+            Some(sample) if !sample.data_is_empty() => sample,
+            _ => {
+                msg!("no pyth sample recorded for period {}", period);
+                return Ok(None);
             }
+        };
+        let sample = Account::<PythSample>::try_from(sample)?;
+        self.aggregate(sample.results())
+    }
 
-            Ok(())
+    /// Returns the aggregated SLI of the `results` of the oracle feeds of the SLA with the bitmask
+    /// of the feeds that have a result, if they reach the quorum of the SLA
+    fn aggregate(&self, results: Vec<Option<Decimal>>) -> Result<Option<(DslaDecimal, u8)>> {
+        let mut contributors: u8 = 0;
+        let mut results: Vec<Decimal> = results
+            .into_iter()
+            .enumerate()
+            .filter_map(|(index, result)| {
+                if result.is_some() {
+                    contributors |= 1 << index;
+                }
+                result
+            })
+            .collect();
+        if results.len() < self.sla.min_quorum as usize {
+            return Ok(None);
         }
-        _ => err!(ErrorCode::AlreadyVerifiedPeriod),
+
+        let sli = self.sla.aggregation_policy.aggregate(&mut results)?;
+        Ok(Some((DslaDecimal::from_decimal(sli), contributors)))
     }
+
+    /// records the status of `period` for the `sli`, moves the reward between the pools
+    /// and pays the validator, the protocol and the burn from the DSLA deposit
+    ///
+    /// # Arguments
+    ///
+    /// * `period` - the period to settle, it needs to pass `check_period`
+    /// * `sli` - the SLI of the period
+    /// * `contributors` - the bitmask of the oracle feeds used for the SLI
+    /// * `checkpoint` - the checkpoint account of `period`
+    /// * `authority_bump` - the bump of the `sla_authority`
+    pub fn settle_period(
+        &mut self,
+        period: usize,
+        sli: DslaDecimal,
+        contributors: u8,
+        checkpoint: &AccountInfo,
+        authority_bump: u8,
+    ) -> Result<()> {
+        let checkpoint = PeriodCheckpoint::load(checkpoint, &self.sla.key(), period as u64)?;
+
+        // UPDATE STATUS
+        let status_registry = &mut self.status_registry.status_registry;
+        let periods_left = status_registry.len().checked_sub(period).unwrap();
+        let respected = self
+            .sla
+            .settle_period(sli, periods_left, checkpoint.user_pool_size)?;
+        let status = Status::settled(respected, sli, contributors);
+        status_registry[period] = status.clone();
+        self.sla
+            .release_pending_transfer(checkpoint.max_transfer, status_registry);
+
+        // REWARD VALIDATOR
+        let sla_key = self.sla.key();
+        let seeds = &[
+            SLA_AUTHORITY_SEED.as_bytes(),
+            sla_key.as_ref(),
+            &[authority_bump],
+        ];
+        pay_verification_rewards(
+            &self.governance,
+            self.token_program.to_account_info(),
+            self.dsla_mint.to_account_info(),
+            self.dsla_pool.to_account_info(),
+            self.sla_authority.to_account_info(),
+            self.protocol_dsla_token_account.to_account_info(),
+            self.validator_dsla_token_account.to_account_info(),
+            &[&seeds[..]],
+        )?;
+
+        emit!(ValidatedPeriodEvent {
+            sla_account_address: sla_key,
+            period: period as u64,
+            status,
+        });
+
+        Ok(())
+    }
+}
+
+/// pays the validator, the protocol and the burn of a settled period from the DSLA deposit of the SLA
+///
+/// # Arguments
+///
+/// * `governance` - the governance with the amounts to pay
+/// * `signer_seeds` - the seeds of the `sla_authority` of the SLA
+#[allow(clippy::too_many_arguments)]
+pub fn pay_verification_rewards<'info>(
+    governance: &Governance,
+    token_program: AccountInfo<'info>,
+    dsla_mint: AccountInfo<'info>,
+    dsla_pool: AccountInfo<'info>,
+    sla_authority: AccountInfo<'info>,
+    protocol_dsla_token_account: AccountInfo<'info>,
+    validator_dsla_token_account: AccountInfo<'info>,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    if governance.dsla_protocol_reward > 0 {
+        let protocol_transfer_context = CpiContext::new_with_signer(
+            token_program.clone(),
+            Transfer {
+                from: dsla_pool.clone(),
+                to: protocol_dsla_token_account,
+                authority: sla_authority.clone(),
+            },
+            signer_seeds,
+        );
+        token::transfer(protocol_transfer_context, governance.dsla_protocol_reward)?;
+    }
+    if governance.dsla_validator_reward > 0 {
+        let validator_transfer_context = CpiContext::new_with_signer(
+            token_program.clone(),
+            Transfer {
+                from: dsla_pool.clone(),
+                to: validator_dsla_token_account,
+                authority: sla_authority.clone(),
+            },
+            signer_seeds,
+        );
+        token::transfer(validator_transfer_context, governance.dsla_validator_reward)?;
+    }
+    if governance.dsla_burned_by_verification > 0 {
+        let burn_context = CpiContext::new_with_signer(
+            token_program,
+            Burn {
+                mint: dsla_mint,
+                from: dsla_pool,
+                authority: sla_authority,
+            },
+            signer_seeds,
+        );
+        token::burn(burn_context, governance.dsla_burned_by_verification)?;
+    }
+    Ok(())
+}
+
+pub(crate) fn handler(ctx: Context<ValidatePeriod>, period: usize) -> Result<()> {
+    let authority_bump = *ctx
+        .bumps
+        .get("sla_authority")
+        .expect("sla_authority should exists");
+
+    ctx.accounts.check_period(period)?;
+    let (checkpoint, oracles) = ctx
+        .remaining_accounts
+        .split_first()
+        .ok_or(anchor_lang::error::ErrorCode::AccountNotEnoughKeys)?;
+    let (sli, contributors) = ctx
+        .accounts
+        .read_sli(period, oracles)?
+        .ok_or(FeedErrorCode::QuorumNotReached)?;
+    ctx.accounts
+        .settle_period(period, sli, contributors, checkpoint, authority_bump)
 }
@@ -1,14 +1,16 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Burn, Mint, Token, TokenAccount, Transfer};
-use rust_decimal::prelude::*;
 
 use crate::constants::*;
-use crate::program::Dsla;
+use crate::errors::ErrorCode;
+use crate::quote::{self, WithdrawQuote};
 use crate::state::sla::Sla;
-use crate::state::{Governance, Lockup, SlaAuthority, SlaStatus};
+use crate::state::{Governance, Lockup, SlaAuthority};
 
 /// Instruction to claim all rewards up to the latest available
 /// eg. if current period is 5 and I have never claimed before, I will receive all rewards up to 4th period according to the status, leverage and deviation
+/// it fails if less than `min_tokens_out` tokens would be paid to the provider, see `quote`
+/// and until every finished period is checkpointed, see `checkpoint_period`
 #[derive(Accounts)]
 pub struct WithdrawProvider<'info> {
     /// provider
@@ -16,7 +18,10 @@ pub struct WithdrawProvider<'info> {
     pub withdrawer: Signer<'info>,
 
     /// the SLA
-    #[account(mut)]
+    #[account(
+        mut,
+        constraint = sla.version == Sla::VERSION @ ErrorCode::AccountNotMigrated
+    )]
     pub sla: Account<'info, Sla>,
 
     #[account(
@@ -42,12 +47,13 @@ pub struct WithdrawProvider<'info> {
             sla.key().as_ref(),
         ],
         bump,
+        constraint = pt_lockup.version == Lockup::VERSION @ ErrorCode::AccountNotMigrated,
     )]
     pub pt_lockup: Box<Account<'info, Lockup>>,
 
     #[account(
         mut,
-        constraint = mint.is_initialized == true,
+        constraint = mint.is_initialized,
         constraint = mint.key() == sla.mint_address,
     )]
     pub mint: Account<'info, Mint>,
@@ -67,28 +73,24 @@ pub struct WithdrawProvider<'info> {
             PT_MINT_SEED.as_bytes(),
             sla.key().as_ref(),
         ],
-        constraint = pt_mint.is_initialized == true,
+        constraint = pt_mint.is_initialized,
         bump,
     )]
     pub pt_mint: Box<Account<'info, Mint>>,
 
     #[account(
         seeds = [GOVERNANCE_SEED.as_bytes()],
-        bump
+        bump,
+        constraint = governance.version == Governance::VERSION @ ErrorCode::AccountNotMigrated
     )]
     pub governance: Account<'info, Governance>,
     pub token_program: Program<'info, Token>,
 
-    #[account(address = crate::ID)]
-    pub program: Program<'info, Dsla>,
-    // @fixme this need to be checked, that only allowed program_data is the one linked to the program
-    pub program_data: Account<'info, ProgramData>,
-
     // @todo test if effectvily only the `associated_token::authority` can be passed here
     #[account(
         mut,
         associated_token::mint = mint,
-        associated_token::authority = program_data.upgrade_authority_address.unwrap()
+        associated_token::authority = governance.protocol_treasury
     )]
     pub protocol_token_account: Box<Account<'info, TokenAccount>>,
     #[account(
@@ -114,80 +116,32 @@ impl<'info> WithdrawProvider<'info> {
     }
 }
 
-pub fn handler(ctx: Context<WithdrawProvider>, pt_burn_amount: u64) -> Result<()> {
-    let leverage = &ctx.accounts.sla.leverage.to_decimal();
-    let pt_burn_amount_dec = Decimal::from_u64(pt_burn_amount).unwrap();
-    let provider_pool_size_dec = Decimal::from_u128(ctx.accounts.sla.provider_pool_size).unwrap();
-    let pt_supply_dec = Decimal::from_u128(ctx.accounts.sla.pt_supply).unwrap();
-    let sla_status = ctx.accounts.sla.period_data.get_current_period_id()?;
+pub(crate) fn handler(
+    ctx: Context<WithdrawProvider>,
+    pt_burn_amount: u64,
+    min_tokens_out: u64,
+) -> Result<()> {
+    let sla_status = ctx.accounts.sla.status()?;
+
+    // the withdrawal is priced with the periods that finished before it, see `quote`
+    ctx.accounts.sla.check_checkpointed(sla_status)?;
 
     // REFRESH AVAILABLE TOKENS IN THE LOCKUPS
     ctx.accounts.pt_lockup.update_available_tokens(sla_status)?;
 
     // CALCULATIONS
-    // @todo add test
-    let tokens_to_withdraw = pt_burn_amount_dec
-        .checked_div(provider_pool_size_dec.checked_div(pt_supply_dec).unwrap())
-        .unwrap()
-        .floor();
-    let tokens_to_withdraw_u128 = tokens_to_withdraw.to_u128().unwrap();
-
-    // @todo add error here
-    match sla_status {
-        SlaStatus::Ended => {
-            // check tokens are avaialable
-            require_gte!(ctx.accounts.sla.provider_pool_size, tokens_to_withdraw_u128);
-        }
-        _ => {
-            // CHECK IF ENOUGH PROVIDER LIQUIDITY IS AVAILABLE FOR WITHDRAWAL
-            let leverage_adjusted_user_pool = leverage
-                .checked_mul(
-                    Decimal::from_u128(
-                        ctx.accounts
-                            .sla
-                            .user_pool_size
-                            .checked_sub(tokens_to_withdraw_u128)
-                            .unwrap(),
-                    )
-                    .unwrap(),
-                )
-                .unwrap()
-                .floor();
-
-            // @todo add error here
-            require_gte!(provider_pool_size_dec, leverage_adjusted_user_pool);
-        }
-    }
-
-    // @todo add test
-    let deployer_amount = tokens_to_withdraw
-        .checked_mul(
-            ctx.accounts
-                .governance
-                .sla_deployer_rewards_rate
-                .to_decimal(),
-        )
-        .unwrap()
-        .floor()
-        .to_u64()
-        .unwrap();
-    // @todo add test
-    let protocol_amount = tokens_to_withdraw
-        .checked_mul(ctx.accounts.governance.protocol_rewards_rate.to_decimal())
-        .unwrap()
-        .floor()
-        .to_u64()
-        .unwrap();
-    // @todo add test
-
-    let provider_amount = tokens_to_withdraw
-        .floor()
-        .to_u64()
-        .unwrap()
-        .checked_sub(protocol_amount)
-        .unwrap()
-        .checked_sub(deployer_amount)
-        .unwrap();
+    let WithdrawQuote {
+        tokens_to_withdraw,
+        withdrawer_amount: provider_amount,
+        deployer_amount,
+        protocol_amount,
+    } = quote::withdraw_provider(
+        &ctx.accounts.sla,
+        &ctx.accounts.governance,
+        sla_status,
+        pt_burn_amount,
+    )?;
+    require_gte!(provider_amount, min_tokens_out, ErrorCode::SlippageExceeded);
 
     // @todo add test
     token::burn(ctx.accounts.pt_burn_context(), pt_burn_amount)?;
@@ -198,10 +152,10 @@ pub fn handler(ctx: Context<WithdrawProvider>, pt_burn_amount: u64) -> Result<()
         .sla
         .pt_supply
         .checked_sub(pt_burn_amount as u128)
-        .unwrap();
+        .ok_or(ErrorCode::CheckedOperationOverflow)?;
     ctx.accounts.pt_lockup.withdraw(pt_burn_amount)?;
 
-    let sla_key = ctx.accounts.sla.key().clone();
+    let sla_key = ctx.accounts.sla.key();
     let authority_bump = *ctx
         .bumps
         .get("sla_authority")
@@ -250,7 +204,7 @@ pub fn handler(ctx: Context<WithdrawProvider>, pt_burn_amount: u64) -> Result<()
         .accounts
         .sla
         .provider_pool_size
-        .checked_sub(provider_amount as u128)
-        .unwrap();
+        .checked_sub(tokens_to_withdraw as u128)
+        .ok_or(ErrorCode::CheckedOperationOverflow)?;
     Ok(())
 }
@@ -1,14 +1,16 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Burn, Mint, Token, TokenAccount, Transfer};
-use rust_decimal::prelude::*;
 
 use crate::constants::*;
-use crate::program::Dsla;
+use crate::errors::ErrorCode;
+use crate::quote::{self, WithdrawQuote};
 use crate::state::sla::Sla;
 use crate::state::{Governance, Lockup, SlaAuthority};
 
 /// Instruction to claim all rewards up to the latest available
 /// eg. if current period is 5 and I have never claimed before, I will receive all rewards up to 4th period according to the status, leverage and deviation
+/// it fails if less than `min_tokens_out` tokens would be paid to the user, see `quote`
+/// and until every finished period is checkpointed, see `checkpoint_period`
 #[derive(Accounts)]
 pub struct WithdrawUser<'info> {
     /// user
@@ -16,7 +18,10 @@ pub struct WithdrawUser<'info> {
     pub withdrawer: Signer<'info>,
 
     /// the SLA
-    #[account(mut)]
+    #[account(
+        mut,
+        constraint = sla.version == Sla::VERSION @ ErrorCode::AccountNotMigrated
+    )]
     pub sla: Account<'info, Sla>,
 
     #[account(
@@ -37,7 +42,7 @@ pub struct WithdrawUser<'info> {
     // @fixme make sure mint is same as defined in initialization
     #[account(
         mut,
-        constraint = mint.is_initialized == true,
+        constraint = mint.is_initialized,
         constraint = mint.key() == sla.mint_address,
     )]
     pub mint: Account<'info, Mint>,
@@ -57,7 +62,7 @@ pub struct WithdrawUser<'info> {
             UT_MINT_SEED.as_bytes(),
             sla.key().as_ref(),
         ],
-        constraint = ut_mint.is_initialized == true,
+        constraint = ut_mint.is_initialized,
         bump,
     )]
     pub ut_mint: Box<Account<'info, Mint>>,
@@ -69,6 +74,7 @@ pub struct WithdrawUser<'info> {
             sla.key().as_ref(),
         ],
         bump,
+        constraint = ut_lockup.version == Lockup::VERSION @ ErrorCode::AccountNotMigrated,
     )]
     pub ut_lockup: Box<Account<'info, Lockup>>,
     #[account(
@@ -82,19 +88,16 @@ pub struct WithdrawUser<'info> {
     #[account(
             mut,
             associated_token::mint = mint,
-            associated_token::authority = program_data.upgrade_authority_address.unwrap()
+            associated_token::authority = governance.protocol_treasury
         )]
     pub protocol_token_account: Box<Account<'info, TokenAccount>>,
     #[account(
         seeds = [GOVERNANCE_SEED.as_bytes()],
-        bump
+        bump,
+        constraint = governance.version == Governance::VERSION @ ErrorCode::AccountNotMigrated
     )]
     pub governance: Account<'info, Governance>,
     pub token_program: Program<'info, Token>,
-    #[account(address = crate::ID)]
-    pub program: Program<'info, Dsla>,
-    // @fixme this need to be checked, that only allowed program_data is the one linked to the program
-    pub program_data: Account<'info, ProgramData>,
     pub rent: Sysvar<'info, Rent>,
     pub system_program: Program<'info, System>,
 }
@@ -112,50 +115,25 @@ impl<'info> WithdrawUser<'info> {
     }
 }
 
-pub fn handler(ctx: Context<WithdrawUser>, burn_amount: u64) -> Result<()> {
-    let burn_amount_dec = Decimal::from_u64(burn_amount).unwrap();
-    let user_pool_size_dec = Decimal::from_u128(ctx.accounts.sla.user_pool_size).unwrap();
-    let ut_supply_dec = Decimal::from_u128(ctx.accounts.sla.ut_supply).unwrap();
-    let period_id = ctx.accounts.sla.period_data.get_current_period_id()?;
-
-    ctx.accounts.ut_lockup.update_available_tokens(period_id)?;
+pub(crate) fn handler(
+    ctx: Context<WithdrawUser>,
+    burn_amount: u64,
+    min_tokens_out: u64,
+) -> Result<()> {
+    let period_id = ctx.accounts.sla.status()?;
 
-    // @todo add test
-    let tokens_to_withdraw = burn_amount_dec
-        .checked_div(user_pool_size_dec.checked_div(ut_supply_dec).unwrap())
-        .unwrap()
-        .floor();
-    let tokens_to_withdraw_u128 = tokens_to_withdraw.to_u128().unwrap();
+    // the withdrawal is priced with the periods that finished before it, see `quote`
+    ctx.accounts.sla.check_checkpointed(period_id)?;
 
-    // @todo add error
-    require_gte!(ctx.accounts.sla.user_pool_size, tokens_to_withdraw_u128);
+    ctx.accounts.ut_lockup.update_available_tokens(period_id)?;
 
-    // @todo add test
-    let deployer_amount = tokens_to_withdraw
-        .checked_mul(
-            ctx.accounts
-                .governance
-                .sla_deployer_rewards_rate
-                .to_decimal(),
-        )
-        .unwrap()
-        .floor()
-        .to_u64()
-        .unwrap();
-    // @todo add test
-    let protocol_amount = tokens_to_withdraw
-        .checked_mul(ctx.accounts.governance.protocol_rewards_rate.to_decimal())
-        .unwrap()
-        .floor()
-        .to_u64()
-        .unwrap();
-    let user_amount = tokens_to_withdraw
-        .to_u64()
-        .unwrap()
-        .checked_sub(protocol_amount)
-        .unwrap()
-        .checked_sub(deployer_amount)
-        .unwrap();
+    let WithdrawQuote {
+        tokens_to_withdraw,
+        withdrawer_amount: user_amount,
+        deployer_amount,
+        protocol_amount,
+    } = quote::withdraw_user(&ctx.accounts.sla, &ctx.accounts.governance, burn_amount)?;
+    require_gte!(user_amount, min_tokens_out, ErrorCode::SlippageExceeded);
 
     // @todo add test
     // BURN TOKENS
@@ -165,10 +143,10 @@ pub fn handler(ctx: Context<WithdrawUser>, burn_amount: u64) -> Result<()> {
         .sla
         .ut_supply
         .checked_sub(burn_amount as u128)
-        .unwrap();
+        .ok_or(ErrorCode::CheckedOperationOverflow)?;
 
     ctx.accounts.ut_lockup.withdraw(burn_amount)?;
-    let sla_key = ctx.accounts.sla.key().clone();
+    let sla_key = ctx.accounts.sla.key();
     let authority_bump = *ctx
         .bumps
         .get("sla_authority")
@@ -218,8 +196,8 @@ pub fn handler(ctx: Context<WithdrawUser>, burn_amount: u64) -> Result<()> {
         .accounts
         .sla
         .user_pool_size
-        .checked_sub(tokens_to_withdraw_u128)
-        .unwrap();
+        .checked_sub(tokens_to_withdraw as u128)
+        .ok_or(ErrorCode::CheckedOperationOverflow)?;
 
     Ok(())
 }
@@ -9,12 +9,19 @@ pub mod errors;
 pub mod events;
 /// available instructions
 pub mod instructions;
+/// share prices of the UT and PT of the pools, used by the stake and withdraw instructions
+pub mod pricing;
+/// previews of the amounts of the stake and withdraw instructions, for clients to set their slippage limits
+pub mod quote;
 /// Accounts and structs used in the instructions
 pub mod state;
 
 use instructions::*;
 
+use crate::state::governance::GovernanceParameters;
+use crate::state::oracle::{AggregationPolicy, OracleSource};
 use crate::state::sla::{DslaDecimal, PeriodLength, Slo};
+use crate::state::sla_registry::SlaIndexKind;
 
 declare_id!("HaTDBm8Ps7P6xBWFq5YbRUAnSwvCZNTceTuMB2VC3azv");
 
@@ -25,76 +32,184 @@ pub mod dsla {
 
     pub fn init_governance(
         ctx: Context<InitGovernance>,
-        dsla_deposit_by_period: u64,
-        dsla_protocol_reward: u64,
-        dsla_validator_reward: u64,
-        dsla_burned_by_verification: u64,
-        sla_deployer_rewards_rate: DslaDecimal,
-        protocol_rewards_rate: DslaDecimal,
-        max_leverage: DslaDecimal,
+        parameters: GovernanceParameters,
     ) -> Result<()> {
-        instructions::init_governance::handler(
-            ctx,
-            dsla_deposit_by_period,
-            dsla_protocol_reward,
-            dsla_validator_reward,
-            dsla_burned_by_verification,
-            sla_deployer_rewards_rate,
-            protocol_rewards_rate,
-            max_leverage,
-        )
+        instructions::init_governance::handler(ctx, parameters)
     }
 
-    pub fn modify_governance(
-        ctx: Context<ModifyGovernance>,
-        dsla_deposit_by_period: u64,
-        dsla_protocol_reward: u64,
-        dsla_validator_reward: u64,
-        dsla_burned_by_verification: u64,
-        sla_deployer_rewards_rate: DslaDecimal,
-        protocol_rewards_rate: DslaDecimal,
-        max_leverage: DslaDecimal,
+    pub fn propose_governance_change(
+        ctx: Context<ProposeGovernanceChange>,
+        parameters: GovernanceParameters,
     ) -> Result<()> {
-        instructions::modify_governance::handler(
-            ctx,
-            dsla_deposit_by_period,
-            dsla_protocol_reward,
-            dsla_validator_reward,
-            dsla_burned_by_verification,
-            sla_deployer_rewards_rate,
-            protocol_rewards_rate,
-            max_leverage,
-        )
+        instructions::propose_governance_change::handler(ctx, parameters)
+    }
+
+    pub fn execute_governance_change(ctx: Context<ExecuteGovernanceChange>) -> Result<()> {
+        instructions::execute_governance_change::handler(ctx)
+    }
+
+    pub fn cancel_governance_change(ctx: Context<CancelGovernanceChange>) -> Result<()> {
+        instructions::cancel_governance_change::handler(ctx)
+    }
+
+    pub fn transfer_governance_authority(
+        ctx: Context<TransferGovernanceAuthority>,
+        new_authority: Pubkey,
+    ) -> Result<()> {
+        instructions::transfer_governance_authority::handler(ctx, new_authority)
+    }
+
+    pub fn accept_governance_authority(ctx: Context<AcceptGovernanceAuthority>) -> Result<()> {
+        instructions::accept_governance_authority::handler(ctx)
+    }
+
+    pub fn migrate_governance(
+        ctx: Context<MigrateGovernance>,
+        parameters: GovernanceParameters,
+    ) -> Result<()> {
+        instructions::migrate_governance::handler(ctx, parameters)
+    }
+
+    pub fn set_protocol_paused(ctx: Context<SetProtocolPaused>, paused: bool) -> Result<()> {
+        instructions::set_protocol_paused::handler(ctx, paused)
+    }
+
+    pub fn set_sla_paused(ctx: Context<SetSlaPaused>, paused: bool) -> Result<()> {
+        instructions::set_sla_paused::handler(ctx, paused)
+    }
+
+    pub fn cancel_sla(ctx: Context<CancelSla>) -> Result<()> {
+        instructions::cancel_sla::handler(ctx)
+    }
+
+    pub fn reclaim_dsla_deposit(ctx: Context<ReclaimDslaDeposit>) -> Result<()> {
+        instructions::reclaim_dsla_deposit::handler(ctx)
+    }
+
+    pub fn close_sla(ctx: Context<CloseSla>) -> Result<()> {
+        instructions::close_sla::handler(ctx)
+    }
+
+    pub fn close_lockup_accounts(ctx: Context<CloseLockupAccounts>) -> Result<()> {
+        instructions::close_lockup_accounts::handler(ctx)
+    }
+
+    pub fn migrate_sla(ctx: Context<MigrateSla>) -> Result<()> {
+        instructions::migrate_sla::handler(ctx)
+    }
+
+    pub fn migrate_lockup(ctx: Context<MigrateLockup>) -> Result<()> {
+        instructions::migrate_lockup::handler(ctx)
+    }
+
+    pub fn migrate_status_registry(ctx: Context<MigrateStatusRegistry>) -> Result<()> {
+        instructions::migrate_status_registry::handler(ctx)
     }
 
     pub fn init_sla_registry(ctx: Context<InitSlaRegistry>) -> Result<()> {
         instructions::init_sla_registry::handler(ctx)
     }
 
-    pub fn stake_user(ctx: Context<StakeUser>, token_amount: u64) -> Result<()> {
-        instructions::stake_user::handler(ctx, token_amount)
+    pub fn add_sla_registry_page(ctx: Context<AddSlaRegistryPage>) -> Result<()> {
+        instructions::add_sla_registry_page::handler(ctx)
     }
 
-    pub fn stake_provider(ctx: Context<StakeProvider>, token_amount: u64) -> Result<()> {
-        instructions::stake_provider::handler(ctx, token_amount)
+    pub fn init_sla_index(ctx: Context<InitSlaIndex>, kind: SlaIndexKind) -> Result<()> {
+        instructions::init_sla_index::handler(ctx, kind)
+    }
+
+    pub fn add_sla_index_page(ctx: Context<AddSlaIndexPage>, kind: SlaIndexKind) -> Result<()> {
+        instructions::add_sla_index_page::handler(ctx, kind)
+    }
+
+    pub fn stake_user(
+        ctx: Context<StakeUser>,
+        token_amount: u64,
+        min_tokens_out: u64,
+    ) -> Result<()> {
+        instructions::stake_user::handler(ctx, token_amount, min_tokens_out)
+    }
+
+    pub fn stake_provider(
+        ctx: Context<StakeProvider>,
+        token_amount: u64,
+        min_tokens_out: u64,
+    ) -> Result<()> {
+        instructions::stake_provider::handler(ctx, token_amount, min_tokens_out)
     }
 
     pub fn validate_period(ctx: Context<ValidatePeriod>, period: u64) -> Result<()> {
         instructions::validate_period::handler(ctx, period as usize)
     }
 
-    pub fn withdraw_user(ctx: Context<WithdrawUser>, token_amount: u64) -> Result<()> {
-        instructions::withdraw_user::handler(ctx, token_amount)
+    pub fn validate_pending_periods(ctx: Context<ValidatePeriod>, max_periods: u32) -> Result<()> {
+        instructions::validate_pending_periods::handler(ctx, max_periods)
     }
 
-    pub fn withdraw_provider(ctx: Context<WithdrawProvider>, token_amount: u64) -> Result<()> {
-        instructions::withdraw_provider::handler(ctx, token_amount)
+    pub fn record_pyth_sample(ctx: Context<RecordPythSample>, period: u64) -> Result<()> {
+        instructions::record_pyth_sample::handler(ctx, period)
+    }
+
+    pub fn close_pyth_sample(ctx: Context<ClosePythSample>, period: u64) -> Result<()> {
+        instructions::close_pyth_sample::handler(ctx, period)
+    }
+
+    pub fn checkpoint_period(ctx: Context<CheckpointPeriod>, period: u64) -> Result<()> {
+        instructions::checkpoint_period::handler(ctx, period)
+    }
+
+    pub fn close_checkpoint(ctx: Context<CloseCheckpoint>, period: u64) -> Result<()> {
+        instructions::close_checkpoint::handler(ctx, period)
+    }
+
+    pub fn propose_sli(ctx: Context<ProposeSli>, period: u64, sli: DslaDecimal) -> Result<()> {
+        instructions::propose_sli::handler(ctx, period, sli)
+    }
+
+    pub fn dispute_sli(ctx: Context<DisputeSli>, period: u64) -> Result<()> {
+        instructions::dispute_sli::handler(ctx, period)
+    }
+
+    pub fn finalize_period(ctx: Context<FinalizePeriod>, period: u64) -> Result<()> {
+        instructions::finalize_period::handler(ctx, period)
+    }
+
+    pub fn resolve_dispute(
+        ctx: Context<ResolveDispute>,
+        period: u64,
+        sli: DslaDecimal,
+    ) -> Result<()> {
+        instructions::resolve_dispute::handler(ctx, period, sli)
+    }
+
+    pub fn resize_status_registry(
+        ctx: Context<ResizeStatusRegistry>,
+        n_periods: u32,
+    ) -> Result<()> {
+        instructions::resize_status_registry::handler(ctx, n_periods)
+    }
+
+    pub fn withdraw_user(
+        ctx: Context<WithdrawUser>,
+        token_amount: u64,
+        min_tokens_out: u64,
+    ) -> Result<()> {
+        instructions::withdraw_user::handler(ctx, token_amount, min_tokens_out)
+    }
+
+    pub fn withdraw_provider(
+        ctx: Context<WithdrawProvider>,
+        token_amount: u64,
+        min_tokens_out: u64,
+    ) -> Result<()> {
+        instructions::withdraw_provider::handler(ctx, token_amount, min_tokens_out)
     }
 
     pub fn init_lockup_accounts(ctx: Context<InitLockupAccounts>) -> Result<()> {
         instructions::init_lockup_accounts::handler(ctx)
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn deploy_sla(
         ctx: Context<DeploySla>,
         slo: Slo,
@@ -104,6 +219,11 @@ pub mod dsla {
         period_length: PeriodLength,
         severity: Vec<DslaDecimal>,
         penalty: Vec<DslaDecimal>,
+        oracle_source: OracleSource,
+        aggregation_policy: AggregationPolicy,
+        min_quorum: u8,
+        max_staleness: Option<u32>,
+        max_confidence_interval: Option<DslaDecimal>,
     ) -> Result<()> {
         instructions::deploy_sla::handler(
             ctx,
@@ -114,6 +234,11 @@ pub mod dsla {
             period_length,
             severity,
             penalty,
+            oracle_source,
+            aggregation_policy,
+            min_quorum,
+            max_staleness,
+            max_confidence_interval,
         )
     }
 }
@@ -0,0 +1,333 @@
+use anchor_lang::prelude::*;
+use rust_decimal::prelude::*;
+
+use crate::constants::{VIRTUAL_ASSETS, VIRTUAL_SHARES};
+use crate::errors::ErrorCode;
+
+/// split of the tokens of a withdrawal between the withdrawer and the fees
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FeeSplit {
+    /// part of the tokens paid to the withdrawer
+    pub withdrawer_amount: u64,
+    /// part of the tokens paid to the deployer of the SLA
+    pub deployer_amount: u64,
+    /// part of the tokens paid to the protocol treasury
+    pub protocol_amount: u64,
+}
+
+/// Returns the amount of UT or PT minted for depositing `assets` in a pool of `pool_size` tokens
+/// backing `supply` UT or PT, rounded down in favour of the pool
+///
+/// the pool is priced with its virtual UT/PT and tokens, so the first deposit is minted `VIRTUAL_SHARES`
+/// UT or PT for each token and the tokens the pool holds without any UT or PT go to every later deposit
+pub fn shares_for_deposit(assets: u64, pool_size: u128, supply: u128) -> Result<u64> {
+    if supply > 0 {
+        require_gt!(pool_size, 0, ErrorCode::EmptyPool);
+    }
+    let shares = mul_div_floor(
+        assets as u128,
+        virtual_amount(supply, VIRTUAL_SHARES)?,
+        virtual_amount(pool_size, VIRTUAL_ASSETS)?,
+    )?;
+    require_gt!(shares, 0, ErrorCode::DepositTooSmall);
+    Ok(u64::try_from(shares).map_err(|_| ErrorCode::CheckedOperationOverflow)?)
+}
+
+/// Returns the amount of tokens paid out of a pool of `pool_size` tokens for burning `shares`
+/// of the `supply` UT or PT backed by it, rounded down in favour of the pool
+///
+/// the pool is priced with its virtual UT/PT and tokens, the virtual UT/PT keep their part of the pool,
+/// so the value an inflated share price takes from the deposits is not paid back to the holders
+pub fn assets_for_shares(shares: u64, pool_size: u128, supply: u128) -> Result<u64> {
+    require_gte!(supply, shares as u128, ErrorCode::BurnExceedsSupply);
+    if shares == 0 {
+        return Ok(0);
+    }
+    let assets = mul_div_floor(
+        shares as u128,
+        virtual_amount(pool_size, VIRTUAL_ASSETS)?,
+        virtual_amount(supply, VIRTUAL_SHARES)?,
+    )?;
+    Ok(u64::try_from(assets).map_err(|_| ErrorCode::CheckedOperationOverflow)?)
+}
+
+/// Returns the split of `assets` withdrawn from a pool between the withdrawer and the fees
+/// of the deployer and of the protocol, the fees are rounded down
+///
+/// # Arguments
+///
+/// * `deployer_rate` - the part of `assets` paid to the deployer
+/// * `protocol_rate` - the part of `assets` paid to the protocol
+pub fn fee_split(assets: u64, deployer_rate: Decimal, protocol_rate: Decimal) -> Result<FeeSplit> {
+    require!(
+        deployer_rate >= Decimal::ZERO
+            && protocol_rate >= Decimal::ZERO
+            && deployer_rate
+                .checked_add(protocol_rate)
+                .is_some_and(|rate| rate <= Decimal::ONE),
+        ErrorCode::InvalidRewardsRates
+    );
+    let fee = |rate: Decimal| -> Result<u64> {
+        Ok(Decimal::from(assets)
+            .checked_mul(rate)
+            .ok_or(ErrorCode::CheckedOperationOverflow)?
+            .floor()
+            .to_u64()
+            .ok_or(ErrorCode::DecimalConversionError)?)
+    };
+    let deployer_amount = fee(deployer_rate)?;
+    let protocol_amount = fee(protocol_rate)?;
+    let withdrawer_amount = assets
+        .checked_sub(deployer_amount)
+        .and_then(|amount| amount.checked_sub(protocol_amount))
+        .ok_or(ErrorCode::CheckedOperationOverflow)?;
+
+    Ok(FeeSplit {
+        withdrawer_amount,
+        deployer_amount,
+        protocol_amount,
+    })
+}
+
+/// `amount` of tokens or UT/PT of a pool with its `virtual_amount`
+fn virtual_amount(amount: u128, virtual_amount: u128) -> Result<u128> {
+    Ok(amount
+        .checked_add(virtual_amount)
+        .ok_or(ErrorCode::CheckedOperationOverflow)?)
+}
+
+/// `a * b / c` rounded down
+fn mul_div_floor(a: u128, b: u128, c: u128) -> Result<u128> {
+    Ok(a.checked_mul(b)
+        .and_then(|product| product.checked_div(c))
+        .ok_or(ErrorCode::CheckedOperationOverflow)?)
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    #[test]
+    fn shares_for_first_deposit() {
+        assert_eq!(shares_for_deposit(500, 0, 0).unwrap(), 500_000);
+    }
+    #[test]
+    fn shares_for_deposit_with_donated_tokens_and_no_supply() {
+        // the tokens of the pool go to the deposits priced with its virtual UT or PT
+        assert_eq!(
+            shares_for_deposit(1, 1000, 0),
+            err!(ErrorCode::DepositTooSmall)
+        );
+        assert_eq!(shares_for_deposit(500, 1000, 0).unwrap(), 499);
+    }
+    #[test]
+    fn shares_for_deposit_in_empty_pool() {
+        assert_eq!(shares_for_deposit(500, 0, 1000), err!(ErrorCode::EmptyPool));
+    }
+    #[test]
+    fn shares_for_deposit_at_pool_price() {
+        assert_eq!(shares_for_deposit(300, 999, 999_000).unwrap(), 300_000);
+        assert_eq!(shares_for_deposit(300, 1499, 999_000).unwrap(), 200_000);
+        assert_eq!(shares_for_deposit(300, 1999, 3_999_000).unwrap(), 600_000);
+    }
+    #[test]
+    fn shares_for_deposit_rounds_down() {
+        assert_eq!(shares_for_deposit(2, 2, 1000).unwrap(), 1333);
+    }
+    #[test]
+    fn shares_for_deposit_too_small() {
+        assert_eq!(
+            shares_for_deposit(0, 0, 0),
+            err!(ErrorCode::DepositTooSmall)
+        );
+        assert_eq!(
+            shares_for_deposit(1, 1_000_000, 1),
+            err!(ErrorCode::DepositTooSmall)
+        );
+    }
+    #[test]
+    fn shares_for_deposit_overflow() {
+        assert_eq!(
+            shares_for_deposit(u64::MAX, 1, u128::MAX),
+            err!(ErrorCode::CheckedOperationOverflow)
+        );
+        assert_eq!(
+            shares_for_deposit(u64::MAX, 0, 0),
+            err!(ErrorCode::CheckedOperationOverflow)
+        );
+    }
+    #[test]
+    fn assets_for_shares_at_pool_price() {
+        assert_eq!(assets_for_shares(100_000, 999, 999_000).unwrap(), 100);
+        assert_eq!(assets_for_shares(100_000, 1499, 999_000).unwrap(), 150);
+        assert_eq!(assets_for_shares(100_000, 499, 999_000).unwrap(), 50);
+    }
+    #[test]
+    fn assets_for_shares_with_donated_tokens() {
+        // the tokens of the pool go to the holders of the supply, less the part of the virtual UT or PT
+        assert_eq!(assets_for_shares(999_000, 4999, 999_000).unwrap(), 4995);
+    }
+    #[test]
+    fn assets_for_shares_rounds_down() {
+        assert_eq!(assets_for_shares(1, 2, 3).unwrap(), 0);
+        assert_eq!(assets_for_shares(2000, 5, 3000).unwrap(), 3);
+    }
+    #[test]
+    fn assets_for_shares_of_zero_supply() {
+        assert_eq!(assets_for_shares(0, 1000, 0).unwrap(), 0);
+        assert_eq!(
+            assets_for_shares(1, 1000, 0),
+            err!(ErrorCode::BurnExceedsSupply)
+        );
+    }
+    #[test]
+    fn assets_for_shares_exceeding_supply() {
+        assert_eq!(
+            assets_for_shares(1001, 1000, 1000),
+            err!(ErrorCode::BurnExceedsSupply)
+        );
+    }
+    #[test]
+    fn fee_split_rounds_fees_down() {
+        assert_eq!(
+            fee_split(199, Decimal::new(3, 2), Decimal::new(2, 2)).unwrap(),
+            FeeSplit {
+                withdrawer_amount: 191,
+                deployer_amount: 5,
+                protocol_amount: 3,
+            }
+        );
+    }
+    #[test]
+    fn fee_split_without_fees() {
+        assert_eq!(
+            fee_split(100, Decimal::ZERO, Decimal::ZERO).unwrap(),
+            FeeSplit {
+                withdrawer_amount: 100,
+                deployer_amount: 0,
+                protocol_amount: 0,
+            }
+        );
+    }
+    #[test]
+    fn fee_split_invalid_rates() {
+        assert_eq!(
+            fee_split(100, Decimal::new(-1, 2), Decimal::ZERO),
+            err!(ErrorCode::InvalidRewardsRates)
+        );
+        assert_eq!(
+            fee_split(100, Decimal::new(6, 1), Decimal::new(5, 1)),
+            err!(ErrorCode::InvalidRewardsRates)
+        );
+    }
+
+    /// xorshift generator, the properties are checked on the same inputs in every run
+    pub(crate) struct Inputs(pub(crate) u64);
+
+    impl Inputs {
+        pub(crate) fn next(&mut self, max: u64) -> u64 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            self.0 % max.saturating_add(1)
+        }
+    }
+
+    #[test]
+    fn property_deposit_then_withdraw_never_pays_more() {
+        let mut inputs = Inputs(0x2545_f491_4f6c_dd1d);
+        for _ in 0..10_000 {
+            let supply = inputs.next(u32::MAX as u64) as u128;
+            let pool_size = inputs.next(u32::MAX as u64) as u128;
+            let assets = inputs.next(u32::MAX as u64);
+            let shares = match shares_for_deposit(assets, pool_size, supply) {
+                Ok(shares) => shares,
+                Err(_) => continue,
+            };
+            let withdrawn =
+                assets_for_shares(shares, pool_size + assets as u128, supply + shares as u128)
+                    .unwrap();
+            assert!(
+                withdrawn <= assets,
+                "{assets} deposited in {pool_size}/{supply} withdrawn as {withdrawn}"
+            );
+        }
+    }
+    #[test]
+    fn property_share_price_never_decreases() {
+        let mut inputs = Inputs(0x9e37_79b9_7f4a_7c15);
+        for _ in 0..10_000 {
+            let supply = inputs.next(u32::MAX as u64) as u128 + 1;
+            let pool_size = inputs.next(u32::MAX as u64) as u128 + 1;
+            let assets = inputs.next(u32::MAX as u64);
+            // the price of the pool with its virtual UT/PT and tokens never decreases for the holders left in it
+            let price_kept = |new_pool_size: u128, new_supply: u128| {
+                (pool_size + VIRTUAL_ASSETS) * (new_supply + VIRTUAL_SHARES)
+                    <= (new_pool_size + VIRTUAL_ASSETS) * (supply + VIRTUAL_SHARES)
+            };
+            if let Ok(shares) = shares_for_deposit(assets, pool_size, supply) {
+                assert!(price_kept(
+                    pool_size + assets as u128,
+                    supply + shares as u128
+                ));
+            }
+            let burned = inputs.next(supply as u64);
+            let withdrawn = assets_for_shares(burned, pool_size, supply).unwrap();
+            assert!(price_kept(
+                pool_size - withdrawn as u128,
+                supply - burned as u128
+            ));
+        }
+    }
+    #[test]
+    fn property_withdrawals_never_exceed_pool() {
+        let mut inputs = Inputs(0xd1b5_4a32_d192_ed03);
+        for _ in 0..10_000 {
+            let supply = inputs.next(u64::MAX) as u128;
+            let pool_size = inputs.next(u64::MAX) as u128;
+            let burned = inputs.next(supply as u64);
+            let withdrawn = assets_for_shares(burned, pool_size, supply).unwrap();
+            assert!(withdrawn as u128 <= pool_size);
+        }
+    }
+    #[test]
+    fn property_stakes_lose_at_most_one_share() {
+        let mut inputs = Inputs(0xbb67_ae85_84ca_a73b);
+        for _ in 0..10_000 {
+            // the holders of a pool with any share price, however it got there
+            let supply = inputs.next(u32::MAX as u64) as u128 + 1;
+            let pool_size = inputs.next(u64::MAX >> 16) as u128 + 1;
+            let deposit = inputs.next(u32::MAX as u64);
+            let shares = match shares_for_deposit(deposit, pool_size, supply) {
+                Ok(shares) => shares,
+                Err(_) => continue,
+            };
+            let withdrawn =
+                assets_for_shares(shares, pool_size + deposit as u128, supply + shares as u128)
+                    .unwrap();
+            // the stake loses at most the price of the UT or PT it was rounded down by and a token
+            let loss = (deposit - withdrawn) as u128;
+            assert!(
+                loss * (supply + VIRTUAL_SHARES)
+                    <= (pool_size + VIRTUAL_ASSETS) + (supply + VIRTUAL_SHARES),
+                "{deposit} deposited in {pool_size}/{supply} withdrawn as {withdrawn}"
+            );
+        }
+    }
+    #[test]
+    fn property_fee_split_adds_up() {
+        let mut inputs = Inputs(0x6a09_e667_f3bc_c908);
+        for _ in 0..10_000 {
+            let assets = inputs.next(u64::MAX);
+            let deployer_rate = Decimal::new(inputs.next(5000) as i64, 4);
+            let protocol_rate = Decimal::new(inputs.next(5000) as i64, 4);
+            let split = fee_split(assets, deployer_rate, protocol_rate).unwrap();
+            assert_eq!(
+                split.withdrawer_amount as u128
+                    + split.deployer_amount as u128
+                    + split.protocol_amount as u128,
+                assets as u128
+            );
+        }
+    }
+}
@@ -0,0 +1,677 @@
+use anchor_lang::prelude::*;
+use rust_decimal::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::pricing::{self, FeeSplit};
+use crate::state::{Governance, Sla, SlaStatus};
+
+/// amounts of tokens paid out of a pool by a withdrawal
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WithdrawQuote {
+    /// amount of tokens taken out of the pool for the burned UT or PT
+    pub tokens_to_withdraw: u64,
+    /// part of the tokens paid to the withdrawer
+    pub withdrawer_amount: u64,
+    /// part of the tokens paid to the deployer of the SLA
+    pub deployer_amount: u64,
+    /// part of the tokens paid to the protocol treasury
+    pub protocol_amount: u64,
+}
+
+// the periods that finished but are not settled yet were checkpointed without the stakes and withdrawals
+// made since, which neither share nor escape what they move between the pools: the UT and PT are priced
+// as if those periods moved the most they can against the staker, `Sla::pending_transfer`, to the pool
+// of the side being staked and out of the pool of the side being withdrawn, the stakers that were exposed
+// to the periods keep the difference once they are settled
+
+/// Returns the amount of UT `stake_user` mints for staking `token_amount` in the `sla`
+pub fn stake_user(sla: &Sla, token_amount: u64) -> Result<u64> {
+    let leverage_adjusted_user_pool = sla
+        .leverage
+        .to_decimal()
+        .checked_mul(
+            to_decimal(sla.user_pool_size)?
+                .checked_add(Decimal::from(token_amount))
+                .ok_or(ErrorCode::CheckedOperationOverflow)?,
+        )
+        .ok_or(ErrorCode::CheckedOperationOverflow)?;
+    require_gte!(
+        to_decimal(sla.provider_pool_size)?,
+        leverage_adjusted_user_pool
+    );
+
+    pricing::shares_for_deposit(
+        token_amount,
+        pending_inflow(sla.user_pool_size, sla)?,
+        sla.ut_supply,
+    )
+}
+
+/// Returns the amount of PT `stake_provider` mints for staking `token_amount` in the `sla`
+pub fn stake_provider(sla: &Sla, token_amount: u64) -> Result<u64> {
+    pricing::shares_for_deposit(
+        token_amount,
+        pending_inflow(sla.provider_pool_size, sla)?,
+        sla.pt_supply,
+    )
+}
+
+/// Returns the amounts `withdraw_user` pays for burning `burn_amount` UT of the `sla`
+pub fn withdraw_user(
+    sla: &Sla,
+    governance: &Governance,
+    burn_amount: u64,
+) -> Result<WithdrawQuote> {
+    let tokens_to_withdraw = pricing::assets_for_shares(
+        burn_amount,
+        pending_outflow(sla.user_pool_size, sla),
+        sla.ut_supply,
+    )?;
+
+    withdraw_quote(sla, governance, tokens_to_withdraw)
+}
+
+/// Returns the amounts `withdraw_provider` pays for burning `burn_amount` PT of the `sla`
+///
+/// # Arguments
+///
+/// * `sla_status` - the status of the SLA at the withdrawal, the provider pool covers the user pool until the end
+///
+/// the fees are part of the tokens withdrawn, so the provider pool left without them
+/// still needs to cover the user pool times the leverage
+pub fn withdraw_provider(
+    sla: &Sla,
+    governance: &Governance,
+    sla_status: SlaStatus,
+    burn_amount: u64,
+) -> Result<WithdrawQuote> {
+    let tokens_to_withdraw = pricing::assets_for_shares(
+        burn_amount,
+        pending_outflow(sla.provider_pool_size, sla),
+        sla.pt_supply,
+    )?;
+    if sla_status != SlaStatus::Ended {
+        let provider_pool_left = sla
+            .provider_pool_size
+            .checked_sub(tokens_to_withdraw as u128)
+            .ok_or(ErrorCode::CheckedOperationOverflow)?;
+        let leverage_adjusted_user_pool = sla
+            .leverage
+            .to_decimal()
+            .checked_mul(to_decimal(sla.user_pool_size)?)
+            .ok_or(ErrorCode::CheckedOperationOverflow)?;
+        require_gte!(
+            to_decimal(provider_pool_left)?,
+            leverage_adjusted_user_pool,
+            ErrorCode::InsufficientProviderCoverage
+        );
+    }
+
+    withdraw_quote(sla, governance, tokens_to_withdraw)
+}
+
+/// size of a pool the stakes are priced at, as if the pending periods paid it their `pending_transfer`
+fn pending_inflow(pool_size: u128, sla: &Sla) -> Result<u128> {
+    Ok(pool_size
+        .checked_add(sla.pending_transfer)
+        .ok_or(ErrorCode::CheckedOperationOverflow)?)
+}
+
+/// size of a pool the withdrawals are priced at, as if it paid the pending periods their `pending_transfer`
+fn pending_outflow(pool_size: u128, sla: &Sla) -> u128 {
+    pool_size.saturating_sub(sla.pending_transfer)
+}
+
+fn to_decimal(amount: u128) -> Result<Decimal> {
+    Ok(Decimal::from_u128(amount).ok_or(ErrorCode::DecimalConversionError)?)
+}
+
+/// splits `tokens_to_withdraw` between the withdrawer, the deployer and the protocol,
+/// the stakes of a cancelled SLA are refunded without fees
+fn withdraw_quote(
+    sla: &Sla,
+    governance: &Governance,
+    tokens_to_withdraw: u64,
+) -> Result<WithdrawQuote> {
+    let (deployer_rate, protocol_rate) = if sla.cancelled {
+        (Decimal::ZERO, Decimal::ZERO)
+    } else {
+        (
+            governance.sla_deployer_rewards_rate.to_decimal(),
+            governance.protocol_rewards_rate.to_decimal(),
+        )
+    };
+    let FeeSplit {
+        withdrawer_amount,
+        deployer_amount,
+        protocol_amount,
+    } = pricing::fee_split(tokens_to_withdraw, deployer_rate, protocol_rate)?;
+
+    Ok(WithdrawQuote {
+        tokens_to_withdraw,
+        withdrawer_amount,
+        deployer_amount,
+        protocol_amount,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::{VIRTUAL_ASSETS, VIRTUAL_SHARES};
+    use crate::pricing::tests::Inputs;
+    use crate::state::{
+        AggregationPolicy, DepositReclaimPolicy, DslaDecimal, OracleSource, PeriodCheckpoint,
+        PeriodGenerator, PeriodLength, Slo, SloType,
+    };
+
+    fn sla(pools: (u128, u128), supplies: (u128, u128)) -> Sla {
+        Sla {
+            version: Sla::VERSION,
+            sla_deployer_address: Pubkey::default(),
+            oracle_source: OracleSource::Switchboard,
+            oracle_addresses: vec![],
+            aggregation_policy: AggregationPolicy::Median,
+            min_quorum: 1,
+            max_staleness: 300,
+            max_confidence_interval: DslaDecimal::from_decimal(Decimal::ONE),
+            slo: Slo {
+                slo_value: DslaDecimal::from_decimal(Decimal::new(100, 0)),
+                slo_type: SloType::GreaterOrEqualTo,
+            },
+            leverage: DslaDecimal::from_decimal(Decimal::ONE),
+            mint_address: Pubkey::default(),
+            period_data: PeriodGenerator {
+                start: 100,
+                period_length: PeriodLength::Custom { length: 50 },
+                n_periods: 4,
+            },
+            user_pool_size: pools.0,
+            provider_pool_size: pools.1,
+            ut_supply: supplies.0,
+            pt_supply: supplies.1,
+            severity: vec![],
+            penalty: vec![],
+            cancelled: false,
+            paused: false,
+            checkpointed_periods: 0,
+            pending_transfer: 0,
+        }
+    }
+
+    fn governance() -> Governance {
+        Governance {
+            version: Governance::VERSION,
+            dsla_deposit_by_period: 0,
+            dsla_protocol_reward: 0,
+            dsla_validator_reward: 0,
+            dsla_burned_by_verification: 0,
+            sla_deployer_rewards_rate: DslaDecimal::from_decimal(Decimal::new(3, 2)),
+            protocol_rewards_rate: DslaDecimal::from_decimal(Decimal::new(2, 2)),
+            max_leverage: DslaDecimal::from_decimal(Decimal::ONE),
+            default_max_staleness: 300,
+            max_staleness_limit: 3600,
+            default_max_confidence_interval: DslaDecimal::from_decimal(Decimal::ONE),
+            max_confidence_interval_limit: DslaDecimal::from_decimal(Decimal::new(100, 0)),
+            attestation_window: 3600,
+            dispute_window: 86400,
+            proposal_bond: 100,
+            dispute_bond: 100,
+            arbiter: Pubkey::default(),
+            protocol_treasury: Pubkey::default(),
+            governance_authority: Pubkey::default(),
+            pending_governance_authority: None,
+            dsla_mint: Pubkey::default(),
+            deposit_reclaim_grace_period: 0,
+            deposit_reclaim_policy: DepositReclaimPolicy::Deployer,
+            sla_cancellation_fee_rate: DslaDecimal::from_decimal(Decimal::ZERO),
+            guardian: Pubkey::default(),
+            paused: false,
+        }
+    }
+
+    #[test]
+    fn stake_mints_virtual_shares_at_par() {
+        let sla = sla((1000, 2000), (1_000_000, 2_000_000));
+        assert_eq!(stake_user(&sla, 500).unwrap(), 500_000);
+        assert_eq!(stake_provider(&sla, 500).unwrap(), 500_000);
+    }
+    #[test]
+    fn stake_mints_at_pool_price() {
+        let sla = sla((1499, 1999), (999_000, 3_999_000));
+        assert_eq!(stake_user(&sla, 300).unwrap(), 200_000);
+        assert_eq!(stake_provider(&sla, 300).unwrap(), 600_000);
+    }
+    #[test]
+    fn stake_user_needs_provider_coverage() {
+        let sla = sla((1000, 1200), (1_000_000, 1_200_000));
+        assert!(stake_user(&sla, 200).is_ok());
+        assert!(stake_user(&sla, 201).is_err());
+    }
+    #[test]
+    fn withdraw_user_splits_fees() {
+        let sla = sla((1000, 1000), (1_000_000, 1_000_000));
+        assert_eq!(
+            withdraw_user(&sla, &governance(), 100_000).unwrap(),
+            WithdrawQuote {
+                tokens_to_withdraw: 100,
+                withdrawer_amount: 95,
+                deployer_amount: 3,
+                protocol_amount: 2,
+            }
+        );
+    }
+    #[test]
+    fn withdraw_user_at_pool_price() {
+        let sla = sla((1499, 2000), (999_000, 2_000_000));
+        assert_eq!(
+            withdraw_user(&sla, &governance(), 100_000).unwrap(),
+            WithdrawQuote {
+                tokens_to_withdraw: 150,
+                withdrawer_amount: 143,
+                deployer_amount: 4,
+                protocol_amount: 3,
+            }
+        );
+    }
+    #[test]
+    fn withdraw_provider_keeps_user_pool_covered() {
+        let mut sla = sla((400, 1000), (400_000, 1_000_000));
+        sla.leverage = DslaDecimal::from_decimal(Decimal::TWO);
+        let active = SlaStatus::Active { period_id: 1 };
+        assert_eq!(
+            withdraw_provider(&sla, &governance(), active, 200_000)
+                .unwrap()
+                .tokens_to_withdraw,
+            200
+        );
+        // the fees of the withdrawal leave the pool too, 799 tokens would be left for 800
+        assert_eq!(
+            withdraw_provider(&sla, &governance(), active, 201_000),
+            err!(ErrorCode::InsufficientProviderCoverage)
+        );
+        assert_eq!(
+            withdraw_provider(&sla, &governance(), SlaStatus::NotStarted, 201_000),
+            err!(ErrorCode::InsufficientProviderCoverage)
+        );
+    }
+    #[test]
+    fn withdraw_provider_coverage_of_provider_pool_left() {
+        let mut sla = sla((500, 1000), (500_000, 1_000_000));
+        sla.leverage = DslaDecimal::from_decimal(Decimal::TWO);
+        let active = SlaStatus::Active { period_id: 1 };
+        // the provider pool exactly covers the user pool, nothing can be withdrawn
+        // even though the user pool is smaller than the provider pool
+        assert_eq!(
+            withdraw_provider(&sla, &governance(), active, 1000),
+            err!(ErrorCode::InsufficientProviderCoverage)
+        );
+        assert_eq!(
+            withdraw_provider(&sla, &governance(), active, 100_000),
+            err!(ErrorCode::InsufficientProviderCoverage)
+        );
+        // a withdrawal larger than the user pool only needs to leave it covered
+        sla.user_pool_size = 100;
+        sla.ut_supply = 100_000;
+        assert_eq!(
+            withdraw_provider(&sla, &governance(), active, 800_000)
+                .unwrap()
+                .tokens_to_withdraw,
+            800
+        );
+        assert_eq!(
+            withdraw_provider(&sla, &governance(), active, 801_000),
+            err!(ErrorCode::InsufficientProviderCoverage)
+        );
+    }
+    #[test]
+    fn withdraw_provider_after_end_without_coverage() {
+        let mut sla = sla((400, 1000), (400_000, 1_000_000));
+        sla.leverage = DslaDecimal::from_decimal(Decimal::TWO);
+        assert_eq!(
+            withdraw_provider(&sla, &governance(), SlaStatus::Ended, 1_000_000)
+                .unwrap()
+                .tokens_to_withdraw,
+            1000
+        );
+    }
+    #[test]
+    fn withdraw_cancelled_without_fees() {
+        let mut sla = sla((1000, 1000), (1_000_000, 1_000_000));
+        sla.cancelled = true;
+        assert_eq!(
+            withdraw_provider(&sla, &governance(), SlaStatus::Ended, 100_000).unwrap(),
+            WithdrawQuote {
+                tokens_to_withdraw: 100,
+                withdrawer_amount: 100,
+                deployer_amount: 0,
+                protocol_amount: 0,
+            }
+        );
+    }
+
+    #[test]
+    fn stake_priced_with_pending_transfer() {
+        let mut sla = sla((1000, 1500), (999_000, 999_000));
+        sla.pending_transfer = 499;
+        // as if the pending periods paid 499 tokens to the pool being staked
+        assert_eq!(stake_user(&sla, 300).unwrap(), 200_000);
+        assert_eq!(stake_provider(&sla, 300).unwrap(), 150_000);
+    }
+    #[test]
+    fn withdraw_priced_with_pending_transfer() {
+        let mut sla = sla((1998, 2498), (999_000, 999_000));
+        sla.pending_transfer = 499;
+        // as if the pool being withdrawn paid 499 tokens to the pending periods
+        assert_eq!(
+            withdraw_user(&sla, &governance(), 100_000)
+                .unwrap()
+                .tokens_to_withdraw,
+            150
+        );
+        assert_eq!(
+            withdraw_provider(&sla, &governance(), SlaStatus::Ended, 100_000)
+                .unwrap()
+                .tokens_to_withdraw,
+            200
+        );
+        // the pending periods can't take more than the pool
+        sla.pending_transfer = 3000;
+        assert_eq!(
+            withdraw_user(&sla, &governance(), 999_000)
+                .unwrap()
+                .tokens_to_withdraw,
+            0
+        );
+    }
+
+    /// an SLA and the balances of its pool token account, updated by the quotes like the instructions do
+    struct Simulation {
+        sla: Sla,
+        governance: Governance,
+        /// balance of the pool token account
+        pool: u128,
+        /// tokens staked in the pool
+        staked: u128,
+        /// tokens paid out of the pool to the stakers, the deployer and the protocol
+        paid: u128,
+    }
+
+    impl Simulation {
+        fn new(leverage: Decimal) -> Self {
+            let mut sla = sla((0, 0), (0, 0));
+            sla.leverage = DslaDecimal::from_decimal(leverage);
+            Self {
+                sla,
+                governance: governance(),
+                pool: 0,
+                staked: 0,
+                paid: 0,
+            }
+        }
+
+        fn stake_user(&mut self, token_amount: u64) -> Result<()> {
+            let minted = stake_user(&self.sla, token_amount)?;
+            self.sla.user_pool_size += token_amount as u128;
+            self.sla.ut_supply += minted as u128;
+            self.deposit(token_amount);
+            Ok(())
+        }
+
+        fn stake_provider(&mut self, token_amount: u64) -> Result<()> {
+            let minted = stake_provider(&self.sla, token_amount)?;
+            self.sla.provider_pool_size += token_amount as u128;
+            self.sla.pt_supply += minted as u128;
+            self.deposit(token_amount);
+            Ok(())
+        }
+
+        /// returns the amount of tokens withdrawn for the burned UT
+        fn withdraw_user(&mut self, burn_amount: u64) -> Result<u64> {
+            let quote = withdraw_user(&self.sla, &self.governance, burn_amount)?;
+            self.sla.ut_supply -= burn_amount as u128;
+            self.sla.user_pool_size -= quote.tokens_to_withdraw as u128;
+            self.pay(quote);
+            Ok(quote.tokens_to_withdraw)
+        }
+
+        /// returns the amount of tokens withdrawn for the burned PT
+        fn withdraw_provider(&mut self, sla_status: SlaStatus, burn_amount: u64) -> Result<u64> {
+            let quote = withdraw_provider(&self.sla, &self.governance, sla_status, burn_amount)?;
+            self.sla.pt_supply -= burn_amount as u128;
+            self.sla.provider_pool_size -= quote.tokens_to_withdraw as u128;
+            self.pay(quote);
+            Ok(quote.tokens_to_withdraw)
+        }
+
+        /// checkpoints the finished `period` like `checkpoint_period` does
+        fn checkpoint(&mut self, period: u64) -> PeriodCheckpoint {
+            let checkpoint =
+                PeriodCheckpoint::new(Pubkey::default(), period, &self.sla, false).unwrap();
+            self.sla.checkpoint_period(checkpoint.max_transfer).unwrap();
+            checkpoint
+        }
+
+        /// settles the period of the `checkpoint` with `sli` like the validation does
+        fn settle(&mut self, checkpoint: &PeriodCheckpoint, sli: i64) -> Result<bool> {
+            let periods_left = (self.sla.period_data.n_periods as u64 - checkpoint.period) as usize;
+            let sli = DslaDecimal::from_decimal(Decimal::from(sli));
+            let respected = self
+                .sla
+                .settle_period(sli, periods_left, checkpoint.user_pool_size)?;
+            self.sla.pending_transfer -= checkpoint.max_transfer;
+            Ok(respected)
+        }
+
+        fn deposit(&mut self, token_amount: u64) {
+            self.pool += token_amount as u128;
+            self.staked += token_amount as u128;
+        }
+
+        fn pay(&mut self, quote: WithdrawQuote) {
+            let paid = quote.withdrawer_amount as u128
+                + quote.deployer_amount as u128
+                + quote.protocol_amount as u128;
+            assert_eq!(paid, quote.tokens_to_withdraw as u128);
+            self.pool -= paid;
+            self.paid += paid;
+        }
+
+        fn covered(&self) -> bool {
+            Decimal::from(self.sla.provider_pool_size)
+                >= self.sla.leverage.to_decimal() * Decimal::from(self.sla.user_pool_size)
+        }
+
+        /// the pools of the SLA hold every token of the pool token account
+        fn assert_balanced(&self) {
+            assert_eq!(
+                self.pool,
+                self.sla.user_pool_size + self.sla.provider_pool_size
+            );
+            assert_eq!(self.staked, self.pool + self.paid);
+        }
+    }
+
+    #[test]
+    fn simulation_withdraw_provider_fees_leave_the_pool() {
+        let mut simulation = Simulation::new(Decimal::ONE);
+        simulation.stake_provider(1000).unwrap();
+        simulation.stake_user(400).unwrap();
+        simulation
+            .withdraw_provider(SlaStatus::Active { period_id: 1 }, 100_000)
+            .unwrap();
+        assert_eq!(simulation.sla.provider_pool_size, 900);
+        assert_eq!(simulation.paid, 100);
+        simulation.assert_balanced();
+    }
+    #[test]
+    fn simulation_withdraw_provider_down_to_coverage() {
+        let mut simulation = Simulation::new(Decimal::new(15, 1));
+        let active = SlaStatus::Active { period_id: 1 };
+        simulation.stake_provider(1000).unwrap();
+        simulation.stake_user(600).unwrap();
+        simulation.withdraw_provider(active, 100_000).unwrap();
+        assert_eq!(
+            simulation.withdraw_provider(active, 1000),
+            err!(ErrorCode::InsufficientProviderCoverage)
+        );
+        // the users leaving free up the provider pool
+        simulation.withdraw_user(200_000).unwrap();
+        simulation.withdraw_provider(active, 300_000).unwrap();
+        assert!(simulation.covered());
+        simulation.assert_balanced();
+    }
+    #[test]
+    fn simulation_inflated_share_price_costs_the_attacker() {
+        let mut simulation = Simulation::new(Decimal::from(10));
+        simulation.stake_provider(1_000_000_000_000).unwrap();
+        // the attacker inflates the UT price with the rewards of breached periods
+        // and burns every UT but one before the victim stakes
+        simulation.stake_user(1000).unwrap();
+        for periods_left in (1..=10).rev() {
+            let respected = simulation
+                .sla
+                .settle_period(
+                    DslaDecimal::from_decimal(Decimal::ZERO),
+                    periods_left,
+                    simulation.sla.user_pool_size,
+                )
+                .unwrap();
+            assert!(!respected);
+        }
+        simulation
+            .withdraw_user(simulation.sla.ut_supply as u64 - 1)
+            .unwrap();
+        let pool_size = simulation.sla.user_pool_size + VIRTUAL_ASSETS;
+        let supply = simulation.sla.ut_supply + VIRTUAL_SHARES;
+
+        let victim_stake = 1_000_000;
+        let minted = stake_user(&simulation.sla, victim_stake).unwrap();
+        simulation.stake_user(victim_stake).unwrap();
+        let tokens_to_withdraw = withdraw_user(&simulation.sla, &simulation.governance, minted)
+            .unwrap()
+            .tokens_to_withdraw;
+        simulation.withdraw_user(minted).unwrap();
+        // the victim loses at most the price of one UT and one token to the rounding
+        let victim_loss = (victim_stake - tokens_to_withdraw) as u128;
+        assert!(victim_loss * supply <= pool_size + supply);
+
+        // while the virtual UT keep their part of the pool the attacker paid for
+        simulation.withdraw_user(1).unwrap();
+        assert_eq!(simulation.sla.ut_supply, 0);
+        assert!(simulation.sla.user_pool_size > 100 * victim_loss);
+        simulation.assert_balanced();
+    }
+
+    #[test]
+    fn simulation_stake_after_period_does_not_share_its_compensation() {
+        let mut simulation = Simulation::new(Decimal::ONE);
+        simulation.stake_provider(10_000).unwrap();
+        simulation.stake_user(1000).unwrap();
+        let exposed_ut = simulation.sla.ut_supply as u64;
+        let checkpoint = simulation.checkpoint(0);
+        // 1000 tokens / 4 periods left * 25%
+        assert_eq!(checkpoint.max_transfer, 62);
+
+        // the stake right before the breached period is validated pays for its compensation
+        let minted = stake_user(&simulation.sla, 1000).unwrap();
+        simulation.stake_user(1000).unwrap();
+        assert!(!simulation.settle(&checkpoint, 0).unwrap());
+        assert_eq!(simulation.sla.user_pool_size, 2062);
+        assert_eq!(simulation.sla.pending_transfer, 0);
+
+        assert!(simulation.withdraw_user(minted).unwrap() <= 1000);
+        // the users exposed to the period get all of it
+        assert!(simulation.withdraw_user(exposed_ut).unwrap() >= 1061);
+        simulation.assert_balanced();
+    }
+    #[test]
+    fn simulation_withdrawal_before_settlement_does_not_escape_the_period() {
+        let mut simulation = Simulation::new(Decimal::ONE);
+        simulation.stake_provider(10_000).unwrap();
+        simulation.stake_user(1000).unwrap();
+        simulation.stake_user(1000).unwrap();
+        let half = (simulation.sla.ut_supply / 2) as u64;
+        let checkpoint = simulation.checkpoint(0);
+        assert_eq!(checkpoint.max_transfer, 125);
+
+        // the withdrawal is paid as if the users paid the respected period
+        let first = simulation.withdraw_user(half).unwrap();
+        assert!(simulation.settle(&checkpoint, 200).unwrap());
+        let second = simulation.withdraw_user(half).unwrap();
+        assert!(first <= 938);
+        assert!(second >= 937);
+        assert_eq!(simulation.sla.ut_supply, 0);
+        simulation.assert_balanced();
+    }
+
+    #[test]
+    fn simulation_provider_withdrawals_keep_the_periods_settleable() {
+        let mut inputs = Inputs(0x3c6e_f372_fe94_f82b);
+        for _ in 0..500 {
+            let mut simulation = Simulation::new(Decimal::new(inputs.next(30) as i64 + 1, 1));
+            simulation.sla.period_data.n_periods = 5;
+            let mut pending = vec![];
+            for step in 0..50 {
+                let period_id = step / 10;
+                let active = SlaStatus::Active { period_id };
+                // the finished periods are checkpointed before the next stake or withdrawal
+                while simulation.sla.checkpointed_periods < period_id {
+                    let period = simulation.sla.checkpointed_periods as u64;
+                    pending.push(simulation.checkpoint(period));
+                }
+                let amount = inputs.next(1_000_000);
+                match inputs.next(4) {
+                    0 => {
+                        let _ = simulation.stake_user(amount);
+                    }
+                    1 => {
+                        let _ = simulation.stake_provider(amount);
+                    }
+                    2 => {
+                        let burned = inputs.next(simulation.sla.ut_supply as u64);
+                        let _ = simulation.withdraw_user(burned);
+                    }
+                    3 => {
+                        let covered = simulation.covered();
+                        let burned = inputs.next(simulation.sla.pt_supply as u64);
+                        match simulation.withdraw_provider(active, burned) {
+                            Ok(_) => {
+                                assert!(simulation.covered());
+                                // the next period can still be settled whatever its SLI
+                                for sli in [0, 200] {
+                                    let mut sla = simulation.sla.clone();
+                                    let sli = DslaDecimal::from_decimal(Decimal::from(sli));
+                                    let user_pool_size = sla.user_pool_size;
+                                    assert!(sla.settle_period(sli, 1, user_pool_size).is_ok());
+                                }
+                            }
+                            Err(error) => assert!(
+                                !covered
+                                    || error == error!(ErrorCode::InsufficientProviderCoverage)
+                            ),
+                        }
+                    }
+                    _ if !pending.is_empty() => {
+                        let checkpoint = pending.remove(0);
+                        if simulation
+                            .settle(&checkpoint, inputs.next(200) as i64)
+                            .is_err()
+                        {
+                            pending.insert(0, checkpoint);
+                        }
+                    }
+                    _ => {}
+                }
+                simulation.assert_balanced();
+            }
+            // once the SLA ended every staker can leave, even before the last periods are settled
+            let (ut_supply, pt_supply) = (simulation.sla.ut_supply, simulation.sla.pt_supply);
+            simulation.withdraw_user(ut_supply as u64).unwrap();
+            simulation
+                .withdraw_provider(SlaStatus::Ended, pt_supply as u64)
+                .unwrap();
+            simulation.assert_balanced();
+        }
+    }
+}
@@ -0,0 +1,303 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::ed25519_program;
+use anchor_lang::solana_program::sysvar::instructions::load_instruction_at_checked;
+
+use rust_decimal::Decimal;
+
+use super::DslaDecimal;
+use crate::errors::FeedErrorCode;
+
+/// size of the header of an ed25519 instruction, number of signatures and padding
+const ED25519_HEADER_LEN: usize = 2;
+/// size of the offsets of each signature of an ed25519 instruction
+const ED25519_OFFSETS_LEN: usize = 14;
+/// instruction index of the offsets pointing in the ed25519 instruction itself
+const CURRENT_INSTRUCTION: u16 = u16::MAX;
+
+/// SLI of a period measured by an off-chain monitoring system,
+/// attesters sign its borsh serialization with an ed25519 instruction in the validation transaction
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Attestation {
+    /// address of the SLA the SLI was measured for
+    pub sla: Pubkey,
+    /// period the SLI was measured for
+    pub period: u64,
+    /// measured SLI of the period
+    pub sli: DslaDecimal,
+    /// unix timestamp the attestation was signed at
+    pub timestamp: i64,
+}
+
+impl Attestation {
+    pub const LEN: usize = 32 // sla
+    + 8 // period
+    + DslaDecimal::LEN // sli
+    + 8 // timestamp
+    ;
+
+    /// Returns the attestations and their signer of all the ed25519 instructions of the transaction
+    ///
+    /// the runtime fails the transaction if any ed25519 signature is not valid, so they don't need to be verified again
+    ///
+    /// # Arguments
+    ///
+    /// * `instructions_sysvar` - the instructions sysvar account
+    pub fn load_verified(instructions_sysvar: &AccountInfo) -> Result<Vec<(Pubkey, Attestation)>> {
+        require_keys_eq!(
+            *instructions_sysvar.key,
+            anchor_lang::solana_program::sysvar::instructions::ID,
+            FeedErrorCode::InvalidInstructionsSysvar
+        );
+        let mut attestations = vec![];
+        let mut index = 0;
+        while let Ok(instruction) = load_instruction_at_checked(index, instructions_sysvar) {
+            if instruction.program_id == ed25519_program::ID {
+                for (signer, message) in parse_ed25519_instruction(&instruction.data)? {
+                    // ignore the signed messages that are not attestations
+                    if message.len() != Attestation::LEN {
+                        continue;
+                    }
+                    if let Ok(attestation) = Attestation::try_from_slice(message) {
+                        attestations.push((signer, attestation));
+                    }
+                }
+            }
+            index += 1;
+        }
+        Ok(attestations)
+    }
+
+    /// Returns the SLI attested by each of the `attesters` for `period` of the `sla` in the order of `attesters`,
+    /// `None` for the attesters without an attestation signed after `period_end` and at the latest at `now`
+    ///
+    /// # Arguments
+    ///
+    /// * `attestations` - the attestations of the transaction with their signer, see `load_verified`
+    pub fn results(
+        attestations: &[(Pubkey, Attestation)],
+        sla: Pubkey,
+        period: u64,
+        attesters: &[Pubkey],
+        period_end: u128,
+        now: i64,
+    ) -> Result<Vec<Option<Decimal>>> {
+        let mut results = vec![None; attesters.len()];
+        for (signer, attestation) in attestations {
+            if attestation.sla != sla || attestation.period != period {
+                continue;
+            }
+            let index = match attesters.iter().position(|attester| attester == signer) {
+                Some(index) => index,
+                None => continue,
+            };
+            require!(results[index].is_none(), FeedErrorCode::DuplicateOracle);
+            if attestation.timestamp as u128 <= period_end || attestation.timestamp > now {
+                msg!("skipping attestation of {}", signer);
+                continue;
+            }
+            results[index] = Some(attestation.sli.to_decimal());
+        }
+        Ok(results)
+    }
+}
+
+/// Returns the signer and the message of each signature of an ed25519 instruction
+///
+/// only the signatures with their public key and message in the instruction itself are supported
+fn parse_ed25519_instruction(data: &[u8]) -> Result<Vec<(Pubkey, &[u8])>> {
+    require_gte!(
+        data.len(),
+        ED25519_HEADER_LEN,
+        FeedErrorCode::InvalidAttestation
+    );
+    let n_signatures = data[0] as usize;
+    let mut signatures = Vec::with_capacity(n_signatures);
+
+    for i in 0..n_signatures {
+        let start = ED25519_HEADER_LEN + i * ED25519_OFFSETS_LEN;
+        let offsets = data
+            .get(start..start + ED25519_OFFSETS_LEN)
+            .ok_or(FeedErrorCode::InvalidAttestation)?;
+        let read_u16 = |at: usize| u16::from_le_bytes([offsets[at], offsets[at + 1]]);
+
+        let signature_instruction_index = read_u16(2);
+        let public_key_offset = read_u16(4) as usize;
+        let public_key_instruction_index = read_u16(6);
+        let message_offset = read_u16(8) as usize;
+        let message_size = read_u16(10) as usize;
+        let message_instruction_index = read_u16(12);
+        require!(
+            signature_instruction_index == CURRENT_INSTRUCTION
+                && public_key_instruction_index == CURRENT_INSTRUCTION
+                && message_instruction_index == CURRENT_INSTRUCTION,
+            FeedErrorCode::InvalidAttestation
+        );
+
+        let public_key: [u8; 32] = data
+            .get(public_key_offset..public_key_offset + 32)
+            .and_then(|public_key| public_key.try_into().ok())
+            .ok_or(FeedErrorCode::InvalidAttestation)?;
+        let message = data
+            .get(message_offset..message_offset + message_size)
+            .ok_or(FeedErrorCode::InvalidAttestation)?;
+        signatures.push((Pubkey::new_from_array(public_key), message));
+    }
+    Ok(signatures)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// builds the data of an ed25519 instruction like `new_ed25519_instruction` of the solana sdk,
+    /// with a dummy signature as parsing doesn't verify it
+    fn ed25519_instruction(signatures: &[(Pubkey, Vec<u8>)], instruction_index: u16) -> Vec<u8> {
+        let mut data = vec![signatures.len() as u8, 0];
+        let mut payload = vec![];
+        let payload_start = ED25519_HEADER_LEN + signatures.len() * ED25519_OFFSETS_LEN;
+        for (signer, message) in signatures {
+            let public_key_offset = payload_start + payload.len();
+            payload.extend_from_slice(signer.as_ref());
+            let signature_offset = payload_start + payload.len();
+            payload.extend_from_slice(&[0; 64]);
+            let message_offset = payload_start + payload.len();
+            payload.extend_from_slice(message);
+
+            for value in [
+                signature_offset as u16,
+                instruction_index,
+                public_key_offset as u16,
+                instruction_index,
+                message_offset as u16,
+                message.len() as u16,
+                instruction_index,
+            ] {
+                data.extend_from_slice(&value.to_le_bytes());
+            }
+        }
+        data.extend_from_slice(&payload);
+        data
+    }
+
+    fn attestation() -> Attestation {
+        Attestation {
+            sla: Pubkey::new_from_array([7; 32]),
+            period: 3,
+            sli: DslaDecimal::from_decimal(Decimal::new(9995, 2)),
+            timestamp: 1_700_000_000,
+        }
+    }
+
+    #[test]
+    fn attestation_len() {
+        assert_eq!(attestation().try_to_vec().unwrap().len(), Attestation::LEN);
+    }
+    #[test]
+    fn results_in_order_of_attesters() {
+        let attesters = [
+            Pubkey::new_from_array([1; 32]),
+            Pubkey::new_from_array([2; 32]),
+            Pubkey::new_from_array([3; 32]),
+        ];
+        let other_period = Attestation {
+            period: 4,
+            ..attestation()
+        };
+        let too_early = Attestation {
+            timestamp: 1_600_000_000,
+            ..attestation()
+        };
+        let attestations = [
+            (attesters[2], attestation()),
+            (Pubkey::new_from_array([9; 32]), attestation()),
+            (attesters[0], other_period),
+            (attesters[1], too_early),
+        ];
+        let sli = attestation().sli.to_decimal();
+        assert_eq!(
+            Attestation::results(
+                &attestations,
+                attestation().sla,
+                3,
+                &attesters,
+                1_650_000_000,
+                1_700_000_000
+            )
+            .unwrap(),
+            vec![None, None, Some(sli)]
+        );
+        // attestations signed in the future are skipped too
+        assert_eq!(
+            Attestation::results(
+                &attestations,
+                attestation().sla,
+                3,
+                &attesters,
+                1_650_000_000,
+                1_699_999_999
+            )
+            .unwrap(),
+            vec![None, None, None]
+        );
+    }
+    #[test]
+    fn results_duplicate_attestation() {
+        let attester = Pubkey::new_from_array([1; 32]);
+        let attestations = [(attester, attestation()), (attester, attestation())];
+        assert_eq!(
+            Attestation::results(
+                &attestations,
+                attestation().sla,
+                3,
+                &[attester],
+                1_650_000_000,
+                1_700_000_000
+            ),
+            err!(FeedErrorCode::DuplicateOracle)
+        );
+    }
+    #[test]
+    fn parse_ed25519_signatures() {
+        let first = Pubkey::new_from_array([1; 32]);
+        let second = Pubkey::new_from_array([2; 32]);
+        let message = attestation().try_to_vec().unwrap();
+        let data = ed25519_instruction(
+            &[(first, message.clone()), (second, b"other".to_vec())],
+            CURRENT_INSTRUCTION,
+        );
+
+        let signatures = parse_ed25519_instruction(&data).unwrap();
+        assert_eq!(signatures.len(), 2);
+        assert_eq!(signatures[0], (first, &message[..]));
+        assert_eq!(signatures[1], (second, &b"other"[..]));
+        assert_eq!(
+            Attestation::try_from_slice(signatures[0].1).unwrap(),
+            attestation()
+        );
+    }
+    #[test]
+    fn parse_ed25519_data_in_other_instruction() {
+        let message = attestation().try_to_vec().unwrap();
+        let data = ed25519_instruction(&[(Pubkey::new_from_array([1; 32]), message)], 0);
+        assert_eq!(
+            parse_ed25519_instruction(&data),
+            err!(FeedErrorCode::InvalidAttestation)
+        );
+    }
+    #[test]
+    fn parse_ed25519_truncated() {
+        let message = attestation().try_to_vec().unwrap();
+        let data = ed25519_instruction(
+            &[(Pubkey::new_from_array([1; 32]), message)],
+            CURRENT_INSTRUCTION,
+        );
+        assert_eq!(
+            parse_ed25519_instruction(&data[..data.len() - 1]),
+            err!(FeedErrorCode::InvalidAttestation)
+        );
+        assert_eq!(
+            parse_ed25519_instruction(&[]),
+            err!(FeedErrorCode::InvalidAttestation)
+        );
+    }
+}
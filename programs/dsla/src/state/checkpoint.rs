@@ -0,0 +1,116 @@
+use anchor_lang::prelude::*;
+
+use super::Sla;
+use crate::constants::CHECKPOINT_SEED;
+
+/// snapshot of the pools and token supplies of an SLA when one of its periods finished,
+/// the UT and PT held then were the ones exposed to the period, see `checkpoint_period`
+///
+/// the period is settled from the user pool of its checkpoint, and until then the stakes and withdrawals
+/// are priced as if it moved its `max_transfer` against them, see `Sla::pending_transfer`
+#[account]
+#[derive(Debug, PartialEq, Eq)]
+pub struct PeriodCheckpoint {
+    /// account that paid the rent of the checkpoint, it gets it back with `close_checkpoint`
+    pub payer: Pubkey,
+    /// the checkpointed period
+    pub period: u64,
+    /// amount of tokens in the provider pool when the period was checkpointed
+    pub provider_pool_size: u128,
+    /// amount of tokens in the user pool when the period was checkpointed
+    pub user_pool_size: u128,
+    /// user token supply when the period was checkpointed
+    pub ut_supply: u128,
+    /// provider token supply when the period was checkpointed
+    pub pt_supply: u128,
+    /// max amount of tokens the settlement of the period can move between the pools,
+    /// 0 if it was already settled when it was checkpointed
+    pub max_transfer: u128,
+}
+
+impl PeriodCheckpoint {
+    pub const LEN: usize = 8 // discriminator
+    + 32 // payer
+    + 8 // period
+    + 16 // provider_pool_size
+    + 16 // user_pool_size
+    + 16 // ut_supply
+    + 16 // pt_supply
+    + 16 // max_transfer
+    ;
+
+    /// Returns the checkpoint of `period` with the current pools and supplies of the `sla`
+    ///
+    /// # Arguments
+    ///
+    /// * `payer` - the account paying the rent of the checkpoint
+    /// * `period` - the finished period to checkpoint
+    /// * `settled` - whether the period is already settled, it can't move tokens anymore then
+    pub fn new(payer: Pubkey, period: u64, sla: &Sla, settled: bool) -> Result<Self> {
+        let max_transfer = if settled {
+            0
+        } else {
+            let periods_left = sla
+                .period_data
+                .n_periods
+                .checked_sub(period as u32)
+                .ok_or(crate::errors::ErrorCode::InvalidPeriodId)?;
+            sla.max_transfer(sla.user_pool_size, periods_left as usize)?
+        };
+        Ok(Self {
+            payer,
+            period,
+            provider_pool_size: sla.provider_pool_size,
+            user_pool_size: sla.user_pool_size,
+            ut_supply: sla.ut_supply,
+            pt_supply: sla.pt_supply,
+            max_transfer,
+        })
+    }
+
+    /// Returns the address of the checkpoint of `period` of the SLA `sla`
+    pub fn address(sla: &Pubkey, period: u64) -> Pubkey {
+        Pubkey::find_program_address(
+            &[
+                CHECKPOINT_SEED.as_bytes(),
+                sla.as_ref(),
+                &period.to_le_bytes(),
+            ],
+            &crate::ID,
+        )
+        .0
+    }
+
+    /// Returns the checkpoint of `period` of the SLA `sla` stored in `account`,
+    /// for the instructions that take the checkpoints as remaining accounts
+    pub fn load(account: &AccountInfo, sla: &Pubkey, period: u64) -> Result<Self> {
+        require_keys_eq!(
+            account.key(),
+            PeriodCheckpoint::address(sla, period),
+            anchor_lang::error::ErrorCode::ConstraintSeeds
+        );
+        Ok(Account::<PeriodCheckpoint>::try_from(account)?.into_inner())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checkpoint_len() {
+        let checkpoint = PeriodCheckpoint {
+            payer: Pubkey::default(),
+            period: u64::MAX,
+            provider_pool_size: u128::MAX,
+            user_pool_size: u128::MAX,
+            ut_supply: u128::MAX,
+            pt_supply: u128::MAX,
+            max_transfer: u128::MAX,
+        };
+        assert_eq!(
+            checkpoint.try_to_vec().unwrap().len() + 8,
+            PeriodCheckpoint::LEN
+        );
+    }
+}
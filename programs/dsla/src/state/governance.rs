@@ -1,10 +1,16 @@
 use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
 
 use super::DslaDecimal;
+use crate::errors::{ErrorCode, FeedErrorCode};
 
 /// collection for all the parametric Governances one account for all SLAs
 #[account]
 pub struct Governance {
+    /// layout version of the account, see `Governance::migrate`
+    pub version: u8,
     /// amount of dsla to be deposited by the sla_deployer to deploy the sla for each period
     pub dsla_deposit_by_period: u64,
     /// amount of dsla deposit by period to be given to the platform
@@ -19,10 +25,60 @@ pub struct Governance {
     pub protocol_rewards_rate: DslaDecimal,
     /// max leverage allowed in a DSLA
     pub max_leverage: DslaDecimal,
+    /// oracle max staleness in seconds used by SLAs that don't set their own
+    pub default_max_staleness: u32,
+    /// highest oracle max staleness in seconds an SLA can set
+    pub max_staleness_limit: u32,
+    /// oracle max confidence interval used by SLAs that don't set their own
+    pub default_max_confidence_interval: DslaDecimal,
+    /// highest oracle max confidence interval an SLA can set
+    pub max_confidence_interval_limit: DslaDecimal,
+    /// number of seconds after the end of a period during which every attester needs to attest its SLI,
+    /// the attesters still missing after it count against the quorum
+    pub attestation_window: u32,
+    /// number of seconds an SLI proposed with optimistic validation can be disputed
+    pub dispute_window: u32,
+    /// amount of dsla to be bonded by the validator proposing an SLI
+    pub proposal_bond: u64,
+    /// amount of dsla to be bonded by the challenger disputing a proposed SLI
+    pub dispute_bond: u64,
+    /// account settling the disputed SLIs
+    pub arbiter: Pubkey,
+    /// account receiving the protocol fees in its associated token accounts
+    pub protocol_treasury: Pubkey,
+    /// account managing the governance parameters
+    pub governance_authority: Pubkey,
+    /// account the governance authority is being transferred to, until it accepts it
+    pub pending_governance_authority: Option<Pubkey>,
+    /// mint of the DSLA token the deposits, rewards and bonds are paid in
+    pub dsla_mint: Pubkey,
+    /// number of seconds after the end of an SLA before the deposit of its unverified periods can be reclaimed
+    pub deposit_reclaim_grace_period: u32,
+    /// who gets the reclaimed deposit of the unverified periods
+    pub deposit_reclaim_policy: DepositReclaimPolicy,
+    /// percentage of the DSLA deposit paid to the protocol when an SLA is cancelled
+    pub sla_cancellation_fee_rate: DslaDecimal,
+    /// account that can pause and unpause the protocol and each SLA in an emergency
+    pub guardian: Pubkey,
+    /// whether staking and validation are paused for all SLAs
+    pub paused: bool,
+}
+
+/// who gets the DSLA deposit of the periods of an SLA that were never verified
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, PartialEq, Eq, Clone, Copy)]
+pub enum DepositReclaimPolicy {
+    /// the deployer of the SLA who paid the deposit
+    Deployer,
+    /// the protocol treasury
+    ProtocolTreasury,
 }
 
 impl Governance {
+    /// layout version of the accounts initialized or migrated by this program
+    pub const VERSION: u8 = 1;
+
     pub const LEN: usize = 8  // discriminator
+    + 1 // version
     + 8 // dsla_deposit_by_period
     + 8 // dsla_protocol_reward
     + 8 // dsla_validator_reward
@@ -30,5 +86,775 @@ impl Governance {
     + 12 // sla_deployer_rewards_rate
     + 12 // protocol_rewards_rate
     + 12  // max_leverage
+    + 4 // default_max_staleness
+    + 4 // max_staleness_limit
+    + 12 // default_max_confidence_interval
+    + 12 // max_confidence_interval_limit
+    + 4 // attestation_window
+    + 4 // dispute_window
+    + 8 // proposal_bond
+    + 8 // dispute_bond
+    + 32 // arbiter
+    + 32 // protocol_treasury
+    + 32 // governance_authority
+    + 1 + 32 // pending_governance_authority
+    + 32 // dsla_mint
+    + 4 // deposit_reclaim_grace_period
+    + 1 // deposit_reclaim_policy
+    + DslaDecimal::LEN // sla_cancellation_fee_rate
+    + 32 // guardian
+    + 1 // paused
+    + 14 // reserved for new parameters, zeroed after the serialized fields
+    ;
+}
+
+impl Governance {
+    /// validates the `parameters` and sets them
+    pub fn set_parameters(&mut self, parameters: &GovernanceParameters) -> Result<()> {
+        parameters.validate()?;
+        self.dsla_deposit_by_period = parameters.dsla_deposit_by_period;
+        self.dsla_protocol_reward = parameters.dsla_protocol_reward;
+        self.dsla_validator_reward = parameters.dsla_validator_reward;
+        self.dsla_burned_by_verification = parameters.dsla_burned_by_verification;
+        self.sla_deployer_rewards_rate = parameters.sla_deployer_rewards_rate;
+        self.protocol_rewards_rate = parameters.protocol_rewards_rate;
+        self.max_leverage = parameters.max_leverage;
+        self.default_max_staleness = parameters.default_max_staleness;
+        self.max_staleness_limit = parameters.max_staleness_limit;
+        self.default_max_confidence_interval = parameters.default_max_confidence_interval;
+        self.max_confidence_interval_limit = parameters.max_confidence_interval_limit;
+        self.attestation_window = parameters.attestation_window;
+        self.dispute_window = parameters.dispute_window;
+        self.proposal_bond = parameters.proposal_bond;
+        self.dispute_bond = parameters.dispute_bond;
+        self.arbiter = parameters.arbiter;
+        self.protocol_treasury = parameters.protocol_treasury;
+        self.deposit_reclaim_grace_period = parameters.deposit_reclaim_grace_period;
+        self.deposit_reclaim_policy = parameters.deposit_reclaim_policy;
+        self.sla_cancellation_fee_rate = parameters.sla_cancellation_fee_rate;
+        self.guardian = parameters.guardian;
+        Ok(())
+    }
+
+    /// Returns the oracle max staleness and max confidence interval of an SLA
+    /// using the governance defaults for the settings that are not set
+    ///
+    /// # Arguments
+    ///
+    /// * `max_staleness` - max staleness in seconds requested by the SLA deployer
+    /// * `max_confidence_interval` - max confidence interval requested by the SLA deployer
+    pub fn oracle_settings(
+        &self,
+        max_staleness: Option<u32>,
+        max_confidence_interval: Option<DslaDecimal>,
+    ) -> Result<(u32, DslaDecimal)> {
+        let max_staleness = max_staleness.unwrap_or(self.default_max_staleness);
+        require!(
+            max_staleness > 0 && max_staleness <= self.max_staleness_limit,
+            FeedErrorCode::InvalidMaxStaleness
+        );
+
+        let max_confidence_interval =
+            max_confidence_interval.unwrap_or(self.default_max_confidence_interval);
+        require!(
+            max_confidence_interval.to_decimal() >= Decimal::ZERO
+                && max_confidence_interval.to_decimal()
+                    <= self.max_confidence_interval_limit.to_decimal(),
+            FeedErrorCode::InvalidMaxConfidenceInterval
+        );
+        Ok((max_staleness, max_confidence_interval))
+    }
+
+    /// checks that the `leverage` of a new SLA is positive and not higher than `max_leverage`
+    pub fn check_leverage(&self, leverage: DslaDecimal) -> Result<()> {
+        require!(
+            leverage.to_decimal() > Decimal::ZERO
+                && leverage.to_decimal() <= self.max_leverage.to_decimal(),
+            ErrorCode::InvalidLeverage
+        );
+        Ok(())
+    }
+
+    /// Returns the last unix timestamp an SLI proposed at `proposed_at` can be disputed at
+    pub fn dispute_deadline(&self, proposed_at: i64) -> Result<i64> {
+        proposed_at
+            .checked_add(self.dispute_window as i64)
+            .ok_or_else(|| error!(ErrorCode::CheckedOperationOverflow))
+    }
+
+    /// Returns the last unix timestamp a period ending at `period_end` waits for every attester at
+    pub fn attestation_deadline(&self, period_end: u128) -> Result<u128> {
+        period_end
+            .checked_add(self.attestation_window as u128)
+            .ok_or_else(|| error!(ErrorCode::CheckedOperationOverflow))
+    }
+
+    /// Returns the owner of the token account the reclaimed deposit of an SLA deployed by `sla_deployer` goes to
+    pub fn deposit_reclaim_destination(&self, sla_deployer: Pubkey) -> Pubkey {
+        match self.deposit_reclaim_policy {
+            DepositReclaimPolicy::Deployer => sla_deployer,
+            DepositReclaimPolicy::ProtocolTreasury => self.protocol_treasury,
+        }
+    }
+
+    /// Returns the part of the DSLA `deposit` of a cancelled SLA paid to the protocol
+    pub fn sla_cancellation_fee(&self, deposit: u64) -> Result<u64> {
+        Decimal::from(deposit)
+            .checked_mul(self.sla_cancellation_fee_rate.to_decimal())
+            .ok_or(ErrorCode::CheckedOperationOverflow)?
+            .floor()
+            .to_u64()
+            .ok_or_else(|| error!(ErrorCode::DecimalConversionError))
+    }
+
+    /// Returns a governance of the current layout with `parameters`, managed by `governance_authority`
+    pub fn new(
+        parameters: &GovernanceParameters,
+        governance_authority: Pubkey,
+        dsla_mint: Pubkey,
+    ) -> Result<Governance> {
+        parameters.validate()?;
+        Ok(Governance {
+            version: Governance::VERSION,
+            dsla_deposit_by_period: parameters.dsla_deposit_by_period,
+            dsla_protocol_reward: parameters.dsla_protocol_reward,
+            dsla_validator_reward: parameters.dsla_validator_reward,
+            dsla_burned_by_verification: parameters.dsla_burned_by_verification,
+            sla_deployer_rewards_rate: parameters.sla_deployer_rewards_rate,
+            protocol_rewards_rate: parameters.protocol_rewards_rate,
+            max_leverage: parameters.max_leverage,
+            default_max_staleness: parameters.default_max_staleness,
+            max_staleness_limit: parameters.max_staleness_limit,
+            default_max_confidence_interval: parameters.default_max_confidence_interval,
+            max_confidence_interval_limit: parameters.max_confidence_interval_limit,
+            attestation_window: parameters.attestation_window,
+            dispute_window: parameters.dispute_window,
+            proposal_bond: parameters.proposal_bond,
+            dispute_bond: parameters.dispute_bond,
+            arbiter: parameters.arbiter,
+            protocol_treasury: parameters.protocol_treasury,
+            governance_authority,
+            pending_governance_authority: None,
+            dsla_mint,
+            deposit_reclaim_grace_period: parameters.deposit_reclaim_grace_period,
+            deposit_reclaim_policy: parameters.deposit_reclaim_policy,
+            sla_cancellation_fee_rate: parameters.sla_cancellation_fee_rate,
+            guardian: parameters.guardian,
+            paused: false,
+        })
+    }
+
+    /// Returns the governance stored in the account `data` upgraded to the current layout
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - the data of a governance account of any layout version older than the current one
+    /// * `parameters` - the values of the parameters the `GovernanceV0` layout doesn't have
+    /// * `upgrade_authority` - the program upgrade authority, it managed the `GovernanceV0` accounts
+    /// * `dsla_mint` - the DSLA mint of the protocol, the `GovernanceV0` layout doesn't have it
+    pub fn migrate(
+        data: &[u8],
+        parameters: &GovernanceParameters,
+        upgrade_authority: Pubkey,
+        dsla_mint: Pubkey,
+    ) -> Result<Governance> {
+        require!(
+            data.len() >= 8 && data[..8] == Governance::discriminator(),
+            anchor_lang::error::ErrorCode::AccountDiscriminatorMismatch
+        );
+        // accounts created before versioning have exactly the size of the unversioned layout
+        if data.len() == GovernanceV0::LEN {
+            let governance = GovernanceV0::deserialize(&mut &data[8..])?;
+            return governance.migrate(parameters, upgrade_authority, dsla_mint);
+        }
+        let governance = Governance::try_deserialize(&mut &data[..])?;
+        require_gt!(
+            Governance::VERSION,
+            governance.version,
+            ErrorCode::GovernanceAlreadyMigrated
+        );
+        Ok(governance)
+    }
+}
+
+/// layout of the `Governance` accounts before they were versioned, only used to migrate them
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, PartialEq, Eq, Clone)]
+pub struct GovernanceV0 {
+    pub dsla_deposit_by_period: u64,
+    pub dsla_protocol_reward: u64,
+    pub dsla_validator_reward: u64,
+    pub dsla_burned_by_verification: u64,
+    pub sla_deployer_rewards_rate: DslaDecimal,
+    pub protocol_rewards_rate: DslaDecimal,
+    pub max_leverage: DslaDecimal,
+}
+
+impl GovernanceV0 {
+    pub const LEN: usize = 8  // discriminator
+    + 8 // dsla_deposit_by_period
+    + 8 // dsla_protocol_reward
+    + 8 // dsla_validator_reward
+    + 8 // dsla_burned_by_verification
+    + DslaDecimal::LEN // sla_deployer_rewards_rate
+    + DslaDecimal::LEN // protocol_rewards_rate
+    + DslaDecimal::LEN // max_leverage
     ;
+
+    /// Returns the governance in the current layout, it keeps the parameters of this layout
+    /// and takes the other ones from `parameters`
+    pub fn migrate(
+        self,
+        parameters: &GovernanceParameters,
+        upgrade_authority: Pubkey,
+        dsla_mint: Pubkey,
+    ) -> Result<Governance> {
+        let parameters = GovernanceParameters {
+            dsla_deposit_by_period: self.dsla_deposit_by_period,
+            dsla_protocol_reward: self.dsla_protocol_reward,
+            dsla_validator_reward: self.dsla_validator_reward,
+            dsla_burned_by_verification: self.dsla_burned_by_verification,
+            sla_deployer_rewards_rate: self.sla_deployer_rewards_rate,
+            protocol_rewards_rate: self.protocol_rewards_rate,
+            max_leverage: self.max_leverage,
+            ..parameters.clone()
+        };
+        Governance::new(&parameters, upgrade_authority, dsla_mint)
+    }
+}
+
+/// all the parameters of the `Governance` that can be changed, see `Governance` for their meaning
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, PartialEq, Eq, Clone)]
+pub struct GovernanceParameters {
+    pub dsla_deposit_by_period: u64,
+    pub dsla_protocol_reward: u64,
+    pub dsla_validator_reward: u64,
+    pub dsla_burned_by_verification: u64,
+    pub sla_deployer_rewards_rate: DslaDecimal,
+    pub protocol_rewards_rate: DslaDecimal,
+    pub max_leverage: DslaDecimal,
+    pub default_max_staleness: u32,
+    pub max_staleness_limit: u32,
+    pub default_max_confidence_interval: DslaDecimal,
+    pub max_confidence_interval_limit: DslaDecimal,
+    pub attestation_window: u32,
+    pub dispute_window: u32,
+    pub proposal_bond: u64,
+    pub dispute_bond: u64,
+    pub arbiter: Pubkey,
+    pub protocol_treasury: Pubkey,
+    pub deposit_reclaim_grace_period: u32,
+    pub deposit_reclaim_policy: DepositReclaimPolicy,
+    pub sla_cancellation_fee_rate: DslaDecimal,
+    pub guardian: Pubkey,
+}
+
+impl GovernanceParameters {
+    pub const LEN: usize = 8 // dsla_deposit_by_period
+    + 8 // dsla_protocol_reward
+    + 8 // dsla_validator_reward
+    + 8 // dsla_burned_by_verification
+    + DslaDecimal::LEN // sla_deployer_rewards_rate
+    + DslaDecimal::LEN // protocol_rewards_rate
+    + DslaDecimal::LEN // max_leverage
+    + 4 // default_max_staleness
+    + 4 // max_staleness_limit
+    + DslaDecimal::LEN // default_max_confidence_interval
+    + DslaDecimal::LEN // max_confidence_interval_limit
+    + 4 // attestation_window
+    + 4 // dispute_window
+    + 8 // proposal_bond
+    + 8 // dispute_bond
+    + 32 // arbiter
+    + 32 // protocol_treasury
+    + 4 // deposit_reclaim_grace_period
+    + 1 // deposit_reclaim_policy
+    + DslaDecimal::LEN // sla_cancellation_fee_rate
+    + 32 // guardian
+    ;
+
+    /// checks that the parameters are consistent with each other
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            Some(self.dsla_deposit_by_period)
+                == self
+                    .dsla_burned_by_verification
+                    .checked_add(self.dsla_validator_reward)
+                    .and_then(|sum| sum.checked_add(self.dsla_protocol_reward)),
+            ErrorCode::NonValidGovernanceParameters
+        );
+        require!(
+            self.default_max_staleness > 0
+                && self.default_max_staleness <= self.max_staleness_limit,
+            ErrorCode::NonValidGovernanceParameters
+        );
+        require!(
+            self.default_max_confidence_interval.to_decimal() >= Decimal::ZERO
+                && self.default_max_confidence_interval.to_decimal()
+                    <= self.max_confidence_interval_limit.to_decimal(),
+            ErrorCode::NonValidGovernanceParameters
+        );
+        require!(
+            self.dispute_window > 0,
+            ErrorCode::NonValidGovernanceParameters
+        );
+        // the periods attested by only part of the attesters can be validated before their deposit is reclaimed
+        require!(
+            self.attestation_window > 0
+                && self.attestation_window <= self.deposit_reclaim_grace_period,
+            ErrorCode::NonValidGovernanceParameters
+        );
+        // the withdrawal fees can't be higher than the withdrawn amount
+        let sla_deployer_rewards_rate = self.sla_deployer_rewards_rate.to_decimal();
+        let protocol_rewards_rate = self.protocol_rewards_rate.to_decimal();
+        require!(
+            sla_deployer_rewards_rate >= Decimal::ZERO
+                && protocol_rewards_rate >= Decimal::ZERO
+                && sla_deployer_rewards_rate
+                    .checked_add(protocol_rewards_rate)
+                    .is_some_and(|sum| sum < Decimal::ONE),
+            ErrorCode::InvalidRewardsRates
+        );
+        require!(
+            self.max_leverage.to_decimal() > Decimal::ZERO,
+            ErrorCode::InvalidLeverage
+        );
+        require!(
+            self.sla_cancellation_fee_rate.to_decimal() >= Decimal::ZERO
+                && self.sla_cancellation_fee_rate.to_decimal() <= Decimal::ONE,
+            ErrorCode::NonValidGovernanceParameters
+        );
+        Ok(())
+    }
+}
+
+/// change of the `Governance` parameters waiting for its timelock, only one change can be pending at a time
+#[account]
+pub struct GovernanceChange {
+    /// account that proposed the change and paid for this account
+    pub proposer: Pubkey,
+    /// the new parameters
+    pub parameters: GovernanceParameters,
+    /// unix timestamp the change was proposed at
+    pub proposed_at: i64,
+    /// first unix timestamp the change can be executed at
+    pub executable_at: i64,
+}
+
+impl GovernanceChange {
+    pub const LEN: usize = 8 // discriminator
+    + 32 // proposer
+    + GovernanceParameters::LEN // parameters
+    + 8 // proposed_at
+    + 8 // executable_at
+    ;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn governance() -> Governance {
+        Governance {
+            version: Governance::VERSION,
+            dsla_deposit_by_period: 0,
+            dsla_protocol_reward: 0,
+            dsla_validator_reward: 0,
+            dsla_burned_by_verification: 0,
+            sla_deployer_rewards_rate: DslaDecimal::from_decimal(Decimal::ZERO),
+            protocol_rewards_rate: DslaDecimal::from_decimal(Decimal::ZERO),
+            max_leverage: DslaDecimal::from_decimal(Decimal::ONE),
+            default_max_staleness: 300,
+            max_staleness_limit: 3600,
+            default_max_confidence_interval: DslaDecimal::from_decimal(Decimal::new(1, 0)),
+            max_confidence_interval_limit: DslaDecimal::from_decimal(Decimal::new(100, 0)),
+            attestation_window: 3600,
+            dispute_window: 86400,
+            proposal_bond: 100,
+            dispute_bond: 100,
+            arbiter: Pubkey::default(),
+            protocol_treasury: Pubkey::default(),
+            governance_authority: Pubkey::default(),
+            pending_governance_authority: None,
+            dsla_mint: Pubkey::default(),
+            deposit_reclaim_grace_period: 0,
+            deposit_reclaim_policy: DepositReclaimPolicy::Deployer,
+            sla_cancellation_fee_rate: DslaDecimal::from_decimal(Decimal::ZERO),
+            guardian: Pubkey::default(),
+            paused: false,
+        }
+    }
+
+    #[test]
+    fn oracle_settings_defaults() {
+        assert_eq!(
+            governance().oracle_settings(None, None).unwrap(),
+            (300, DslaDecimal::from_decimal(Decimal::new(1, 0)))
+        );
+    }
+    #[test]
+    fn oracle_settings_within_limits() {
+        let max_confidence_interval = DslaDecimal::from_decimal(Decimal::new(100, 0));
+        assert_eq!(
+            governance()
+                .oracle_settings(Some(3600), Some(max_confidence_interval))
+                .unwrap(),
+            (3600, max_confidence_interval)
+        );
+    }
+    #[test]
+    fn oracle_settings_staleness_out_of_limits() {
+        assert_eq!(
+            governance().oracle_settings(Some(3601), None),
+            err!(FeedErrorCode::InvalidMaxStaleness)
+        );
+        assert_eq!(
+            governance().oracle_settings(Some(0), None),
+            err!(FeedErrorCode::InvalidMaxStaleness)
+        );
+    }
+    #[test]
+    fn oracle_settings_confidence_interval_out_of_limits() {
+        assert_eq!(
+            governance()
+                .oracle_settings(None, Some(DslaDecimal::from_decimal(Decimal::new(1001, 1)))),
+            err!(FeedErrorCode::InvalidMaxConfidenceInterval)
+        );
+        assert_eq!(
+            governance()
+                .oracle_settings(None, Some(DslaDecimal::from_decimal(Decimal::new(-1, 0)))),
+            err!(FeedErrorCode::InvalidMaxConfidenceInterval)
+        );
+    }
+    #[test]
+    fn dispute_deadline() {
+        assert_eq!(governance().dispute_deadline(1000).unwrap(), 87400);
+        assert_eq!(
+            governance().dispute_deadline(i64::MAX),
+            err!(ErrorCode::CheckedOperationOverflow)
+        );
+    }
+    #[test]
+    fn attestation_deadline() {
+        assert_eq!(governance().attestation_deadline(1000).unwrap(), 4600);
+        assert_eq!(
+            governance().attestation_deadline(u128::MAX),
+            err!(ErrorCode::CheckedOperationOverflow)
+        );
+    }
+    fn parameters() -> GovernanceParameters {
+        GovernanceParameters {
+            dsla_deposit_by_period: 6,
+            dsla_protocol_reward: 1,
+            dsla_validator_reward: 2,
+            dsla_burned_by_verification: 3,
+            sla_deployer_rewards_rate: DslaDecimal::from_decimal(Decimal::new(1, 2)),
+            protocol_rewards_rate: DslaDecimal::from_decimal(Decimal::new(1, 2)),
+            max_leverage: DslaDecimal::from_decimal(Decimal::new(10, 0)),
+            default_max_staleness: 300,
+            max_staleness_limit: 3600,
+            default_max_confidence_interval: DslaDecimal::from_decimal(Decimal::new(1, 0)),
+            max_confidence_interval_limit: DslaDecimal::from_decimal(Decimal::new(100, 0)),
+            attestation_window: 3600,
+            dispute_window: 86400,
+            proposal_bond: 100,
+            dispute_bond: 100,
+            arbiter: Pubkey::default(),
+            protocol_treasury: Pubkey::new_from_array([1; 32]),
+            deposit_reclaim_grace_period: 604800,
+            deposit_reclaim_policy: DepositReclaimPolicy::ProtocolTreasury,
+            sla_cancellation_fee_rate: DslaDecimal::from_decimal(Decimal::new(5, 2)),
+            guardian: Pubkey::new_from_array([6; 32]),
+        }
+    }
+    #[test]
+    fn deposit_reclaim_destination() {
+        let deployer = Pubkey::new_from_array([2; 32]);
+        let mut governance = governance();
+        assert_eq!(governance.deposit_reclaim_destination(deployer), deployer);
+        governance.set_parameters(&parameters()).unwrap();
+        assert_eq!(governance.deposit_reclaim_grace_period, 604800);
+        assert_eq!(
+            governance.deposit_reclaim_destination(deployer),
+            Pubkey::new_from_array([1; 32])
+        );
+    }
+    #[test]
+    fn sla_cancellation_fee() {
+        let mut governance = governance();
+        assert_eq!(governance.sla_cancellation_fee(1000).unwrap(), 0);
+        governance.set_parameters(&parameters()).unwrap();
+        assert_eq!(governance.sla_cancellation_fee(1000).unwrap(), 50);
+        assert_eq!(governance.sla_cancellation_fee(39).unwrap(), 1);
+    }
+    #[test]
+    fn parameters_invalid_cancellation_fee_rate() {
+        let parameters = GovernanceParameters {
+            sla_cancellation_fee_rate: DslaDecimal::from_decimal(Decimal::new(101, 2)),
+            ..parameters()
+        };
+        assert_eq!(
+            parameters.validate(),
+            err!(ErrorCode::NonValidGovernanceParameters)
+        );
+    }
+    #[test]
+    fn set_parameters() {
+        let mut governance = governance();
+        governance.set_parameters(&parameters()).unwrap();
+        assert_eq!(governance.dsla_deposit_by_period, 6);
+        assert_eq!(governance.dsla_burned_by_verification, 3);
+        assert_eq!(
+            governance.max_leverage,
+            DslaDecimal::from_decimal(Decimal::new(10, 0))
+        );
+        assert_eq!(
+            governance.protocol_treasury,
+            Pubkey::new_from_array([1; 32])
+        );
+        assert_eq!(governance.guardian, Pubkey::new_from_array([6; 32]));
+    }
+    #[test]
+    fn parameters_len() {
+        assert_eq!(
+            parameters().try_to_vec().unwrap().len(),
+            GovernanceParameters::LEN
+        );
+    }
+    #[test]
+    fn parameters_deposit_not_split() {
+        let parameters = GovernanceParameters {
+            dsla_deposit_by_period: 7,
+            ..parameters()
+        };
+        assert_eq!(
+            parameters.validate(),
+            err!(ErrorCode::NonValidGovernanceParameters)
+        );
+        let parameters = GovernanceParameters {
+            dsla_burned_by_verification: u64::MAX,
+            ..parameters
+        };
+        assert_eq!(
+            parameters.validate(),
+            err!(ErrorCode::NonValidGovernanceParameters)
+        );
+    }
+    #[test]
+    fn parameters_invalid_oracle_defaults() {
+        let staleness = GovernanceParameters {
+            default_max_staleness: 3601,
+            ..parameters()
+        };
+        assert_eq!(
+            staleness.validate(),
+            err!(ErrorCode::NonValidGovernanceParameters)
+        );
+        let confidence_interval = GovernanceParameters {
+            default_max_confidence_interval: DslaDecimal::from_decimal(Decimal::new(101, 0)),
+            ..parameters()
+        };
+        assert_eq!(
+            confidence_interval.validate(),
+            err!(ErrorCode::NonValidGovernanceParameters)
+        );
+    }
+    #[test]
+    fn parameters_no_dispute_window() {
+        let parameters = GovernanceParameters {
+            dispute_window: 0,
+            ..parameters()
+        };
+        assert_eq!(
+            parameters.validate(),
+            err!(ErrorCode::NonValidGovernanceParameters)
+        );
+    }
+    #[test]
+    fn parameters_invalid_attestation_window() {
+        let no_window = GovernanceParameters {
+            attestation_window: 0,
+            ..parameters()
+        };
+        assert_eq!(
+            no_window.validate(),
+            err!(ErrorCode::NonValidGovernanceParameters)
+        );
+        // the attestations can't be waited for after the deposit of the period is reclaimed
+        let after_grace_period = GovernanceParameters {
+            attestation_window: 604801,
+            ..parameters()
+        };
+        assert_eq!(
+            after_grace_period.validate(),
+            err!(ErrorCode::NonValidGovernanceParameters)
+        );
+    }
+    #[test]
+    fn check_leverage() {
+        let governance = governance();
+        governance
+            .check_leverage(DslaDecimal::from_decimal(Decimal::new(5, 1)))
+            .unwrap();
+        governance
+            .check_leverage(DslaDecimal::from_decimal(Decimal::ONE))
+            .unwrap();
+        assert_eq!(
+            governance.check_leverage(DslaDecimal::from_decimal(Decimal::new(11, 1))),
+            err!(ErrorCode::InvalidLeverage)
+        );
+        assert_eq!(
+            governance.check_leverage(DslaDecimal::from_decimal(Decimal::ZERO)),
+            err!(ErrorCode::InvalidLeverage)
+        );
+    }
+    #[test]
+    fn parameters_invalid_rewards_rates() {
+        let sum_too_high = GovernanceParameters {
+            sla_deployer_rewards_rate: DslaDecimal::from_decimal(Decimal::new(5, 1)),
+            protocol_rewards_rate: DslaDecimal::from_decimal(Decimal::new(5, 1)),
+            ..parameters()
+        };
+        assert_eq!(
+            sum_too_high.validate(),
+            err!(ErrorCode::InvalidRewardsRates)
+        );
+        let negative = GovernanceParameters {
+            sla_deployer_rewards_rate: DslaDecimal::from_decimal(Decimal::new(-1, 2)),
+            ..parameters()
+        };
+        assert_eq!(negative.validate(), err!(ErrorCode::InvalidRewardsRates));
+    }
+    #[test]
+    fn parameters_no_max_leverage() {
+        let parameters = GovernanceParameters {
+            max_leverage: DslaDecimal::from_decimal(Decimal::ZERO),
+            ..parameters()
+        };
+        assert_eq!(parameters.validate(), err!(ErrorCode::InvalidLeverage));
+    }
+    /// serializes `governance` in an account of `len` bytes like anchor does
+    fn account_data<T: AnchorSerialize>(governance: &T, len: usize) -> Vec<u8> {
+        let mut data = Governance::discriminator().to_vec();
+        governance.serialize(&mut data).unwrap();
+        data.resize(len, 0);
+        data
+    }
+    fn governance_v0() -> GovernanceV0 {
+        GovernanceV0 {
+            dsla_deposit_by_period: 6,
+            dsla_protocol_reward: 1,
+            dsla_validator_reward: 2,
+            dsla_burned_by_verification: 3,
+            sla_deployer_rewards_rate: DslaDecimal::from_decimal(Decimal::new(1, 2)),
+            protocol_rewards_rate: DslaDecimal::from_decimal(Decimal::new(2, 2)),
+            max_leverage: DslaDecimal::from_decimal(Decimal::new(10, 0)),
+        }
+    }
+    #[test]
+    fn governance_len() {
+        let governance = Governance {
+            pending_governance_authority: Some(Pubkey::default()),
+            ..governance()
+        };
+        assert_eq!(
+            governance.try_to_vec().unwrap().len() + 8 + 14,
+            Governance::LEN
+        );
+        assert_eq!(
+            governance_v0().try_to_vec().unwrap().len() + 8,
+            GovernanceV0::LEN
+        );
+        assert_eq!(GovernanceV0::LEN, 76);
+    }
+    #[test]
+    fn deserialize_v0_bytes() {
+        // the account as written by the program before versioning
+        let mut data = Governance::discriminator().to_vec();
+        for amount in [6u64, 1, 2, 3] {
+            data.extend_from_slice(&amount.to_le_bytes());
+        }
+        for (mantissa, scale) in [(1i64, 2u32), (2, 2), (10, 0)] {
+            data.extend_from_slice(&mantissa.to_le_bytes());
+            data.extend_from_slice(&scale.to_le_bytes());
+        }
+        assert_eq!(data.len(), GovernanceV0::LEN);
+        assert_eq!(data, account_data(&governance_v0(), GovernanceV0::LEN));
+        assert_eq!(
+            GovernanceV0::deserialize(&mut &data[8..]).unwrap(),
+            governance_v0()
+        );
+    }
+    #[test]
+    fn migrate_v0() {
+        let data = account_data(&governance_v0(), GovernanceV0::LEN);
+        let upgrade_authority = Pubkey::new_from_array([3; 32]);
+        let dsla_mint = Pubkey::new_from_array([5; 32]);
+        let governance =
+            Governance::migrate(&data, &parameters(), upgrade_authority, dsla_mint).unwrap();
+        assert_eq!(governance.version, Governance::VERSION);
+        // the parameters of the unversioned layout are kept
+        assert_eq!(governance.dsla_deposit_by_period, 6);
+        assert_eq!(
+            governance.protocol_rewards_rate,
+            DslaDecimal::from_decimal(Decimal::new(2, 2))
+        );
+        assert_eq!(
+            governance.max_leverage,
+            DslaDecimal::from_decimal(Decimal::new(10, 0))
+        );
+        // the other ones are the given parameters
+        assert_eq!(governance.dispute_window, 86400);
+        assert_eq!(
+            governance.protocol_treasury,
+            Pubkey::new_from_array([1; 32])
+        );
+        assert_eq!(
+            governance.deposit_reclaim_policy,
+            DepositReclaimPolicy::ProtocolTreasury
+        );
+        assert_eq!(governance.guardian, Pubkey::new_from_array([6; 32]));
+        assert_eq!(governance.governance_authority, upgrade_authority);
+        assert_eq!(governance.pending_governance_authority, None);
+        assert_eq!(governance.dsla_mint, dsla_mint);
+        assert!(!governance.paused);
+
+        // the migrated account deserializes with the current layout
+        let migrated = account_data(&governance, Governance::LEN);
+        let deserialized = Governance::try_deserialize(&mut &migrated[..]).unwrap();
+        assert_eq!(
+            deserialized.try_to_vec().unwrap(),
+            governance.try_to_vec().unwrap()
+        );
+    }
+    #[test]
+    fn migrate_v0_invalid_parameters() {
+        let data = account_data(&governance_v0(), GovernanceV0::LEN);
+        let parameters = GovernanceParameters {
+            dispute_window: 0,
+            ..parameters()
+        };
+        assert_eq!(
+            Governance::migrate(&data, &parameters, Pubkey::default(), Pubkey::default()).err(),
+            Some(error!(ErrorCode::NonValidGovernanceParameters))
+        );
+    }
+    #[test]
+    fn migrate_current_version() {
+        let data = account_data(&governance(), Governance::LEN);
+        assert_eq!(
+            Governance::migrate(&data, &parameters(), Pubkey::default(), Pubkey::default()).err(),
+            Some(error!(ErrorCode::GovernanceAlreadyMigrated))
+        );
+    }
+    #[test]
+    fn migrate_other_account() {
+        let mut data = account_data(&governance_v0(), GovernanceV0::LEN);
+        data[0] ^= 1;
+        assert_eq!(
+            Governance::migrate(&data, &parameters(), Pubkey::default(), Pubkey::default()).err(),
+            Some(error!(
+                anchor_lang::error::ErrorCode::AccountDiscriminatorMismatch
+            ))
+        );
+    }
 }
@@ -1,12 +1,15 @@
 use crate::errors::ErrorCode;
 use crate::state::sla::SlaStatus;
 use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
 use std::cmp::Ordering;
 
 /// account to keep track of tokens that need to be locked
 /// rule should be if tokens was staked in the last period should stay staked for at least one full period
 #[account]
 pub struct Lockup {
+    /// layout version of the account, see `Lockup::migrate`
+    pub version: u8,
     pub available_tokens: u64,
     locked_tokens_prev: u64,
     locked_tokens: u64,
@@ -19,9 +22,20 @@ impl Default for Lockup {
 }
 
 impl Lockup {
-    pub const LEN: usize = 8 + 16 + 16 + 16 + 16;
+    /// layout version of the accounts initialized or migrated by this program
+    pub const VERSION: u8 = 1;
+
+    pub const LEN: usize = 8 // discriminator
+    + 1 // version
+    + 8 // available_tokens
+    + 8 // locked_tokens_prev
+    + 8 // locked_tokens
+    + 8 // locked_from_period_id
+    + 32 // reserved for new fields, zeroed after the serialized fields
+    ;
     pub fn new() -> Self {
         Self {
+            version: Self::VERSION,
             available_tokens: 0,
             locked_tokens_prev: 0,
             locked_tokens: 0,
@@ -29,6 +43,30 @@ impl Lockup {
         }
     }
 
+    /// Returns the lockup stored in the account `data` upgraded to the current layout
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - the data of a lockup account of any layout version older than the current one
+    pub fn migrate(data: &[u8]) -> Result<Lockup> {
+        require!(
+            data.len() >= 8 && data[..8] == Lockup::discriminator(),
+            anchor_lang::error::ErrorCode::AccountDiscriminatorMismatch
+        );
+        // accounts initialized before versioning have exactly the size of the unversioned layout
+        if data.len() == LockupV0::LEN {
+            let lockup = LockupV0::deserialize(&mut &data[8..])?;
+            return Ok(lockup.into());
+        }
+        let lockup = Lockup::try_deserialize(&mut &data[..])?;
+        require_gt!(
+            Lockup::VERSION,
+            lockup.version,
+            ErrorCode::LockupAlreadyMigrated
+        );
+        Ok(lockup)
+    }
+
     pub fn update_available_tokens(&mut self, status: SlaStatus) -> Result<()> {
         match status {
             SlaStatus::NotStarted => {}
@@ -123,6 +161,11 @@ impl Lockup {
         Ok(())
     }
 
+    /// whether no tokens are available or locked anymore
+    pub fn is_empty(&self) -> bool {
+        self.available_tokens == 0 && self.locked_tokens_prev == 0 && self.locked_tokens == 0
+    }
+
     pub fn withdraw(&mut self, withdraw_size: u64) -> Result<()> {
         if withdraw_size < 1 {
             return err!(ErrorCode::WithdrawalIsZero);
@@ -135,6 +178,32 @@ impl Lockup {
     }
 }
 
+/// layout of the `Lockup` accounts before they were versioned, only used to migrate them
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, PartialEq, Eq, Clone)]
+pub struct LockupV0 {
+    pub available_tokens: u64,
+    pub locked_tokens_prev: u64,
+    pub locked_tokens: u64,
+    pub locked_from_period_id: u64,
+}
+
+impl LockupV0 {
+    /// size the unversioned accounts were allocated with, it counted 16 bytes for each field
+    pub const LEN: usize = 8 + 16 + 16 + 16 + 16;
+}
+
+impl From<LockupV0> for Lockup {
+    fn from(lockup: LockupV0) -> Self {
+        Lockup {
+            version: Lockup::VERSION,
+            available_tokens: lockup.available_tokens,
+            locked_tokens_prev: lockup.locked_tokens_prev,
+            locked_tokens: lockup.locked_tokens,
+            locked_from_period_id: lockup.locked_from_period_id,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -465,4 +534,65 @@ mod tests {
         assert_eq!(lockup.withdraw(520), Ok(()));
         assert_eq!(lockup.available_tokens, 2480);
     }
+
+    /// serializes `lockup` in an account of `len` bytes like anchor does
+    fn account_data<T: AnchorSerialize>(lockup: &T, len: usize) -> Vec<u8> {
+        let mut data = Lockup::discriminator().to_vec();
+        lockup.serialize(&mut data).unwrap();
+        data.resize(len, 0);
+        data
+    }
+
+    #[test]
+    fn is_empty() {
+        let mut lockup = Lockup::new();
+        assert!(lockup.is_empty());
+        lockup
+            .stake_update(1000, SlaStatus::Active { period_id: 1 })
+            .unwrap();
+        assert!(!lockup.is_empty());
+        lockup.update_available_tokens(SlaStatus::Ended).unwrap();
+        assert!(!lockup.is_empty());
+        lockup.withdraw(1000).unwrap();
+        assert!(lockup.is_empty());
+    }
+
+    #[test]
+    fn lockup_len() {
+        assert_eq!(
+            Lockup::new().try_to_vec().unwrap().len() + 8 + 32,
+            Lockup::LEN
+        );
+    }
+
+    #[test]
+    fn migrate_v0() {
+        let legacy = LockupV0 {
+            available_tokens: 100,
+            locked_tokens_prev: 200,
+            locked_tokens: 300,
+            locked_from_period_id: 4,
+        };
+        let data = account_data(&legacy, LockupV0::LEN);
+        let lockup = Lockup::migrate(&data).unwrap();
+        assert_eq!(lockup.version, Lockup::VERSION);
+        assert_eq!(lockup.available_tokens, 100);
+        assert_eq!(lockup.locked_tokens_prev, 200);
+        assert_eq!(lockup.locked_tokens, 300);
+        assert_eq!(lockup.locked_from_period_id, 4);
+
+        // the migrated account deserializes with the current layout
+        let migrated = account_data(&lockup, Lockup::LEN);
+        let deserialized = Lockup::try_deserialize(&mut &migrated[..]).unwrap();
+        assert_eq!(deserialized.locked_tokens, 300);
+    }
+
+    #[test]
+    fn migrate_current_version() {
+        let data = account_data(&Lockup::new(), Lockup::LEN);
+        assert_eq!(
+            Lockup::migrate(&data).err(),
+            Some(error!(ErrorCode::LockupAlreadyMigrated))
+        );
+    }
 }
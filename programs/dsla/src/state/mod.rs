@@ -1,12 +1,22 @@
+pub mod attestation;
+pub mod checkpoint;
 pub mod governance;
 pub mod lockup;
+pub mod oracle;
+pub mod proposal;
+pub mod pyth_sample;
 pub mod sla;
 pub mod sla_authority;
 pub mod sla_registry;
 pub mod status_registry;
 
+pub use attestation::*;
+pub use checkpoint::*;
 pub use governance::*;
 pub use lockup::*;
+pub use oracle::*;
+pub use proposal::*;
+pub use pyth_sample::*;
 pub use sla::*;
 pub use sla_authority::*;
 pub use sla_registry::*;
@@ -0,0 +1,957 @@
+use crate::constants::PYTH_PROGRAM_ID;
+use crate::errors::{ErrorCode, FeedErrorCode};
+use anchor_lang::prelude::*;
+use pyth_sdk_solana::state::{load_price_account, PriceStatus};
+use rust_decimal::prelude::*;
+use switchboard_v2::{
+    AggregatorAccountData, AggregatorHistoryBuffer, SwitchboardDecimal, SWITCHBOARD_PROGRAM_ID,
+};
+
+/// the oracle network the feeds of an `Sla` belong to
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, PartialEq, Eq, Clone, Copy)]
+pub enum OracleSource {
+    /// switchboard v2 aggregator accounts
+    Switchboard,
+    /// pyth price accounts, they only keep their current and previous price so each period
+    /// is validated from the `PythSample` recorded during its sample window
+    Pyth,
+    /// no feeds, the SLI is proposed by a bonded validator and can be disputed during the
+    /// dispute window of the governance
+    Optimistic,
+    /// public keys of attesters signing the SLI of each period off-chain, see `Attestation`,
+    /// the quorum of the SLA is the number of attesters needed
+    Attestation,
+}
+
+impl OracleSource {
+    pub const LEN: usize = 1;
+
+    /// checks that `oracle` is a feed account of this oracle source, any account can be an attester
+    pub fn check_account(&self, oracle: &AccountInfo) -> Result<()> {
+        let data = oracle.try_borrow_data()?;
+        match self {
+            OracleSource::Switchboard => {
+                require_keys_eq!(
+                    *oracle.owner,
+                    SWITCHBOARD_PROGRAM_ID,
+                    FeedErrorCode::InvalidSwitchboardAccount
+                );
+                AggregatorAccountData::new_from_bytes(&data)
+                    .map_err(|_| error!(FeedErrorCode::InvalidSwitchboardAccount))?;
+            }
+            OracleSource::Pyth => {
+                require_keys_eq!(
+                    *oracle.owner,
+                    PYTH_PROGRAM_ID,
+                    FeedErrorCode::InvalidPythAccount
+                );
+                load_price_account(&data).map_err(|_| error!(FeedErrorCode::InvalidPythAccount))?;
+            }
+            OracleSource::Optimistic => return err!(FeedErrorCode::OptimisticValidation),
+            // attesters are only public keys, they don't need to be accounts
+            OracleSource::Attestation => {}
+        }
+        Ok(())
+    }
+
+    /// Returns the address of the account storing the past results of the `oracle` feed if any
+    pub fn history_buffer(&self, oracle: &AccountInfo) -> Result<Option<Pubkey>> {
+        self.check_account(oracle)?;
+        match self {
+            OracleSource::Switchboard => {
+                let data = oracle.try_borrow_data()?;
+                let history_buffer = AggregatorAccountData::new_from_bytes(&data)?.history_buffer;
+                Ok(Some(history_buffer).filter(|key| *key != Pubkey::default()))
+            }
+            OracleSource::Pyth | OracleSource::Optimistic | OracleSource::Attestation => Ok(None),
+        }
+    }
+
+    /// Returns the result of each oracle feed of an SLA observed in `window` in the order of its `oracle_addresses`,
+    /// `None` for the feeds without a trusted result or that can't be read, they count against the quorum
+    ///
+    /// every feed needs to be passed so that the caller can't leave out the feeds it dislikes
+    ///
+    /// # Arguments
+    ///
+    /// * `oracle_addresses` - the oracle feeds of the SLA
+    /// * `accounts` - the feed accounts in the order of `oracle_addresses`, each feed with a history buffer followed by it
+    /// * `window` - the time window the results need to be observed in
+    /// * `max_confidence_interval` - max confidence interval of the results of the feeds
+    pub fn read_all(
+        &self,
+        oracle_addresses: &[Pubkey],
+        accounts: &[AccountInfo],
+        window: &SampleWindow,
+        max_confidence_interval: Decimal,
+    ) -> Result<Vec<Option<Decimal>>> {
+        let mut accounts = accounts.iter();
+        let mut results = Vec::with_capacity(oracle_addresses.len());
+        for address in oracle_addresses {
+            let oracle = accounts.next().ok_or(FeedErrorCode::MissingOracle)?;
+            require_keys_eq!(*oracle.key, *address, FeedErrorCode::MissingOracle);
+            // a feed that can't be read has no history buffer to pass
+            let history_buffer = match self.history_buffer(oracle) {
+                Ok(Some(history_buffer)) => {
+                    let account = accounts.next().ok_or(FeedErrorCode::InvalidHistoryBuffer)?;
+                    require_keys_eq!(
+                        *account.key,
+                        history_buffer,
+                        FeedErrorCode::InvalidHistoryBuffer
+                    );
+                    Some(account)
+                }
+                Ok(None) | Err(_) => None,
+            };
+            let result = self
+                .read(oracle, history_buffer, window, max_confidence_interval)
+                .unwrap_or_else(|error| {
+                    msg!("cannot read oracle feed {}: {}", oracle.key, error);
+                    None
+                });
+            if result.is_none() {
+                msg!("skipping oracle feed {}", oracle.key);
+            }
+            results.push(result);
+        }
+        require!(accounts.next().is_none(), FeedErrorCode::UnknownOracle);
+        Ok(results)
+    }
+
+    /// Returns the last result of the `oracle` feed observed in `window` or `None` if there is
+    /// no such result or if it cannot be trusted
+    ///
+    /// # Arguments
+    ///
+    /// * `oracle` - the feed account, it needs to belong to this oracle source
+    /// * `history_buffer` - the account storing the past results of the feed, if any
+    /// * `window` - the time window the result needs to be observed in
+    /// * `max_confidence_interval` - max confidence interval of the result of the feed
+    pub fn read(
+        &self,
+        oracle: &AccountInfo,
+        history_buffer: Option<&AccountInfo>,
+        window: &SampleWindow,
+        max_confidence_interval: Decimal,
+    ) -> Result<Option<Decimal>> {
+        self.check_account(oracle)?;
+        let data = oracle.try_borrow_data()?;
+        match self {
+            OracleSource::Switchboard => {
+                read_switchboard_feed(&data, history_buffer, window, max_confidence_interval)
+            }
+            OracleSource::Pyth => read_pyth_feed(&data, window, max_confidence_interval),
+            // attestations are read from the ed25519 instructions of the transaction
+            OracleSource::Optimistic | OracleSource::Attestation => Ok(None),
+        }
+    }
+}
+
+/// the time window in which an oracle result needs to have been observed to be used as the SLI of a period
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct SampleWindow {
+    /// first unix timestamp of the window
+    pub start: i64,
+    /// last unix timestamp of the window
+    pub end: i64,
+}
+
+impl SampleWindow {
+    /// Returns the window of a period, the result needs to be observed during the period
+    /// and at most `max_staleness` seconds before its end
+    ///
+    /// # Arguments
+    ///
+    /// * `period_start` - start timestamp of the period
+    /// * `period_end` - end timestamp of the period
+    /// * `max_staleness` - max age in seconds of the result at the end of the period
+    pub fn new(period_start: u128, period_end: u128, max_staleness: u32) -> Result<Self> {
+        let start = i64::try_from(period_start).map_err(|_| ErrorCode::CheckedOperationOverflow)?;
+        let end = i64::try_from(period_end).map_err(|_| ErrorCode::CheckedOperationOverflow)?;
+        Ok(Self {
+            start: start.max(end.saturating_sub(max_staleness as i64)),
+            end,
+        })
+    }
+
+    pub fn contains(&self, timestamp: i64) -> bool {
+        self.start <= timestamp && timestamp <= self.end
+    }
+}
+
+/// how the results of the different oracle feeds of an `Sla` are combined into a single SLI
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, PartialEq, Eq, Clone, Copy)]
+pub enum AggregationPolicy {
+    /// middle result, or the mean of the 2 middle results for an even number of results
+    Median,
+    /// smallest result
+    Min,
+    /// largest result
+    Max,
+    /// mean of the results after removing the `trim` smallest and the `trim` largest
+    TrimmedMean { trim: u8 },
+}
+
+impl AggregationPolicy {
+    pub const LEN: usize = 1 + 1;
+
+    /// checks that the policy can always produce a result when `min_quorum` feeds contributed
+    pub fn check_quorum(&self, min_quorum: u8) -> Result<()> {
+        if let AggregationPolicy::TrimmedMean { trim } = self {
+            require_gt!(
+                min_quorum as u16,
+                (*trim as u16) * 2,
+                FeedErrorCode::InvalidAggregationPolicy
+            );
+        }
+        Ok(())
+    }
+
+    /// Returns the aggregated value of the oracle `results`
+    ///
+    /// # Arguments
+    ///
+    /// * `results` - the results of all the feeds that contributed, they get sorted in place
+    pub fn aggregate(&self, results: &mut [Decimal]) -> Result<Decimal> {
+        require!(!results.is_empty(), FeedErrorCode::QuorumNotReached);
+        results.sort();
+        let len = results.len();
+
+        match self {
+            AggregationPolicy::Min => Ok(results[0]),
+            AggregationPolicy::Max => Ok(results[len - 1]),
+            AggregationPolicy::Median => {
+                if len % 2 == 1 {
+                    Ok(results[len / 2])
+                } else {
+                    mean(&results[len / 2 - 1..=len / 2])
+                }
+            }
+            AggregationPolicy::TrimmedMean { trim } => {
+                let trim = *trim as usize;
+                require_gt!(len, trim * 2, FeedErrorCode::InvalidAggregationPolicy);
+                mean(&results[trim..len - trim])
+            }
+        }
+    }
+}
+
+fn mean(values: &[Decimal]) -> Result<Decimal> {
+    let mut sum = Decimal::ZERO;
+    for value in values {
+        sum = sum
+            .checked_add(*value)
+            .ok_or(ErrorCode::CheckedOperationOverflow)?;
+    }
+    Ok(sum
+        .checked_div(Decimal::from(values.len()))
+        .ok_or(ErrorCode::CheckedOperationOverflow)?)
+}
+
+/// Returns the last result of a switchboard aggregator observed in `window` or `None`
+///
+/// the latest round is used if it was opened in the window, otherwise the last sample of the
+/// window is looked up in the history buffer, history samples have no confidence interval
+///
+/// # Arguments
+///
+/// * `data` - the data of the switchboard aggregator account
+/// * `history_buffer` - the history buffer account of the aggregator, if any
+/// * `window` - the time window the result needs to be observed in
+/// * `max_confidence_interval` - max standard deviation of the oracle responses of the latest round
+fn read_switchboard_feed(
+    data: &[u8],
+    history_buffer: Option<&AccountInfo>,
+    window: &SampleWindow,
+    max_confidence_interval: Decimal,
+) -> Result<Option<Decimal>> {
+    let feed = AggregatorAccountData::new_from_bytes(data)
+        .map_err(|_| error!(FeedErrorCode::InvalidSwitchboardAccount))?;
+
+    // the feed has not been updated since the window, use the latest round
+    if window.contains(feed.latest_confirmed_round.round_open_timestamp) {
+        // check feed does not exceed max_confidence_interval
+        if feed
+            .check_confidence_interval(SwitchboardDecimal::from_rust_decimal(
+                max_confidence_interval,
+            ))
+            .is_err()
+        {
+            return Ok(None);
+        }
+
+        let result: f64 = feed.get_result()?.try_into()?;
+        let result = Decimal::from_f64(result).ok_or(ErrorCode::DecimalConversionError)?;
+        return Ok(Some(result));
+    }
+
+    let history_buffer = match history_buffer {
+        Some(history_buffer) => history_buffer,
+        None => return Ok(None),
+    };
+    require_keys_eq!(
+        *history_buffer.key,
+        feed.history_buffer,
+        FeedErrorCode::InvalidHistoryBuffer
+    );
+    let history = AggregatorHistoryBuffer::new(history_buffer)
+        .map_err(|_| error!(FeedErrorCode::InvalidHistoryBuffer))?;
+
+    match history.lower_bound(window.end) {
+        Some(row) if window.contains(row.timestamp) => {
+            let result: Decimal = row.value.try_into()?;
+            Ok(Some(result))
+        }
+        _ => Ok(None),
+    }
+}
+
+/// Returns the last price of a pyth price account published in `window` or `None`
+///
+/// only the current and the previous aggregate prices are available on-chain, pyth publishes
+/// several times a second so the price of a window is recorded during it, see `PythSample`
+///
+/// # Arguments
+///
+/// * `data` - the data of the pyth price account
+/// * `window` - the time window the price needs to be published in
+/// * `max_confidence_interval` - max confidence interval of the price
+fn read_pyth_feed(
+    data: &[u8],
+    window: &SampleWindow,
+    max_confidence_interval: Decimal,
+) -> Result<Option<Decimal>> {
+    let price_account =
+        load_price_account(data).map_err(|_| error!(FeedErrorCode::InvalidPythAccount))?;
+
+    let current = (price_account.agg.status == PriceStatus::Trading).then_some((
+        price_account.agg.price,
+        price_account.agg.conf,
+        price_account.timestamp,
+    ));
+    let previous = Some((
+        price_account.prev_price,
+        price_account.prev_conf,
+        price_account.prev_timestamp,
+    ));
+
+    let (price, conf) = match [current, previous]
+        .into_iter()
+        .flatten()
+        .find(|(_, _, publish_time)| window.contains(*publish_time))
+    {
+        Some((price, conf, _)) => (price, conf),
+        None => return Ok(None),
+    };
+
+    // check price does not exceed max_confidence_interval
+    let exponent = pyth_exponent(price_account.expo)?;
+    let conf = Decimal::from(conf)
+        .checked_mul(exponent)
+        .ok_or(ErrorCode::CheckedOperationOverflow)?;
+    if conf > max_confidence_interval {
+        return Ok(None);
+    }
+
+    Ok(Some(
+        Decimal::from(price)
+            .checked_mul(exponent)
+            .ok_or(ErrorCode::CheckedOperationOverflow)?,
+    ))
+}
+
+/// `10^expo` as a decimal
+fn pyth_exponent(expo: i32) -> Result<Decimal> {
+    let scale = Decimal::from(
+        10u64
+            .checked_pow(expo.unsigned_abs())
+            .ok_or(ErrorCode::CheckedOperationOverflow)?,
+    );
+    if expo < 0 {
+        Ok(Decimal::ONE
+            .checked_div(scale)
+            .ok_or(ErrorCode::CheckedOperationOverflow)?)
+    } else {
+        Ok(scale)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pyth_sdk_solana::state::{AccountType, PriceAccount, MAGIC, VERSION_2};
+    use switchboard_v2::AggregatorHistoryRow;
+
+    const PERIOD_START: i64 = 1_700_000_000;
+    const PERIOD_END: i64 = 1_700_003_599;
+
+    fn results(values: &[i64]) -> Vec<Decimal> {
+        values.iter().map(|v| Decimal::new(*v, 0)).collect()
+    }
+
+    fn window() -> SampleWindow {
+        SampleWindow::new(PERIOD_START as u128, PERIOD_END as u128, 300).unwrap()
+    }
+
+    fn switchboard_data(
+        result: f64,
+        std_deviation: f64,
+        updated_at: i64,
+        history_buffer: Pubkey,
+    ) -> Vec<u8> {
+        let mut feed: AggregatorAccountData = bytemuck::Zeroable::zeroed();
+        feed.latest_confirmed_round.result = SwitchboardDecimal::from_f64(result);
+        feed.latest_confirmed_round.std_deviation = SwitchboardDecimal::from_f64(std_deviation);
+        feed.latest_confirmed_round.round_open_timestamp = updated_at;
+        feed.history_buffer = history_buffer;
+        let mut data =
+            <AggregatorAccountData as anchor_lang::Discriminator>::discriminator().to_vec();
+        data.extend_from_slice(bytemuck::bytes_of(&feed));
+        data
+    }
+
+    fn history_buffer_data(samples: &[(i64, f64)]) -> Vec<u8> {
+        let rows: Vec<AggregatorHistoryRow> = samples
+            .iter()
+            .map(|(timestamp, value)| AggregatorHistoryRow {
+                timestamp: *timestamp,
+                value: SwitchboardDecimal::from_f64(*value),
+            })
+            .collect();
+        let mut data = b"BUFFERxx".to_vec();
+        data.extend_from_slice(&((samples.len() - 1) as u32).to_le_bytes());
+        data.extend_from_slice(bytemuck::cast_slice(&rows));
+        data
+    }
+
+    fn pyth_data(
+        (price, conf, published_at): (i64, u64, i64),
+        (prev_price, prev_conf, prev_published_at): (i64, u64, i64),
+        expo: i32,
+    ) -> Vec<u8> {
+        let mut price_account = PriceAccount {
+            magic: MAGIC,
+            ver: VERSION_2,
+            atype: AccountType::Price as u32,
+            expo,
+            timestamp: published_at,
+            prev_price,
+            prev_conf,
+            prev_timestamp: prev_published_at,
+            ..Default::default()
+        };
+        price_account.agg.price = price;
+        price_account.agg.conf = conf;
+        price_account.agg.status = PriceStatus::Trading;
+        bytemuck::bytes_of(&price_account).to_vec()
+    }
+
+    fn read(
+        source: OracleSource,
+        owner: &Pubkey,
+        data: &mut [u8],
+        history_buffer: Option<(Pubkey, &mut [u8])>,
+        max_confidence_interval: Option<f64>,
+    ) -> Result<Option<Decimal>> {
+        let max_confidence_interval = max_confidence_interval
+            .map(|interval| Decimal::from_f64(interval).unwrap())
+            .unwrap_or(Decimal::MAX);
+        let key = Pubkey::new_unique();
+        let mut lamports = 0;
+        let oracle = AccountInfo::new(&key, false, false, &mut lamports, data, owner, false, 0);
+
+        match history_buffer {
+            Some((history_key, history_data)) => {
+                let mut history_lamports = 0;
+                let history_buffer = AccountInfo::new(
+                    &history_key,
+                    false,
+                    false,
+                    &mut history_lamports,
+                    history_data,
+                    &SWITCHBOARD_PROGRAM_ID,
+                    false,
+                    0,
+                );
+                source.read(
+                    &oracle,
+                    Some(&history_buffer),
+                    &window(),
+                    max_confidence_interval,
+                )
+            }
+            None => source.read(&oracle, None, &window(), max_confidence_interval),
+        }
+    }
+
+    /// reads the `feeds` of an SLA passed in `order`, all of them in their order without it
+    fn read_all(
+        source: OracleSource,
+        feeds: &mut [(Pubkey, Pubkey, Vec<u8>)],
+        order: Option<&[usize]>,
+    ) -> Result<Vec<Option<Decimal>>> {
+        let oracle_addresses: Vec<Pubkey> = feeds.iter().map(|(key, _, _)| *key).collect();
+        let mut lamports = vec![0; feeds.len()];
+        let accounts: Vec<AccountInfo> = feeds
+            .iter_mut()
+            .zip(lamports.iter_mut())
+            .map(|((key, owner, data), lamports)| {
+                AccountInfo::new(key, false, false, lamports, data, owner, false, 0)
+            })
+            .collect();
+        let accounts: Vec<AccountInfo> = match order {
+            Some(order) => order.iter().map(|index| accounts[*index].clone()).collect(),
+            None => accounts,
+        };
+        source.read_all(&oracle_addresses, &accounts, &window(), Decimal::MAX)
+    }
+
+    fn pyth_feed(price: i64, published_at: i64) -> (Pubkey, Pubkey, Vec<u8>) {
+        (
+            Pubkey::new_unique(),
+            PYTH_PROGRAM_ID,
+            pyth_data((price, 0, published_at), (0, 0, 0), 0),
+        )
+    }
+
+    #[test]
+    fn read_all_feeds() {
+        let mut feeds = [
+            pyth_feed(90, PERIOD_END),
+            pyth_feed(100, PERIOD_END),
+            pyth_feed(110, PERIOD_END),
+        ];
+        assert_eq!(
+            read_all(OracleSource::Pyth, &mut feeds, None).unwrap(),
+            vec![
+                Some(Decimal::new(90, 0)),
+                Some(Decimal::new(100, 0)),
+                Some(Decimal::new(110, 0))
+            ]
+        );
+    }
+    #[test]
+    fn read_all_without_a_feed() {
+        let mut feeds = [
+            pyth_feed(90, PERIOD_END),
+            pyth_feed(100, PERIOD_END),
+            pyth_feed(110, PERIOD_END),
+        ];
+        let mut all: Vec<Decimal> = read_all(OracleSource::Pyth, &mut feeds, None)
+            .unwrap()
+            .into_iter()
+            .flatten()
+            .collect();
+        assert_eq!(
+            AggregationPolicy::Median.aggregate(&mut all).unwrap(),
+            Decimal::new(100, 0)
+        );
+        // leaving out the highest feed would lower the median SLI to 95
+        assert_eq!(
+            AggregationPolicy::Median
+                .aggregate(&mut all[..2].to_vec())
+                .unwrap(),
+            Decimal::new(95, 0)
+        );
+        assert_eq!(
+            read_all(OracleSource::Pyth, &mut feeds, Some(&[0, 1])),
+            err!(FeedErrorCode::MissingOracle)
+        );
+        assert_eq!(
+            read_all(OracleSource::Pyth, &mut feeds, Some(&[0, 2])),
+            err!(FeedErrorCode::MissingOracle)
+        );
+    }
+    #[test]
+    fn read_all_out_of_order() {
+        let mut feeds = [pyth_feed(90, PERIOD_END), pyth_feed(100, PERIOD_END)];
+        assert_eq!(
+            read_all(OracleSource::Pyth, &mut feeds, Some(&[1, 0])),
+            err!(FeedErrorCode::MissingOracle)
+        );
+    }
+    #[test]
+    fn read_all_extra_account() {
+        let mut feeds = [pyth_feed(90, PERIOD_END), pyth_feed(100, PERIOD_END)];
+        assert_eq!(
+            read_all(OracleSource::Pyth, &mut feeds, Some(&[0, 1, 1])),
+            err!(FeedErrorCode::UnknownOracle)
+        );
+    }
+    #[test]
+    fn read_all_stale_and_failed_feeds_count_against_quorum() {
+        let mut feeds = [
+            pyth_feed(90, PERIOD_END),
+            pyth_feed(100, PERIOD_END - 301),
+            (
+                Pubkey::new_unique(),
+                SWITCHBOARD_PROGRAM_ID,
+                pyth_data((110, 0, PERIOD_END), (0, 0, 0), 0),
+            ),
+        ];
+        assert_eq!(
+            read_all(OracleSource::Pyth, &mut feeds, None).unwrap(),
+            vec![Some(Decimal::new(90, 0)), None, None]
+        );
+    }
+    #[test]
+    fn read_all_needs_history_buffers() {
+        let history_key = Pubkey::new_unique();
+        let mut feeds = [
+            (
+                Pubkey::new_unique(),
+                SWITCHBOARD_PROGRAM_ID,
+                switchboard_data(10.0, 0.5, PERIOD_END + 10_000, history_key),
+            ),
+            (
+                history_key,
+                SWITCHBOARD_PROGRAM_ID,
+                history_buffer_data(&[(PERIOD_END - 100, 98.5), (PERIOD_END + 10_000, 10.0)]),
+            ),
+        ];
+        // the history buffer is passed as a feed of the SLA here, it follows the first feed
+        let oracle_addresses = [feeds[0].0];
+        let mut lamports = [0, 0];
+        let [(key, owner, data), (history_key, history_owner, history_data)] = &mut feeds;
+        let [lamports, history_lamports] = &mut lamports;
+        let oracle = AccountInfo::new(key, false, false, lamports, data, owner, false, 0);
+        let history_buffer = AccountInfo::new(
+            history_key,
+            false,
+            false,
+            history_lamports,
+            history_data,
+            history_owner,
+            false,
+            0,
+        );
+        assert_eq!(
+            OracleSource::Switchboard
+                .read_all(
+                    &oracle_addresses,
+                    &[oracle.clone(), history_buffer],
+                    &window(),
+                    Decimal::MAX
+                )
+                .unwrap(),
+            vec![Some(Decimal::new(985, 1))]
+        );
+        assert_eq!(
+            OracleSource::Switchboard.read_all(
+                &oracle_addresses,
+                &[oracle],
+                &window(),
+                Decimal::MAX
+            ),
+            err!(FeedErrorCode::InvalidHistoryBuffer)
+        );
+    }
+    #[test]
+    fn aggregate_min_max() {
+        let mut values = results(&[7, 3, 9, 5]);
+        assert_eq!(
+            AggregationPolicy::Min.aggregate(&mut values).unwrap(),
+            Decimal::new(3, 0)
+        );
+        assert_eq!(
+            AggregationPolicy::Max.aggregate(&mut values).unwrap(),
+            Decimal::new(9, 0)
+        );
+    }
+    #[test]
+    fn aggregate_median_odd() {
+        let mut values = results(&[7, 3, 9]);
+        assert_eq!(
+            AggregationPolicy::Median.aggregate(&mut values).unwrap(),
+            Decimal::new(7, 0)
+        );
+    }
+    #[test]
+    fn aggregate_median_even() {
+        let mut values = results(&[7, 3, 9, 4]);
+        assert_eq!(
+            AggregationPolicy::Median.aggregate(&mut values).unwrap(),
+            Decimal::new(55, 1)
+        );
+    }
+    #[test]
+    fn aggregate_trimmed_mean() {
+        let mut values = results(&[100, 3, 4, 5, 0]);
+        assert_eq!(
+            AggregationPolicy::TrimmedMean { trim: 1 }
+                .aggregate(&mut values)
+                .unwrap(),
+            Decimal::new(4, 0)
+        );
+    }
+    #[test]
+    fn aggregate_trimmed_mean_too_few_results() {
+        let mut values = results(&[3, 4]);
+        assert_eq!(
+            AggregationPolicy::TrimmedMean { trim: 1 }.aggregate(&mut values),
+            err!(FeedErrorCode::InvalidAggregationPolicy)
+        );
+    }
+    #[test]
+    fn aggregate_no_results() {
+        assert_eq!(
+            AggregationPolicy::Median.aggregate(&mut []),
+            err!(FeedErrorCode::QuorumNotReached)
+        );
+    }
+    #[test]
+    fn check_quorum_trimmed_mean() {
+        let policy = AggregationPolicy::TrimmedMean { trim: 1 };
+        assert_eq!(
+            policy.check_quorum(2),
+            err!(FeedErrorCode::InvalidAggregationPolicy)
+        );
+        assert!(policy.check_quorum(3).is_ok());
+        assert!(AggregationPolicy::Median.check_quorum(1).is_ok());
+    }
+    #[test]
+    fn sample_window_bounded_by_period_start() {
+        assert_eq!(
+            SampleWindow::new(100, 199, 300).unwrap(),
+            SampleWindow {
+                start: 100,
+                end: 199
+            }
+        );
+        assert_eq!(
+            window(),
+            SampleWindow {
+                start: PERIOD_END - 300,
+                end: PERIOD_END
+            }
+        );
+    }
+    #[test]
+    fn read_switchboard_latest_round_in_window() {
+        let mut data = switchboard_data(99.5, 0.5, PERIOD_END - 10, Pubkey::default());
+        assert_eq!(
+            read(
+                OracleSource::Switchboard,
+                &SWITCHBOARD_PROGRAM_ID,
+                &mut data,
+                None,
+                Some(1.0)
+            )
+            .unwrap(),
+            Some(Decimal::new(995, 1))
+        );
+    }
+    #[test]
+    fn read_switchboard_latest_round_too_old() {
+        let mut data = switchboard_data(99.5, 0.5, PERIOD_END - 301, Pubkey::default());
+        assert_eq!(
+            read(
+                OracleSource::Switchboard,
+                &SWITCHBOARD_PROGRAM_ID,
+                &mut data,
+                None,
+                None
+            )
+            .unwrap(),
+            None
+        );
+    }
+    #[test]
+    fn read_switchboard_latest_round_after_window_without_history() {
+        // the value observed after the end of the period cannot be used
+        let mut data = switchboard_data(99.5, 0.5, PERIOD_END + 1, Pubkey::default());
+        assert_eq!(
+            read(
+                OracleSource::Switchboard,
+                &SWITCHBOARD_PROGRAM_ID,
+                &mut data,
+                None,
+                None
+            )
+            .unwrap(),
+            None
+        );
+    }
+    #[test]
+    fn read_switchboard_history_in_window() {
+        let history_key = Pubkey::new_unique();
+        let mut data = switchboard_data(10.0, 0.5, PERIOD_END + 10_000, history_key);
+        let mut history = history_buffer_data(&[
+            (PERIOD_END - 500, 97.0),
+            (PERIOD_END - 100, 98.5),
+            (PERIOD_END + 1, 42.0),
+            (PERIOD_END + 10_000, 10.0),
+        ]);
+        assert_eq!(
+            read(
+                OracleSource::Switchboard,
+                &SWITCHBOARD_PROGRAM_ID,
+                &mut data,
+                Some((history_key, &mut history)),
+                Some(1.0)
+            )
+            .unwrap(),
+            Some(Decimal::new(985, 1))
+        );
+    }
+    #[test]
+    fn read_switchboard_history_overwritten() {
+        // the last sample before the end of the period is older than the max staleness
+        let history_key = Pubkey::new_unique();
+        let mut data = switchboard_data(10.0, 0.5, PERIOD_END + 10_000, history_key);
+        let mut history =
+            history_buffer_data(&[(PERIOD_END - 301, 97.0), (PERIOD_END + 10_000, 10.0)]);
+        assert_eq!(
+            read(
+                OracleSource::Switchboard,
+                &SWITCHBOARD_PROGRAM_ID,
+                &mut data,
+                Some((history_key, &mut history)),
+                None
+            )
+            .unwrap(),
+            None
+        );
+    }
+    #[test]
+    fn read_switchboard_wrong_history_buffer() {
+        let mut data = switchboard_data(10.0, 0.5, PERIOD_END + 10_000, Pubkey::new_unique());
+        let mut history = history_buffer_data(&[(PERIOD_END - 100, 98.5)]);
+        assert_eq!(
+            read(
+                OracleSource::Switchboard,
+                &SWITCHBOARD_PROGRAM_ID,
+                &mut data,
+                Some((Pubkey::new_unique(), &mut history)),
+                None
+            ),
+            err!(FeedErrorCode::InvalidHistoryBuffer)
+        );
+    }
+    #[test]
+    fn read_switchboard_confidence_interval_exceeded() {
+        let mut data = switchboard_data(99.5, 2.0, PERIOD_END, Pubkey::default());
+        assert_eq!(
+            read(
+                OracleSource::Switchboard,
+                &SWITCHBOARD_PROGRAM_ID,
+                &mut data,
+                None,
+                Some(1.0)
+            )
+            .unwrap(),
+            None
+        );
+    }
+    #[test]
+    fn read_switchboard_wrong_owner() {
+        let mut data = switchboard_data(99.5, 0.5, PERIOD_END, Pubkey::default());
+        assert_eq!(
+            read(
+                OracleSource::Switchboard,
+                &PYTH_PROGRAM_ID,
+                &mut data,
+                None,
+                None
+            ),
+            err!(FeedErrorCode::InvalidSwitchboardAccount)
+        );
+    }
+    #[test]
+    fn read_pyth_current_price_in_window() {
+        // 99.5 with a confidence interval of 0.5
+        let mut data = pyth_data((9_950, 50, PERIOD_END - 10), (0, 0, 0), -2);
+        assert_eq!(
+            read(
+                OracleSource::Pyth,
+                &PYTH_PROGRAM_ID,
+                &mut data,
+                None,
+                Some(1.0)
+            )
+            .unwrap(),
+            Some(Decimal::new(995, 1))
+        );
+    }
+    #[test]
+    fn read_pyth_previous_price_in_window() {
+        let mut data = pyth_data(
+            (1_000, 50, PERIOD_END + 1),
+            (9_850, 50, PERIOD_END - 10),
+            -2,
+        );
+        assert_eq!(
+            read(
+                OracleSource::Pyth,
+                &PYTH_PROGRAM_ID,
+                &mut data,
+                None,
+                Some(1.0)
+            )
+            .unwrap(),
+            Some(Decimal::new(985, 1))
+        );
+    }
+    #[test]
+    fn read_pyth_positive_exponent() {
+        let mut data = pyth_data((12, 0, PERIOD_END), (0, 0, 0), 3);
+        assert_eq!(
+            read(OracleSource::Pyth, &PYTH_PROGRAM_ID, &mut data, None, None).unwrap(),
+            Some(Decimal::new(12_000, 0))
+        );
+    }
+    #[test]
+    fn read_pyth_no_price_in_window() {
+        let mut data = pyth_data(
+            (9_950, 50, PERIOD_END + 20),
+            (9_950, 50, PERIOD_END + 10),
+            -2,
+        );
+        assert_eq!(
+            read(OracleSource::Pyth, &PYTH_PROGRAM_ID, &mut data, None, None).unwrap(),
+            None
+        );
+        let mut data = pyth_data((9_950, 50, PERIOD_END - 301), (0, 0, 0), -2);
+        assert_eq!(
+            read(OracleSource::Pyth, &PYTH_PROGRAM_ID, &mut data, None, None).unwrap(),
+            None
+        );
+    }
+    #[test]
+    fn read_pyth_confidence_interval_exceeded() {
+        let mut data = pyth_data((9_950, 200, PERIOD_END), (0, 0, 0), -2);
+        assert_eq!(
+            read(
+                OracleSource::Pyth,
+                &PYTH_PROGRAM_ID,
+                &mut data,
+                None,
+                Some(1.0)
+            )
+            .unwrap(),
+            None
+        );
+    }
+    #[test]
+    fn read_pyth_wrong_owner() {
+        let mut data = pyth_data((9_950, 50, PERIOD_END), (0, 0, 0), -2);
+        assert_eq!(
+            read(
+                OracleSource::Pyth,
+                &SWITCHBOARD_PROGRAM_ID,
+                &mut data,
+                None,
+                None
+            ),
+            err!(FeedErrorCode::InvalidPythAccount)
+        );
+    }
+    #[test]
+    fn read_pyth_with_switchboard_data() {
+        let mut data = switchboard_data(99.5, 0.5, PERIOD_END, Pubkey::default());
+        assert_eq!(
+            read(OracleSource::Pyth, &PYTH_PROGRAM_ID, &mut data, None, None),
+            err!(FeedErrorCode::InvalidPythAccount)
+        );
+    }
+}
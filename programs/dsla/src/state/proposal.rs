@@ -0,0 +1,109 @@
+use anchor_lang::prelude::*;
+
+use super::DslaDecimal;
+use crate::errors::ErrorCode;
+
+/// SLI proposed for a period of an SLA using optimistic validation,
+/// the bonds are held in the bond vault of the proposal until the period is final
+#[account]
+pub struct Proposal {
+    /// validator that proposed the SLI
+    pub proposer: Pubkey,
+    /// proposed SLI of the period
+    pub value: DslaDecimal,
+    /// amount of dsla bonded by the proposer
+    pub proposer_bond: u64,
+    /// last unix timestamp the proposal can be disputed at
+    pub dispute_deadline: i64,
+    /// account that disputed the proposal if any
+    pub challenger: Option<Pubkey>,
+    /// amount of dsla bonded by the challenger
+    pub challenger_bond: u64,
+}
+
+impl Proposal {
+    pub const LEN: usize = 8 // discriminator
+    + 32 // proposer
+    + DslaDecimal::LEN // value
+    + 8 // proposer_bond
+    + 8 // dispute_deadline
+    + 1 + 32 // challenger
+    + 8 // challenger_bond
+    ;
+
+    /// whether the proposal can still be disputed at `timestamp`
+    pub fn is_disputable(&self, timestamp: i64) -> bool {
+        self.challenger.is_none() && timestamp <= self.dispute_deadline
+    }
+
+    /// Returns the account that gets both bonds of a disputed proposal,
+    /// the proposer if the arbiter settled the period with the proposed SLI, the challenger otherwise
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - the SLI the arbiter settled the period with
+    pub fn dispute_winner(&self, value: DslaDecimal) -> Result<Pubkey> {
+        let challenger = self.challenger.ok_or(ErrorCode::PeriodNotDisputed)?;
+        if value.to_decimal() == self.value.to_decimal() {
+            Ok(self.proposer)
+        } else {
+            Ok(challenger)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal::Decimal;
+
+    const PROPOSER: Pubkey = Pubkey::new_from_array([1; 32]);
+    const CHALLENGER: Pubkey = Pubkey::new_from_array([2; 32]);
+
+    fn proposal(challenger: Option<Pubkey>) -> Proposal {
+        Proposal {
+            proposer: PROPOSER,
+            value: DslaDecimal::from_decimal(Decimal::new(995, 1)),
+            proposer_bond: 100,
+            dispute_deadline: 1000,
+            challenger,
+            challenger_bond: 0,
+        }
+    }
+
+    #[test]
+    fn is_disputable_until_deadline() {
+        let proposal = proposal(None);
+        assert!(proposal.is_disputable(999));
+        assert!(proposal.is_disputable(1000));
+        assert!(!proposal.is_disputable(1001));
+    }
+    #[test]
+    fn is_disputable_once() {
+        assert!(!proposal(Some(CHALLENGER)).is_disputable(0));
+    }
+    #[test]
+    fn dispute_winner() {
+        let proposal = proposal(Some(CHALLENGER));
+        // the same SLI with a different scale upholds the proposal
+        assert_eq!(
+            proposal
+                .dispute_winner(DslaDecimal::from_decimal(Decimal::new(9950, 2)))
+                .unwrap(),
+            PROPOSER
+        );
+        assert_eq!(
+            proposal
+                .dispute_winner(DslaDecimal::from_decimal(Decimal::new(90, 0)))
+                .unwrap(),
+            CHALLENGER
+        );
+    }
+    #[test]
+    fn dispute_winner_not_disputed() {
+        assert_eq!(
+            proposal(None).dispute_winner(DslaDecimal::from_decimal(Decimal::ONE)),
+            err!(ErrorCode::PeriodNotDisputed)
+        );
+    }
+}
@@ -0,0 +1,85 @@
+use anchor_lang::prelude::*;
+use rust_decimal::Decimal;
+
+use super::DslaDecimal;
+use crate::constants::MAX_ORACLES;
+use crate::errors::FeedErrorCode;
+
+/// prices of the pyth feeds of an SLA recorded during the sample window of a period,
+/// pyth price accounts only keep their current and previous price so the periods of SLAs
+/// using pyth are validated from the sample recorded before the price moved on, see `record_pyth_sample`
+#[account]
+pub struct PythSample {
+    /// account that paid the rent of the sample, it gets it back with `close_pyth_sample`
+    pub recorder: Pubkey,
+    /// price of each feed of the SLA in its order, `None` for the feeds without a trusted price in the window
+    pub prices: Vec<Option<DslaDecimal>>,
+}
+
+impl PythSample {
+    pub const LEN: usize = 8 // discriminator
+    + 32 // recorder
+    + 4 + (1 + DslaDecimal::LEN) * MAX_ORACLES // prices
+    ;
+
+    /// Returns the sample of the `results` of the feeds of an SLA, read with `OracleSource::read_all`
+    ///
+    /// # Arguments
+    ///
+    /// * `recorder` - the account paying the rent of the sample
+    /// * `results` - the result of each feed of the SLA in its order
+    /// * `min_quorum` - number of feeds that need a result, a sample without it would block the period
+    pub fn new(recorder: Pubkey, results: &[Option<Decimal>], min_quorum: u8) -> Result<Self> {
+        require_gte!(
+            results.iter().flatten().count(),
+            min_quorum as usize,
+            FeedErrorCode::QuorumNotReached
+        );
+        Ok(Self {
+            recorder,
+            prices: results
+                .iter()
+                .map(|result| result.map(DslaDecimal::from_decimal))
+                .collect(),
+        })
+    }
+
+    /// Returns the recorded price of each feed of the SLA in its order
+    pub fn results(&self) -> Vec<Option<Decimal>> {
+        self.prices
+            .iter()
+            .map(|price| price.as_ref().map(DslaDecimal::to_decimal))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RECORDER: Pubkey = Pubkey::new_from_array([1; 32]);
+
+    #[test]
+    fn len_fits_max_oracles() {
+        let sample = PythSample {
+            recorder: RECORDER,
+            prices: vec![Some(DslaDecimal::from_decimal(Decimal::new(-1, 9))); MAX_ORACLES],
+        };
+        assert_eq!(sample.try_to_vec().unwrap().len() + 8, PythSample::LEN);
+    }
+    #[test]
+    fn results_in_order_of_feeds() {
+        let results = vec![Some(Decimal::new(995, 1)), None, Some(Decimal::new(100, 0))];
+        let sample = PythSample::new(RECORDER, &results, 2).unwrap();
+        assert_eq!(sample.recorder, RECORDER);
+        assert_eq!(sample.results(), results);
+    }
+    #[test]
+    fn new_needs_the_quorum() {
+        let results = [Some(Decimal::new(995, 1)), None, None];
+        assert_eq!(
+            PythSample::new(RECORDER, &results, 2).map(|sample| sample.prices),
+            err!(FeedErrorCode::QuorumNotReached)
+        );
+    }
+}
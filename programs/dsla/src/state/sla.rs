@@ -1,14 +1,30 @@
+use crate::constants::MAX_ORACLES;
 use crate::errors::ErrorCode;
+use crate::state::oracle::{AggregationPolicy, OracleSource};
+use crate::state::status_registry::Status;
 use anchor_lang::prelude::*;
-use rust_decimal::Decimal;
+use anchor_lang::Discriminator;
+use rust_decimal::prelude::*;
 
 /// `Sla` is Service level agreement account containing all the variables to make it possible
 #[account]
 pub struct Sla {
+    /// layout version of the account, see `Sla::migrate`
+    pub version: u8,
     /// address of who deployed the SLA
     pub sla_deployer_address: Pubkey,
-    /// address of the switchboard aggregator account
-    pub aggregator_address: Pubkey,
+    /// oracle network of the feeds
+    pub oracle_source: OracleSource,
+    /// addresses of the oracle feed accounts, max of 8
+    pub oracle_addresses: Vec<Pubkey>,
+    /// how the results of the oracle feeds are combined into the SLI
+    pub aggregation_policy: AggregationPolicy,
+    /// minimum number of fresh oracle feeds needed to validate a period
+    pub min_quorum: u8,
+    /// max number of seconds since the last update of an oracle feed for it to be fresh
+    pub max_staleness: u32,
+    /// max confidence interval of the result of an oracle feed for it to be fresh
+    pub max_confidence_interval: DslaDecimal,
     /// service level objective, the objective to achieve for the provider to be rewarded
     pub slo: Slo,
     ///  leverage for the SLA between provider and user pool
@@ -29,13 +45,30 @@ pub struct Sla {
     pub severity: Vec<DslaDecimal>,
     /// range of penalty max of 10;
     pub penalty: Vec<DslaDecimal>,
+    /// whether the deployer cancelled the SLA before it started
+    pub cancelled: bool,
+    /// whether the guardian paused staking and validation of the SLA
+    pub paused: bool,
+    /// number of periods with a `PeriodCheckpoint`, the finished periods are checkpointed in order
+    pub checkpointed_periods: u32,
+    /// max amount of tokens the checkpointed periods that are not settled yet can still move between the pools,
+    /// the stakes and withdrawals are priced as if they did, see `quote`
+    pub pending_transfer: u128,
 }
 
 impl Sla {
+    /// layout version of the accounts deployed or migrated by this program
+    pub const VERSION: u8 = 1;
+
     pub const LEN: usize = 8 + // discriminator
-        32 + // aggregator_address
+        1 + // version
+        OracleSource::LEN + // oracle_source
+        4 + (32 * MAX_ORACLES) + // oracle_addresses
+        AggregationPolicy::LEN + // aggregation_policy
+        1 + // min_quorum
+        4 + // max_staleness
+        DslaDecimal::LEN + // max_confidence_interval
         32 + // sla_deployer_address
-        32 + // messenger_address
         Slo::LEN + // SLO
         12 + // leverage
         32 + // mint_address
@@ -45,7 +78,66 @@ impl Sla {
         16 + // ut_supply
         16 + // pt_supply
         4 + (DslaDecimal::LEN * 10) + // severity
-        4 + (DslaDecimal::LEN * 10); // penalty
+        4 + (DslaDecimal::LEN * 10) + // penalty
+        1 + // cancelled
+        1 + // paused
+        4 + // checkpointed_periods
+        16 + // pending_transfer
+        42; // reserved for new fields, zeroed after the serialized fields
+
+    /// Returns the status of the SLA, a cancelled SLA is ended
+    pub fn status(&self) -> Result<SlaStatus> {
+        if self.cancelled {
+            return Ok(SlaStatus::Ended);
+        }
+        self.period_data.get_current_period_id()
+    }
+
+    /// Returns the SLA stored in the account `data` upgraded to the current layout
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - the data of an SLA account of any layout version older than the current one
+    pub fn migrate(data: &[u8]) -> Result<Sla> {
+        require!(
+            data.len() >= 8 && data[..8] == Sla::discriminator(),
+            anchor_lang::error::ErrorCode::AccountDiscriminatorMismatch
+        );
+        // accounts deployed before versioning have exactly the size of the unversioned layout
+        if data.len() == SlaV0::LEN {
+            let sla = SlaV0::deserialize(&mut &data[8..])?;
+            return Ok(sla.into());
+        }
+        let sla = Sla::try_deserialize(&mut &data[..])?;
+        require_gt!(Sla::VERSION, sla.version, ErrorCode::SlaAlreadyMigrated);
+        Ok(sla)
+    }
+
+    /// checks that `severity` and `penalty` can be used by `get_deviation`,
+    /// each severity maps to the penalty with the same index and both increase with the index
+    pub fn check_severity_penalty(severity: &[DslaDecimal], penalty: &[DslaDecimal]) -> Result<()> {
+        require!(
+            severity.len() == penalty.len() && severity.len() <= 10,
+            ErrorCode::InvalidSeverityPenalty
+        );
+        require!(
+            severity
+                .iter()
+                .chain(penalty)
+                .all(|value| value.to_decimal() >= Decimal::ZERO),
+            ErrorCode::InvalidSeverityPenalty
+        );
+        require!(
+            severity
+                .windows(2)
+                .all(|pair| pair[0].to_decimal() < pair[1].to_decimal())
+                && penalty
+                    .windows(2)
+                    .all(|pair| pair[0].to_decimal() <= pair[1].to_decimal()),
+            ErrorCode::InvalidSeverityPenalty
+        );
+        Ok(())
+    }
 
     /// Calculate deviation between SLO and SLI
     /// Ensures a positive deviation for greater / small comparisons
@@ -94,6 +186,230 @@ impl Sla {
             _ => Ok(deviation),
         }
     }
+
+    /// Returns the number of periods that finished by `sla_status`, none for a cancelled SLA
+    pub fn finished_periods(&self, sla_status: SlaStatus) -> u32 {
+        match sla_status {
+            _ if self.cancelled => 0,
+            SlaStatus::NotStarted => 0,
+            SlaStatus::Active { period_id } => period_id,
+            SlaStatus::Ended => self.period_data.n_periods,
+        }
+    }
+
+    /// checks that every period that finished by `sla_status` has a `PeriodCheckpoint`,
+    /// so that `pending_transfer` covers all the periods a stake or withdrawal was not exposed to
+    pub fn check_checkpointed(&self, sla_status: SlaStatus) -> Result<()> {
+        require_gte!(
+            self.checkpointed_periods,
+            self.finished_periods(sla_status),
+            ErrorCode::PeriodsNotCheckpointed
+        );
+        Ok(())
+    }
+
+    /// Returns the highest deviation `get_deviation` can return for any SLI
+    pub fn max_deviation(&self) -> Decimal {
+        let deviation_cap_rate = Decimal::new(25, 2);
+        match self.slo.slo_type {
+            SloType::EqualTo | SloType::NotEqualTo => deviation_cap_rate,
+            _ => self
+                .penalty
+                .iter()
+                .map(DslaDecimal::to_decimal)
+                .fold(deviation_cap_rate, Decimal::max),
+        }
+    }
+
+    /// Returns the reward a period moves between the pools for a `deviation`
+    ///
+    /// # Arguments
+    ///
+    /// * `user_pool_size` - the user pool exposed to the period, see `PeriodCheckpoint`
+    /// * `periods_left` - number of periods not settled yet, including this one
+    /// * `deviation` - the deviation of the SLI of the period, see `get_deviation`
+    fn reward(
+        &self,
+        user_pool_size: u128,
+        periods_left: usize,
+        deviation: Decimal,
+    ) -> Result<u128> {
+        Decimal::from_u128(user_pool_size)
+            .ok_or(ErrorCode::DecimalConversionError)?
+            .checked_mul(self.leverage.to_decimal())
+            .ok_or(ErrorCode::CheckedOperationOverflow)?
+            .checked_div(
+                Decimal::from_usize(periods_left).ok_or(ErrorCode::DecimalConversionError)?,
+            )
+            .ok_or(ErrorCode::CheckedOperationOverflow)?
+            .checked_mul(deviation)
+            .ok_or(ErrorCode::CheckedOperationOverflow)?
+            .floor()
+            .to_u128()
+            .ok_or_else(|| error!(ErrorCode::DecimalConversionError))
+    }
+
+    /// Returns the max amount of tokens a period can move between the pools whatever its SLI
+    ///
+    /// # Arguments
+    ///
+    /// * `user_pool_size` - the user pool exposed to the period
+    /// * `periods_left` - number of periods not settled yet, including this one
+    pub fn max_transfer(&self, user_pool_size: u128, periods_left: usize) -> Result<u128> {
+        self.reward(user_pool_size, periods_left, self.max_deviation())
+    }
+
+    /// reserves the `max_transfer` of a period that was just checkpointed in `pending_transfer`
+    pub fn checkpoint_period(&mut self, max_transfer: u128) -> Result<()> {
+        self.checkpointed_periods = self
+            .checkpointed_periods
+            .checked_add(1)
+            .ok_or(ErrorCode::CheckedOperationOverflow)?;
+        self.pending_transfer = self
+            .pending_transfer
+            .checked_add(max_transfer)
+            .ok_or(ErrorCode::CheckedOperationOverflow)?;
+        Ok(())
+    }
+
+    /// releases the `max_transfer` a checkpointed period reserved in `pending_transfer` once it is settled,
+    /// nothing stays reserved once every checkpointed period in `statuses` is settled or expired,
+    /// expired periods don't release their reservation as their checkpoints aren't read
+    pub fn release_pending_transfer(&mut self, max_transfer: u128, statuses: &[Status]) {
+        let all_settled = statuses
+            .iter()
+            .take(self.checkpointed_periods as usize)
+            .all(Status::is_settled);
+        self.pending_transfer = if all_settled {
+            0
+        } else {
+            self.pending_transfer.saturating_sub(max_transfer)
+        };
+    }
+
+    /// moves the reward of a period between the user and the provider pool
+    /// and returns whether the SLO was respected
+    ///
+    /// the reward is sized from the user pool exposed to the period, the stakes and withdrawals made
+    /// since it finished are priced with `pending_transfer` so they neither share nor escape it,
+    /// it is capped at the pool paying it
+    ///
+    /// # Arguments
+    ///
+    /// * `sli` - the final SLI of the period
+    /// * `periods_left` - number of periods not settled yet, including this one
+    /// * `user_pool_size` - the user pool exposed to the period, see `PeriodCheckpoint`
+    pub fn settle_period(
+        &mut self,
+        sli: DslaDecimal,
+        periods_left: usize,
+        user_pool_size: u128,
+    ) -> Result<bool> {
+        let respected = self.slo.is_respected(sli)?;
+
+        let deviation = self.get_deviation(&sli.to_decimal())?;
+        let reward = self.reward(user_pool_size, periods_left, deviation)?;
+
+        let leverage_adjusted_pool = Decimal::from_u128(self.user_pool_size)
+            .ok_or(ErrorCode::DecimalConversionError)?
+            .checked_mul(self.leverage.to_decimal())
+            .ok_or(ErrorCode::CheckedOperationOverflow)?;
+        require_gte!(
+            self.provider_pool_size,
+            leverage_adjusted_pool
+                .to_u128()
+                .ok_or(ErrorCode::DecimalConversionError)?
+        );
+
+        if respected {
+            let reward = reward.min(self.user_pool_size);
+            self.user_pool_size -= reward;
+            self.provider_pool_size = self
+                .provider_pool_size
+                .checked_add(reward)
+                .ok_or(ErrorCode::CheckedOperationOverflow)?;
+        } else {
+            let reward = reward.min(self.provider_pool_size);
+            self.provider_pool_size -= reward;
+            self.user_pool_size = self
+                .user_pool_size
+                .checked_add(reward)
+                .ok_or(ErrorCode::CheckedOperationOverflow)?;
+        }
+        Ok(respected)
+    }
+}
+
+/// layout of the `Sla` accounts before they were versioned, only used to migrate them
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone)]
+pub struct SlaV0 {
+    pub sla_deployer_address: Pubkey,
+    pub aggregator_address: Pubkey,
+    pub slo: Slo,
+    pub leverage: DslaDecimal,
+    pub mint_address: Pubkey,
+    pub period_data: PeriodGenerator,
+    pub provider_pool_size: u128,
+    pub user_pool_size: u128,
+    pub ut_supply: u128,
+    pub pt_supply: u128,
+    pub severity: Vec<DslaDecimal>,
+    pub penalty: Vec<DslaDecimal>,
+}
+
+impl SlaV0 {
+    /// size the unversioned accounts were allocated with, it counted 32 bytes for an unused messenger address
+    pub const LEN: usize = 8 + // discriminator
+        32 + // sla_deployer_address
+        32 + // aggregator_address
+        32 + // messenger_address
+        Slo::LEN + // SLO
+        12 + // leverage
+        32 + // mint_address
+        PeriodGenerator::LEN + // period_data
+        16 + // provider_pool_size
+        16 + // user_pool_size
+        16 + // ut_supply
+        16 + // pt_supply
+        4 + (DslaDecimal::LEN * 10) + // severity
+        4 + (DslaDecimal::LEN * 10); // penalty
+
+    /// max staleness in seconds the unversioned SLAs validated their switchboard feed with
+    pub const MAX_STALENESS: u32 = 300;
+    /// max confidence interval the unversioned SLAs validated their switchboard feed with
+    pub const MAX_CONFIDENCE_INTERVAL: i64 = 100;
+}
+
+impl From<SlaV0> for Sla {
+    fn from(sla: SlaV0) -> Self {
+        Sla {
+            version: Sla::VERSION,
+            sla_deployer_address: sla.sla_deployer_address,
+            // the unversioned SLAs were validated with their single switchboard aggregator
+            oracle_source: OracleSource::Switchboard,
+            oracle_addresses: vec![sla.aggregator_address],
+            aggregation_policy: AggregationPolicy::Median,
+            min_quorum: 1,
+            max_staleness: SlaV0::MAX_STALENESS,
+            max_confidence_interval: DslaDecimal::from_decimal(Decimal::from(
+                SlaV0::MAX_CONFIDENCE_INTERVAL,
+            )),
+            slo: sla.slo,
+            leverage: sla.leverage,
+            mint_address: sla.mint_address,
+            period_data: sla.period_data,
+            provider_pool_size: sla.provider_pool_size,
+            user_pool_size: sla.user_pool_size,
+            ut_supply: sla.ut_supply,
+            pt_supply: sla.pt_supply,
+            severity: sla.severity,
+            penalty: sla.penalty,
+            cancelled: false,
+            paused: false,
+            checkpointed_periods: 0,
+            pending_transfer: 0,
+        }
+    }
 }
 
 /// `Slo` is service level objective and contains a Decimal number that is the expected value and  SloType
@@ -164,7 +480,7 @@ impl DslaDecimal {
 /// struct used to generate the periods for an SLA with helper function to retrieve any period
 #[derive(AnchorSerialize, AnchorDeserialize, Debug, PartialEq, Eq, Clone)]
 pub struct PeriodGenerator {
-    /// the first timestamp indicating the beginning of the SLA and of the first period
+    /// the first unix timestamp indicating the beginning of the SLA and of the first period
     pub start: u128,
     /// the length of each period
     pub period_length: PeriodLength,
@@ -172,10 +488,14 @@ pub struct PeriodGenerator {
     pub n_periods: u32,
 }
 
+/// the length of the periods of an SLA
 #[derive(AnchorSerialize, AnchorDeserialize, Debug, PartialEq, Eq, Clone)]
 pub enum PeriodLength {
+    /// fixed `length` in seconds
     Custom { length: u128 },
+    /// UTC calendar month, anchored to the day of month of the start
     Monthly,
+    /// UTC calendar year, anchored to the day and month of the start
     Yearly,
 }
 
@@ -225,8 +545,9 @@ impl PeriodGenerator {
                 .start
                 .checked_add(length.checked_mul(period_id as u128).unwrap())
                 .unwrap()),
-            PeriodLength::Monthly => unimplemented!(),
-            PeriodLength::Yearly => unimplemented!(),
+            PeriodLength::Monthly | PeriodLength::Yearly => {
+                self.get_calendar_start(period_id as u64)
+            }
         }
     }
     /// Returns the end timestamp of a given period id
@@ -239,12 +560,17 @@ impl PeriodGenerator {
             PeriodLength::Custom { length } => {
                 msg!(length.to_string().as_ref());
                 Ok(self
-                    .get_start(period_id as usize)?
+                    .get_start(period_id)?
                     .checked_add(length.checked_sub(1).unwrap())
                     .unwrap())
             }
-            PeriodLength::Monthly => unimplemented!(),
-            PeriodLength::Yearly => unimplemented!(),
+            PeriodLength::Monthly | PeriodLength::Yearly => {
+                // check that the period id is valid before looking at the next period
+                self.get_start(period_id)?;
+                self.get_calendar_start((period_id as u64) + 1)?
+                    .checked_sub(1)
+                    .ok_or_else(|| error!(ErrorCode::CheckedOperationOverflow))
+            }
         }
     }
 
@@ -268,27 +594,152 @@ impl PeriodGenerator {
     pub fn get_current_period_id(&self) -> Result<SlaStatus> {
         // @remind to be tested using the client needs the underlying blockchain for time
         let current_timestamp = Clock::get()?.unix_timestamp as u128;
+        self.get_period_id_at(current_timestamp)
+    }
 
-        if current_timestamp > self.get_end((self.n_periods.checked_sub(1).unwrap()) as usize)? {
+    /// returns an enum `SlaStatus` with the period id active at `timestamp`
+    ///
+    /// # Arguments
+    ///
+    /// * `timestamp` - the unix timestamp in seconds to get the status for
+    pub fn get_period_id_at(&self, timestamp: u128) -> Result<SlaStatus> {
+        let last_period = self
+            .n_periods
+            .checked_sub(1)
+            .ok_or(ErrorCode::InvalidPeriodCount)?;
+        if timestamp > self.get_end(last_period as usize)? {
             Ok(SlaStatus::Ended)
-        } else if self.start >= current_timestamp {
+        } else if self.start >= timestamp {
             Ok(SlaStatus::NotStarted)
         } else {
             match self.period_length {
                 PeriodLength::Custom { length } => {
                     // @remind look into this division might cause problems
-                    let period_id = ((current_timestamp.checked_sub(self.start).unwrap())
+                    let period_id = (timestamp - self.start)
                         .checked_div(length)
-                        .unwrap()) as usize;
+                        .ok_or(ErrorCode::CheckedOperationOverflow)?
+                        as usize;
+                    Ok(SlaStatus::Active {
+                        period_id: period_id as u32,
+                    })
+                }
+                PeriodLength::Monthly | PeriodLength::Yearly => {
+                    let elapsed_months = months_between(self.start, timestamp)?;
+                    let period_id = elapsed_months / self.period_length.months();
                     Ok(SlaStatus::Active {
                         period_id: period_id as u32,
                     })
                 }
-                PeriodLength::Monthly => unimplemented!(),
-                PeriodLength::Yearly => unimplemented!(),
             }
         }
     }
+
+    /// start timestamp of the `period_id`-th calendar period, it is not bounded by `n_periods`
+    /// so that it can be used to find the end of the last period
+    fn get_calendar_start(&self, period_id: u64) -> Result<u128> {
+        let months = period_id
+            .checked_mul(self.period_length.months())
+            .ok_or(ErrorCode::CheckedOperationOverflow)?;
+        add_months(self.start, months)
+    }
+}
+
+impl PeriodLength {
+    /// number of calendar months in a `Monthly` or `Yearly` period, 0 for `Custom` periods
+    fn months(&self) -> u64 {
+        match self {
+            PeriodLength::Custom { .. } => 0,
+            PeriodLength::Monthly => 1,
+            PeriodLength::Yearly => 12,
+        }
+    }
+}
+
+const SECONDS_PER_DAY: i64 = 86_400;
+
+/// whether `year` is a leap year in the proleptic Gregorian calendar
+fn is_leap_year(year: i64) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+/// number of days in `month` (1 to 12) of `year`
+fn days_in_month(year: i64, month: u32) -> u32 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// number of days since 1970-01-01 of the UTC date `year`-`month`-`day`
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year.rem_euclid(400);
+    let month = month as i64;
+    let day_of_year =
+        (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + day as i64 - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// UTC date `(year, month, day)` of the day `days` after 1970-01-01
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+/// splits a unix timestamp in seconds into its UTC date and the seconds elapsed in that day
+fn split_timestamp(timestamp: u128) -> Result<((i64, u32, u32), i64)> {
+    let timestamp = i64::try_from(timestamp).map_err(|_| ErrorCode::CheckedOperationOverflow)?;
+    Ok((
+        civil_from_days(timestamp.div_euclid(SECONDS_PER_DAY)),
+        timestamp.rem_euclid(SECONDS_PER_DAY),
+    ))
+}
+
+/// adds `months` calendar months to `timestamp`, keeping the time of day and the day of month
+/// which is clamped to the last day of the target month (e.g. Jan 31 + 1 month = Feb 28 or 29)
+fn add_months(timestamp: u128, months: u64) -> Result<u128> {
+    let ((year, month, day), seconds) = split_timestamp(timestamp)?;
+    let months = i64::try_from(months).map_err(|_| ErrorCode::CheckedOperationOverflow)?;
+    let total_months = (year * 12 + (month as i64 - 1))
+        .checked_add(months)
+        .ok_or(ErrorCode::CheckedOperationOverflow)?;
+    let new_year = total_months.div_euclid(12);
+    let new_month = total_months.rem_euclid(12) as u32 + 1;
+    let new_day = day.min(days_in_month(new_year, new_month));
+
+    let new_timestamp = days_from_civil(new_year, new_month, new_day)
+        .checked_mul(SECONDS_PER_DAY)
+        .and_then(|t| t.checked_add(seconds))
+        .ok_or(ErrorCode::CheckedOperationOverflow)?;
+    u128::try_from(new_timestamp).map_err(|_| error!(ErrorCode::CheckedOperationOverflow))
+}
+
+/// number of whole calendar months elapsed between `from` and `to` anchored to the day of month
+/// and time of `from`, `to` needs to be greater or equal to `from`
+fn months_between(from: u128, to: u128) -> Result<u64> {
+    let ((from_year, from_month, _), _) = split_timestamp(from)?;
+    let ((to_year, to_month, _), _) = split_timestamp(to)?;
+    let mut months =
+        u64::try_from((to_year - from_year) * 12 + to_month as i64 - from_month as i64)
+            .map_err(|_| ErrorCode::CheckedOperationOverflow)?;
+    // the anchor day of the current month might not have been reached yet
+    if months > 0 && add_months(from, months)? > to {
+        months -= 1;
+    }
+    Ok(months)
 }
 
 #[cfg(test)]
@@ -351,4 +802,509 @@ mod tests {
         };
         assert_eq!(period.get_end(9).unwrap(), 599);
     }
+
+    // 2024-01-31T00:00:00Z
+    const JAN_31_2024: u128 = 1706659200;
+    // 2023-12-15T10:30:00Z
+    const DEC_15_2023: u128 = 1702636200;
+
+    #[test]
+    fn get_start_monthly_clamps_to_leap_february() {
+        let period = PeriodGenerator {
+            start: JAN_31_2024,
+            period_length: PeriodLength::Monthly,
+            n_periods: 4,
+        };
+        assert_eq!(period.get_start(0).unwrap(), JAN_31_2024);
+        // 2024-02-29T00:00:00Z
+        assert_eq!(period.get_start(1).unwrap(), 1709164800);
+        // 2024-03-31T00:00:00Z, anchored to the start day of month and not to Feb 29
+        assert_eq!(period.get_start(2).unwrap(), 1711843200);
+        // 2024-04-30T00:00:00Z
+        assert_eq!(period.get_start(3).unwrap(), 1714435200);
+    }
+    #[test]
+    fn get_start_monthly_clamps_to_february() {
+        let period = PeriodGenerator {
+            // 2023-01-31T00:00:00Z
+            start: 1675123200,
+            period_length: PeriodLength::Monthly,
+            n_periods: 3,
+        };
+        // 2023-02-28T00:00:00Z
+        assert_eq!(period.get_start(1).unwrap(), 1677542400);
+        // 2023-03-31T00:00:00Z
+        assert_eq!(period.get_start(2).unwrap(), 1680220800);
+    }
+    #[test]
+    fn get_start_monthly_year_boundary() {
+        let period = PeriodGenerator {
+            start: DEC_15_2023,
+            period_length: PeriodLength::Monthly,
+            n_periods: 3,
+        };
+        // 2024-01-15T10:30:00Z
+        assert_eq!(period.get_start(1).unwrap(), 1705314600);
+        // 2024-02-15T10:30:00Z
+        assert_eq!(period.get_start(2).unwrap(), 1707993000);
+    }
+    #[test]
+    #[should_panic]
+    fn get_start_monthly_id_too_large() {
+        let period = PeriodGenerator {
+            start: DEC_15_2023,
+            period_length: PeriodLength::Monthly,
+            n_periods: 3,
+        };
+        period.get_start(3).unwrap();
+    }
+    #[test]
+    fn get_end_monthly_leap_february() {
+        let period = PeriodGenerator {
+            start: JAN_31_2024,
+            period_length: PeriodLength::Monthly,
+            n_periods: 2,
+        };
+        // 2024-02-28T23:59:59Z
+        assert_eq!(period.get_end(0).unwrap(), 1709164799);
+        // 2024-03-30T23:59:59Z
+        assert_eq!(period.get_end(1).unwrap(), 1711843199);
+    }
+    #[test]
+    #[should_panic]
+    fn get_end_monthly_id_too_large() {
+        let period = PeriodGenerator {
+            start: JAN_31_2024,
+            period_length: PeriodLength::Monthly,
+            n_periods: 2,
+        };
+        period.get_end(2).unwrap();
+    }
+    #[test]
+    fn get_start_yearly_from_leap_day() {
+        let period = PeriodGenerator {
+            // 2024-02-29T00:00:00Z
+            start: 1709164800,
+            period_length: PeriodLength::Yearly,
+            n_periods: 5,
+        };
+        // 2025-02-28T00:00:00Z
+        assert_eq!(period.get_start(1).unwrap(), 1740700800);
+        // 2028-02-29T00:00:00Z
+        assert_eq!(period.get_start(4).unwrap(), 1835395200);
+    }
+    #[test]
+    fn get_end_yearly_valid_last() {
+        let period = PeriodGenerator {
+            start: DEC_15_2023,
+            period_length: PeriodLength::Yearly,
+            n_periods: 2,
+        };
+        // 2024-12-15T10:29:59Z
+        assert_eq!(period.get_end(0).unwrap(), 1734258599);
+        // 2025-12-15T10:29:59Z
+        assert_eq!(period.get_end(1).unwrap(), 1765794599);
+    }
+    #[test]
+    fn get_period_id_at_monthly() {
+        let period = PeriodGenerator {
+            start: JAN_31_2024,
+            period_length: PeriodLength::Monthly,
+            n_periods: 3,
+        };
+        assert_eq!(
+            period.get_period_id_at(JAN_31_2024).unwrap(),
+            SlaStatus::NotStarted
+        );
+        // 2024-02-28T23:59:59Z
+        assert_eq!(
+            period.get_period_id_at(1709164799).unwrap(),
+            SlaStatus::Active { period_id: 0 }
+        );
+        // 2024-02-29T00:00:00Z
+        assert_eq!(
+            period.get_period_id_at(1709164800).unwrap(),
+            SlaStatus::Active { period_id: 1 }
+        );
+        // 2024-03-30T23:59:59Z
+        assert_eq!(
+            period.get_period_id_at(1711843199).unwrap(),
+            SlaStatus::Active { period_id: 1 }
+        );
+        // 2024-03-31T00:00:00Z
+        assert_eq!(
+            period.get_period_id_at(1711843200).unwrap(),
+            SlaStatus::Active { period_id: 2 }
+        );
+        // 2024-04-30T00:00:00Z
+        assert_eq!(
+            period.get_period_id_at(1714435200).unwrap(),
+            SlaStatus::Ended
+        );
+    }
+    #[test]
+    fn get_period_id_at_without_periods() {
+        let period = PeriodGenerator {
+            start: 100,
+            period_length: PeriodLength::Custom { length: 50 },
+            n_periods: 0,
+        };
+        assert_eq!(
+            period.get_period_id_at(120),
+            err!(ErrorCode::InvalidPeriodCount)
+        );
+    }
+    #[test]
+    fn get_period_id_at_yearly() {
+        let period = PeriodGenerator {
+            start: DEC_15_2023,
+            period_length: PeriodLength::Yearly,
+            n_periods: 2,
+        };
+        // 2024-12-15T10:29:59Z
+        assert_eq!(
+            period.get_period_id_at(1734258599).unwrap(),
+            SlaStatus::Active { period_id: 0 }
+        );
+        // 2025-01-15T10:30:00Z
+        assert_eq!(
+            period.get_period_id_at(1736937000).unwrap(),
+            SlaStatus::Active { period_id: 1 }
+        );
+        // 2025-12-15T10:30:00Z
+        assert_eq!(
+            period.get_period_id_at(1765794600).unwrap(),
+            SlaStatus::Ended
+        );
+    }
+
+    fn sla(user_pool_size: u128, provider_pool_size: u128) -> Sla {
+        Sla {
+            version: Sla::VERSION,
+            sla_deployer_address: Pubkey::default(),
+            oracle_source: OracleSource::Switchboard,
+            oracle_addresses: vec![],
+            aggregation_policy: AggregationPolicy::Median,
+            min_quorum: 1,
+            max_staleness: 300,
+            max_confidence_interval: DslaDecimal::from_decimal(Decimal::ONE),
+            slo: Slo {
+                slo_value: DslaDecimal::from_decimal(Decimal::new(100, 0)),
+                slo_type: SloType::GreaterOrEqualTo,
+            },
+            leverage: DslaDecimal::from_decimal(Decimal::ONE),
+            mint_address: Pubkey::default(),
+            period_data: PeriodGenerator {
+                start: 100,
+                period_length: PeriodLength::Custom { length: 50 },
+                n_periods: 4,
+            },
+            provider_pool_size,
+            user_pool_size,
+            ut_supply: 0,
+            pt_supply: 0,
+            severity: vec![],
+            penalty: vec![],
+            cancelled: false,
+            paused: false,
+            checkpointed_periods: 0,
+            pending_transfer: 0,
+        }
+    }
+    #[test]
+    fn settle_period_respected() {
+        let mut sla = sla(1000, 1000);
+        // the deviation is capped at 25%
+        let respected = sla
+            .settle_period(DslaDecimal::from_decimal(Decimal::new(200, 0)), 4, 1000)
+            .unwrap();
+        assert!(respected);
+        assert_eq!(sla.user_pool_size, 938);
+        assert_eq!(sla.provider_pool_size, 1062);
+    }
+    #[test]
+    fn settle_period_not_respected() {
+        let mut sla = sla(1000, 1000);
+        let respected = sla
+            .settle_period(DslaDecimal::from_decimal(Decimal::new(90, 0)), 2, 1000)
+            .unwrap();
+        assert!(!respected);
+        // deviation of 10 / 95
+        assert_eq!(sla.user_pool_size, 1052);
+        assert_eq!(sla.provider_pool_size, 948);
+    }
+    #[test]
+    fn settle_period_provider_pool_too_small() {
+        let mut sla = sla(1000, 999);
+        assert!(sla
+            .settle_period(DslaDecimal::from_decimal(Decimal::new(90, 0)), 2, 1000)
+            .is_err());
+    }
+    #[test]
+    fn settle_period_sized_from_exposed_user_pool() {
+        // 500 tokens were staked after the period finished, they don't change its reward
+        let mut sla = sla(1500, 2000);
+        sla.settle_period(DslaDecimal::from_decimal(Decimal::new(90, 0)), 2, 1000)
+            .unwrap();
+        assert_eq!(sla.user_pool_size, 1552);
+        assert_eq!(sla.provider_pool_size, 1948);
+    }
+    #[test]
+    fn settle_period_capped_at_paying_pool() {
+        // the users that were exposed to the period withdrew since it finished
+        let mut sla = sla(100, 1000);
+        let respected = sla
+            .settle_period(DslaDecimal::from_decimal(Decimal::new(200, 0)), 1, 1000)
+            .unwrap();
+        assert!(respected);
+        assert_eq!(sla.user_pool_size, 0);
+        assert_eq!(sla.provider_pool_size, 1100);
+    }
+    #[test]
+    fn max_transfer_bounds_every_sli() {
+        let mut sla = sla(1000, 3000);
+        sla.leverage = DslaDecimal::from_decimal(Decimal::TWO);
+        sla.severity = decimals(&[0, 9000]);
+        sla.penalty = decimals(&[10, 40]);
+        let max_transfer = sla.max_transfer(1000, 3).unwrap();
+        // 1000 * 2 / 3 * 40%
+        assert_eq!(max_transfer, 266);
+        for sli in [0, 50, 90, 99, 100, 200] {
+            let mut settled = sla.clone();
+            settled
+                .settle_period(DslaDecimal::from_decimal(Decimal::from(sli)), 3, 1000)
+                .unwrap();
+            assert!(settled.user_pool_size.abs_diff(sla.user_pool_size) <= max_transfer);
+        }
+        // the default deviation is capped at 25%
+        sla.penalty = decimals(&[5, 10]);
+        assert_eq!(sla.max_transfer(1000, 1).unwrap(), 500);
+    }
+    #[test]
+    fn finished_periods() {
+        let mut sla = sla(0, 0);
+        assert_eq!(sla.finished_periods(SlaStatus::NotStarted), 0);
+        assert_eq!(sla.finished_periods(SlaStatus::Active { period_id: 2 }), 2);
+        assert_eq!(sla.finished_periods(SlaStatus::Ended), 4);
+        // the periods of a cancelled SLA never ran
+        sla.cancelled = true;
+        assert_eq!(sla.finished_periods(SlaStatus::Ended), 0);
+    }
+    #[test]
+    fn check_checkpointed() {
+        let mut sla = sla(0, 0);
+        sla.checkpointed_periods = 2;
+        assert!(sla.check_checkpointed(SlaStatus::NotStarted).is_ok());
+        assert!(sla
+            .check_checkpointed(SlaStatus::Active { period_id: 2 })
+            .is_ok());
+        assert_eq!(
+            sla.check_checkpointed(SlaStatus::Active { period_id: 3 }),
+            err!(ErrorCode::PeriodsNotCheckpointed)
+        );
+        assert_eq!(
+            sla.check_checkpointed(SlaStatus::Ended),
+            err!(ErrorCode::PeriodsNotCheckpointed)
+        );
+    }
+    #[test]
+    fn release_pending_transfer() {
+        let respected = Status::Respected {
+            value: DslaDecimal::from_decimal(Decimal::ONE),
+            contributors: 1,
+        };
+        let mut sla = sla(0, 0);
+        for max_transfer in [100, 50, 20] {
+            sla.checkpoint_period(max_transfer).unwrap();
+        }
+        assert_eq!(sla.checkpointed_periods, 3);
+        assert_eq!(sla.pending_transfer, 170);
+
+        let mut statuses = vec![
+            respected.clone(),
+            Status::Disputed,
+            Status::NotVerified,
+            Status::NotVerified,
+        ];
+        sla.release_pending_transfer(100, &statuses);
+        assert_eq!(sla.pending_transfer, 70);
+        // the expired period keeps its reservation until every checkpointed period is settled
+        statuses[2] = Status::Expired;
+        statuses[1] = respected;
+        sla.release_pending_transfer(50, &statuses);
+        assert_eq!(sla.pending_transfer, 0);
+    }
+    fn decimals(values: &[i64]) -> Vec<DslaDecimal> {
+        values
+            .iter()
+            .map(|value| DslaDecimal::from_decimal(Decimal::new(*value, 2)))
+            .collect()
+    }
+    #[test]
+    fn check_severity_penalty_increasing() {
+        Sla::check_severity_penalty(&decimals(&[90, 95, 99]), &decimals(&[5, 10, 10])).unwrap();
+        Sla::check_severity_penalty(&[], &[]).unwrap();
+    }
+    #[test]
+    fn check_severity_penalty_invalid() {
+        let invalid = [
+            (decimals(&[90, 95]), decimals(&[5])),
+            (decimals(&[95, 90]), decimals(&[5, 10])),
+            (decimals(&[90, 90]), decimals(&[5, 10])),
+            (decimals(&[90, 95]), decimals(&[10, 5])),
+            (decimals(&[-90, 95]), decimals(&[5, 10])),
+            (decimals(&[1; 11]), decimals(&[1; 11])),
+        ];
+        for (severity, penalty) in invalid {
+            assert_eq!(
+                Sla::check_severity_penalty(&severity, &penalty),
+                err!(ErrorCode::InvalidSeverityPenalty)
+            );
+        }
+    }
+    /// serializes `sla` in an account of `len` bytes like anchor does
+    fn account_data<T: AnchorSerialize>(sla: &T, len: usize) -> Vec<u8> {
+        let mut data = Sla::discriminator().to_vec();
+        sla.serialize(&mut data).unwrap();
+        data.resize(len, 0);
+        data
+    }
+    fn sla_v0(sla: Sla) -> SlaV0 {
+        SlaV0 {
+            sla_deployer_address: sla.sla_deployer_address,
+            aggregator_address: Pubkey::new_from_array([2; 32]),
+            slo: sla.slo,
+            leverage: sla.leverage,
+            mint_address: sla.mint_address,
+            period_data: sla.period_data,
+            provider_pool_size: sla.provider_pool_size,
+            user_pool_size: sla.user_pool_size,
+            ut_supply: sla.ut_supply,
+            pt_supply: sla.pt_supply,
+            severity: sla.severity,
+            penalty: sla.penalty,
+        }
+    }
+    fn largest_sla() -> Sla {
+        Sla {
+            oracle_addresses: vec![Pubkey::default(); MAX_ORACLES],
+            aggregation_policy: AggregationPolicy::TrimmedMean { trim: 1 },
+            severity: decimals(&[1; 10]),
+            penalty: decimals(&[1; 10]),
+            ..sla(1000, 2000)
+        }
+    }
+    #[test]
+    fn sla_len() {
+        assert!(largest_sla().try_to_vec().unwrap().len() + 8 <= Sla::LEN);
+        // the unversioned size counted the unused messenger address and one byte too many for `SloType`
+        assert_eq!(
+            sla_v0(largest_sla()).try_to_vec().unwrap().len() + 8 + 32 + 1,
+            SlaV0::LEN
+        );
+    }
+    #[test]
+    fn migrate_v0() {
+        let legacy = sla_v0(Sla {
+            ut_supply: 300,
+            severity: decimals(&[90, 95]),
+            penalty: decimals(&[5, 10]),
+            ..largest_sla()
+        });
+        let data = account_data(&legacy, SlaV0::LEN);
+        let sla = Sla::migrate(&data).unwrap();
+        assert_eq!(sla.version, Sla::VERSION);
+        assert_eq!(sla.oracle_source, OracleSource::Switchboard);
+        assert_eq!(sla.oracle_addresses, vec![Pubkey::new_from_array([2; 32])]);
+        assert_eq!(sla.aggregation_policy, AggregationPolicy::Median);
+        assert_eq!(sla.min_quorum, 1);
+        assert_eq!(sla.max_staleness, 300);
+        assert_eq!(
+            sla.max_confidence_interval,
+            DslaDecimal::from_decimal(Decimal::new(100, 0))
+        );
+        assert_eq!(sla.user_pool_size, 1000);
+        assert_eq!(sla.provider_pool_size, 2000);
+        assert_eq!(sla.ut_supply, 300);
+        assert_eq!(sla.period_data.n_periods, 4);
+        assert_eq!(sla.severity, decimals(&[90, 95]));
+        assert_eq!(sla.penalty, decimals(&[5, 10]));
+
+        // the migrated account deserializes with the current layout
+        let migrated = account_data(&sla, Sla::LEN);
+        let deserialized = Sla::try_deserialize(&mut &migrated[..]).unwrap();
+        assert_eq!(
+            deserialized.try_to_vec().unwrap(),
+            sla.try_to_vec().unwrap()
+        );
+    }
+    #[test]
+    fn migrate_v0_bytes() {
+        // the account as written by the program before versioning
+        let mut data = Sla::discriminator().to_vec();
+        data.extend_from_slice(&[1; 32]); // sla_deployer_address
+        data.extend_from_slice(&[2; 32]); // aggregator_address
+        data.extend_from_slice(&95i64.to_le_bytes()); // slo value mantissa
+        data.extend_from_slice(&1u32.to_le_bytes()); // slo value scale
+        data.push(5); // slo type GreaterOrEqualTo
+        data.extend_from_slice(&2i64.to_le_bytes()); // leverage mantissa
+        data.extend_from_slice(&0u32.to_le_bytes()); // leverage scale
+        data.extend_from_slice(&[3; 32]); // mint_address
+        data.extend_from_slice(&100u128.to_le_bytes()); // start
+        data.push(0); // period length Custom
+        data.extend_from_slice(&50u128.to_le_bytes()); // length
+        data.extend_from_slice(&4u32.to_le_bytes()); // n_periods
+        for amount in [2000u128, 1000, 300, 400] {
+            // provider_pool_size, user_pool_size, ut_supply, pt_supply
+            data.extend_from_slice(&amount.to_le_bytes());
+        }
+        for values in [[90i64, 95], [5, 10]] {
+            // severity and penalty
+            data.extend_from_slice(&2u32.to_le_bytes());
+            for mantissa in values {
+                data.extend_from_slice(&mantissa.to_le_bytes());
+                data.extend_from_slice(&2u32.to_le_bytes());
+            }
+        }
+        data.resize(SlaV0::LEN, 0);
+
+        let sla = Sla::migrate(&data).unwrap();
+        assert_eq!(sla.sla_deployer_address, Pubkey::new_from_array([1; 32]));
+        assert_eq!(sla.oracle_addresses, vec![Pubkey::new_from_array([2; 32])]);
+        assert_eq!(
+            sla.slo.slo_value,
+            DslaDecimal::from_decimal(Decimal::new(95, 1))
+        );
+        assert!(matches!(sla.slo.slo_type, SloType::GreaterOrEqualTo));
+        assert_eq!(sla.leverage, DslaDecimal::from_decimal(Decimal::new(2, 0)));
+        assert_eq!(sla.mint_address, Pubkey::new_from_array([3; 32]));
+        assert_eq!(sla.period_data.start, 100);
+        assert_eq!(
+            sla.period_data.period_length,
+            PeriodLength::Custom { length: 50 }
+        );
+        assert_eq!(sla.period_data.n_periods, 4);
+        assert_eq!(sla.provider_pool_size, 2000);
+        assert_eq!(sla.user_pool_size, 1000);
+        assert_eq!(sla.ut_supply, 300);
+        assert_eq!(sla.pt_supply, 400);
+        assert_eq!(sla.severity, decimals(&[90, 95]));
+        assert_eq!(sla.penalty, decimals(&[5, 10]));
+    }
+    #[test]
+    fn migrate_current_version() {
+        let data = account_data(&sla(0, 0), Sla::LEN);
+        assert_eq!(
+            Sla::migrate(&data).err(),
+            Some(error!(ErrorCode::SlaAlreadyMigrated))
+        );
+    }
+    #[test]
+    fn status_cancelled() {
+        let sla = Sla {
+            cancelled: true,
+            ..sla(0, 0)
+        };
+        assert_eq!(sla.status().unwrap(), SlaStatus::Ended);
+    }
 }
@@ -1,7 +1,303 @@
 use anchor_lang::prelude::*;
 
-/// the `SlaRegistry` is an account with a vector containing the public keys for all SLAs
+use crate::constants::{DEPLOYER_INDEX_SEED, MINT_INDEX_SEED};
+use crate::errors::ErrorCode;
+
+/// the `SlaRegistryHeader` is the header of a registry of SLAs, the global registry of all SLAs
+/// or the index of the SLAs of a deployer or a mint,
+/// the public keys of the SLAs are stored by id in fixed size `SlaRegistryPage` accounts
+#[account]
+pub struct SlaRegistryHeader {
+    /// number of SLAs registered, the id of the next SLA
+    pub sla_count: u64,
+    /// number of pages created
+    pub page_count: u64,
+    /// number of SLAs stored in each page
+    pub page_size: u32,
+}
+
+impl SlaRegistryHeader {
+    pub const LEN: usize = 8 // discriminator
+    + 8 // sla_count
+    + 8 // page_count
+    + 4 // page_size
+    ;
+
+    /// Returns the index of the page storing the SLA with id `sla_id`
+    pub fn page_of(&self, sla_id: u64) -> u64 {
+        sla_id / self.page_size as u64
+    }
+
+    /// whether all the pages are full and a new one needs to be created for the next SLA
+    pub fn is_full(&self) -> bool {
+        self.sla_count == self.page_count * self.page_size as u64
+    }
+
+    /// Returns the index of the page storing the next SLA of an index,
+    /// an index created on demand by `deploy_sla` has no page size yet and starts with page 0
+    pub fn next_page(&self) -> u64 {
+        match self.page_size {
+            0 => 0,
+            _ => self.page_of(self.sla_count),
+        }
+    }
+
+    /// sets up an index created on demand and counts the page created on demand for the next SLA,
+    /// when all the pages of the index are full
+    ///
+    /// # Arguments
+    ///
+    /// * `page_size` - the number of SLAs stored in each page of the index
+    pub fn open_next_page(&mut self, page_size: u32) -> Result<()> {
+        if self.page_size == 0 {
+            self.page_size = page_size;
+        }
+        if self.is_full() {
+            self.page_count = self
+                .page_count
+                .checked_add(1)
+                .ok_or(ErrorCode::CheckedOperationOverflow)?;
+        }
+        Ok(())
+    }
+
+    /// adds `sla` to the last page and returns its id
+    ///
+    /// # Arguments
+    ///
+    /// * `page` - the page storing the next SLA id
+    /// * `sla` - the address of the SLA
+    pub fn register(&mut self, page: &mut SlaRegistryPage, sla: Pubkey) -> Result<u64> {
+        require!(!self.is_full(), ErrorCode::SlaRegistryFull);
+        require_eq!(
+            page.sla_account_addresses.len() as u64,
+            self.sla_count % self.page_size as u64,
+            ErrorCode::SlaRegistryFull
+        );
+        let sla_id = self.sla_count;
+        page.sla_account_addresses.push(sla);
+        self.sla_count = self
+            .sla_count
+            .checked_add(1)
+            .ok_or(ErrorCode::CheckedOperationOverflow)?;
+        Ok(sla_id)
+    }
+
+    /// replaces `sla` by the default public key in its page, so the ids of the other SLAs don't change
+    ///
+    /// # Arguments
+    ///
+    /// * `page` - the page storing the SLA id
+    /// * `sla_id` - the id of the SLA in this registry
+    /// * `sla` - the address of the SLA
+    pub fn unregister(&self, page: &mut SlaRegistryPage, sla_id: u64, sla: Pubkey) -> Result<()> {
+        let slot = (sla_id % self.page_size as u64) as usize;
+        require!(
+            sla_id < self.sla_count && page.sla_account_addresses.get(slot) == Some(&sla),
+            ErrorCode::SlaNotRegistered
+        );
+        page.sla_account_addresses[slot] = Pubkey::default();
+        Ok(())
+    }
+}
+
+/// page of an `SlaRegistryHeader` with the public keys of `page_size` consecutive SLA ids,
+/// the SLAs removed from an index are replaced by the default public key
 #[account]
-pub struct SlaRegistry {
+pub struct SlaRegistryPage {
     pub sla_account_addresses: Vec<Pubkey>,
 }
+
+impl SlaRegistryPage {
+    /// Returns the size of the account of a page storing `page_size` SLAs
+    pub fn space(page_size: u32) -> usize {
+        8 // discriminator
+        + 4 + (32 * page_size as usize) // sla_account_addresses
+    }
+}
+
+/// the kind of key the SLAs of an index are registered by
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, PartialEq, Eq, Clone, Copy)]
+pub enum SlaIndexKind {
+    /// SLAs of a `sla_deployer_address`
+    Deployer,
+    /// SLAs of a `mint_address`
+    Mint,
+}
+
+impl SlaIndexKind {
+    /// Returns the seed of the header of the indexes of this kind
+    pub fn seed(&self) -> &'static str {
+        match self {
+            SlaIndexKind::Deployer => DEPLOYER_INDEX_SEED,
+            SlaIndexKind::Mint => MINT_INDEX_SEED,
+        }
+    }
+}
+
+/// marker of a registered SLA, its address is derived from the SLA address
+#[account]
+pub struct SlaRegistryEntry {
+    /// sequential id of the SLA in the global `SlaRegistryHeader`
+    pub sla_id: u64,
+    /// id of the SLA in the index of its deployer
+    pub deployer_index_id: u64,
+    /// id of the SLA in the index of its mint
+    pub mint_index_id: u64,
+}
+
+impl SlaRegistryEntry {
+    pub const LEN: usize = 8 // discriminator
+    + 8 // sla_id
+    + 8 // deployer_index_id
+    + 8 // mint_index_id
+    ;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SLAS_PER_PAGE: usize = crate::constants::SLAS_PER_PAGE as usize;
+
+    fn registry(sla_count: u64, page_count: u64) -> SlaRegistryHeader {
+        SlaRegistryHeader {
+            sla_count,
+            page_count,
+            page_size: SLAS_PER_PAGE as u32,
+        }
+    }
+
+    #[test]
+    fn page_of() {
+        let registry = registry(0, 1);
+        assert_eq!(registry.page_of(0), 0);
+        assert_eq!(registry.page_of(SLAS_PER_PAGE as u64 - 1), 0);
+        assert_eq!(registry.page_of(SLAS_PER_PAGE as u64), 1);
+    }
+    #[test]
+    fn register_sequential_ids() {
+        let mut registry = registry(0, 1);
+        let mut page = SlaRegistryPage {
+            sla_account_addresses: vec![],
+        };
+        let first = Pubkey::new_from_array([1; 32]);
+        let second = Pubkey::new_from_array([2; 32]);
+        assert_eq!(registry.register(&mut page, first).unwrap(), 0);
+        assert_eq!(registry.register(&mut page, second).unwrap(), 1);
+        assert_eq!(registry.sla_count, 2);
+        assert_eq!(page.sla_account_addresses, vec![first, second]);
+    }
+    #[test]
+    fn register_full_page() {
+        let mut registry = registry(SLAS_PER_PAGE as u64, 1);
+        assert!(registry.is_full());
+        let mut page = SlaRegistryPage {
+            sla_account_addresses: vec![Pubkey::default(); SLAS_PER_PAGE],
+        };
+        assert_eq!(
+            registry.register(&mut page, Pubkey::default()),
+            err!(ErrorCode::SlaRegistryFull)
+        );
+    }
+    #[test]
+    fn register_wrong_page() {
+        let mut registry = registry(SLAS_PER_PAGE as u64 + 1, 2);
+        let mut page = SlaRegistryPage {
+            sla_account_addresses: vec![Pubkey::default(); SLAS_PER_PAGE],
+        };
+        assert_eq!(
+            registry.register(&mut page, Pubkey::default()),
+            err!(ErrorCode::SlaRegistryFull)
+        );
+    }
+    #[test]
+    fn unregister_keeps_ids() {
+        let mut registry = registry(0, 1);
+        let mut page = SlaRegistryPage {
+            sla_account_addresses: vec![],
+        };
+        let first = Pubkey::new_from_array([1; 32]);
+        let second = Pubkey::new_from_array([2; 32]);
+        registry.register(&mut page, first).unwrap();
+        registry.register(&mut page, second).unwrap();
+        registry.unregister(&mut page, 0, first).unwrap();
+        assert_eq!(page.sla_account_addresses, vec![Pubkey::default(), second]);
+        assert_eq!(registry.sla_count, 2);
+        assert_eq!(
+            registry.unregister(&mut page, 0, first),
+            err!(ErrorCode::SlaNotRegistered)
+        );
+    }
+    #[test]
+    fn unregister_wrong_sla() {
+        let mut registry = registry(0, 1);
+        let mut page = SlaRegistryPage {
+            sla_account_addresses: vec![],
+        };
+        let sla = Pubkey::new_from_array([1; 32]);
+        registry.register(&mut page, sla).unwrap();
+        assert_eq!(
+            registry.unregister(&mut page, 0, Pubkey::new_from_array([2; 32])),
+            err!(ErrorCode::SlaNotRegistered)
+        );
+        assert_eq!(
+            registry.unregister(&mut page, 1, sla),
+            err!(ErrorCode::SlaNotRegistered)
+        );
+        assert_eq!(page.sla_account_addresses, vec![sla]);
+    }
+    #[test]
+    fn page_space_fits_max_slas() {
+        let page = SlaRegistryPage {
+            sla_account_addresses: vec![Pubkey::default(); SLAS_PER_PAGE],
+        };
+        assert_eq!(
+            page.try_to_vec().unwrap().len() + 8,
+            SlaRegistryPage::space(SLAS_PER_PAGE as u32)
+        );
+    }
+    #[test]
+    fn open_next_page_of_new_index() {
+        let mut index = registry(0, 0);
+        index.page_size = 0;
+        assert_eq!(index.next_page(), 0);
+        index.open_next_page(2).unwrap();
+        assert_eq!((index.page_size, index.page_count), (2, 1));
+        let mut page = SlaRegistryPage {
+            sla_account_addresses: vec![],
+        };
+        assert_eq!(index.register(&mut page, Pubkey::default()).unwrap(), 0);
+        index.open_next_page(2).unwrap();
+        assert_eq!(index.page_count, 1);
+    }
+    #[test]
+    fn open_next_page_of_full_index() {
+        let mut index = registry(SLAS_PER_PAGE as u64, 1);
+        assert_eq!(index.next_page(), 1);
+        index.open_next_page(SLAS_PER_PAGE as u32).unwrap();
+        assert_eq!(index.page_count, 2);
+        let mut page = SlaRegistryPage {
+            sla_account_addresses: vec![],
+        };
+        assert_eq!(
+            index.register(&mut page, Pubkey::default()).unwrap(),
+            SLAS_PER_PAGE as u64
+        );
+    }
+    #[test]
+    fn register_small_pages() {
+        let mut registry = SlaRegistryHeader {
+            sla_count: 0,
+            page_count: 1,
+            page_size: 2,
+        };
+        let mut page = SlaRegistryPage {
+            sla_account_addresses: vec![],
+        };
+        registry.register(&mut page, Pubkey::default()).unwrap();
+        registry.register(&mut page, Pubkey::default()).unwrap();
+        assert!(registry.is_full());
+        assert_eq!(registry.page_of(2), 1);
+    }
+}
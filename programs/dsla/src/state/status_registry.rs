@@ -1,25 +1,379 @@
 use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
 
 use super::DslaDecimal;
+use crate::errors::ErrorCode;
+
 /// the registry with the stored status of each period after validation
 #[account]
 pub struct StatusRegistry {
+    /// version of the layout of the account, `StatusRegistry::VERSION` once deployed or migrated
+    pub version: u8,
     pub status_registry: Vec<Status>,
 }
 
 /// Enum defining the status
+///
+/// bit `i` of `contributors` is set when the oracle at index `i` of `Sla::oracle_addresses` was used
 #[derive(AnchorSerialize, AnchorDeserialize, Debug, PartialEq, Eq, Clone)]
 pub enum Status {
     /// Period wasn't verified yet
     NotVerified,
-    /// Period was respected with `value`
-    Respected { value: DslaDecimal },
-    /// Period wasn't respected with `value`
-    NotRespected { value: DslaDecimal },
+    /// Period was respected with `value`, aggregated from the feeds in the `contributors` bitmask
+    Respected {
+        value: DslaDecimal,
+        contributors: u8,
+    },
+    /// Period wasn't respected with `value`, aggregated from the feeds in the `contributors` bitmask
+    NotRespected {
+        value: DslaDecimal,
+        contributors: u8,
+    },
+    /// An SLI was proposed for the period and can be disputed until the end of the dispute window
+    Proposed,
+    /// The SLI proposed for the period was disputed and waits for the arbiter
+    Disputed,
+    /// Period was never verified and its DSLA deposit was reclaimed
+    Expired,
+}
+
+impl Status {
+    /// serialized size of the largest variant
+    pub const LEN: usize = 1 // variant
+    + DslaDecimal::LEN // value
+    + 1 // contributors
+    ;
+
+    /// Returns the final status of a period settled with `value`
+    ///
+    /// # Arguments
+    ///
+    /// * `respected` - whether `value` respects the SLO of the SLA
+    /// * `value` - the final SLI of the period
+    /// * `contributors` - the bitmask of the oracle feeds used for `value`, 0 for optimistic validation
+    pub fn settled(respected: bool, value: DslaDecimal, contributors: u8) -> Self {
+        if respected {
+            Status::Respected {
+                value,
+                contributors,
+            }
+        } else {
+            Status::NotRespected {
+                value,
+                contributors,
+            }
+        }
+    }
+
+    /// whether the period has its final status
+    pub fn is_settled(&self) -> bool {
+        matches!(
+            self,
+            Status::Respected { .. } | Status::NotRespected { .. } | Status::Expired
+        )
+    }
 }
 
 impl StatusRegistry {
+    /// layout version of the accounts deployed or migrated by this program
+    pub const VERSION: u8 = 1;
+
     pub fn new_vec(n_periods: u32) -> Vec<Status> {
         vec![Status::NotVerified; n_periods as usize]
     }
+
+    /// Returns the size of the account of a registry with `n_periods`
+    pub fn space(n_periods: u32) -> usize {
+        8 // discriminator
+        + 1 // version
+        + 4 // status_registry length
+        + Status::LEN * n_periods as usize
+        + 16 // reserved for new fields, zeroed after the serialized fields
+    }
+
+    /// whether the registry has the current layout and the `n_periods` of its SLA,
+    /// an unversioned registry can deserialize with the current layout but never with the periods of its SLA
+    pub fn is_current(&self, n_periods: u32) -> bool {
+        self.version == StatusRegistry::VERSION && self.status_registry.len() == n_periods as usize
+    }
+
+    /// Returns the registry stored in the account `data` upgraded to the current layout
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - the data of a status registry account of any layout version older than the current one
+    pub fn migrate(data: &[u8]) -> Result<StatusRegistry> {
+        require!(
+            data.len() >= 8 && data[..8] == StatusRegistry::discriminator(),
+            anchor_lang::error::ErrorCode::AccountDiscriminatorMismatch
+        );
+        // accounts deployed before versioning have exactly the size of the unversioned layout
+        if data.len() == StatusRegistryV0::LEN {
+            let registry = StatusRegistryV0::deserialize(&mut &data[8..])?;
+            return Ok(registry.into());
+        }
+        let registry = StatusRegistry::try_deserialize(&mut &data[..])?;
+        require_gt!(
+            StatusRegistry::VERSION,
+            registry.version,
+            ErrorCode::StatusRegistryAlreadyMigrated
+        );
+        Ok(registry)
+    }
+
+    /// Returns the ids of the periods that still need to be verified, starting from the first one
+    ///
+    /// # Arguments
+    ///
+    /// * `finished_periods` - number of periods that have finished
+    /// * `max_periods` - max number of period ids to return
+    pub fn pending_periods(&self, finished_periods: usize, max_periods: usize) -> Vec<usize> {
+        self.status_registry
+            .iter()
+            .take(finished_periods)
+            .enumerate()
+            .filter(|(_, status)| **status == Status::NotVerified)
+            .map(|(period, _)| period)
+            .take(max_periods)
+            .collect()
+    }
+
+    /// whether every period has its final status
+    pub fn all_settled(&self) -> bool {
+        self.status_registry.iter().all(Status::is_settled)
+    }
+
+    /// marks all the periods that were never verified as expired and returns their number
+    pub fn expire_unverified(&mut self) -> u32 {
+        let mut expired = 0;
+        for status in self
+            .status_registry
+            .iter_mut()
+            .filter(|status| **status == Status::NotVerified)
+        {
+            *status = Status::Expired;
+            expired += 1;
+        }
+        expired
+    }
+}
+
+/// layout of the `StatusRegistry` accounts before they were versioned, only used to migrate them
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone)]
+pub struct StatusRegistryV0 {
+    pub status_registry: Vec<StatusV0>,
+}
+
+impl StatusRegistryV0 {
+    /// size the unversioned accounts were allocated with whatever their number of periods,
+    /// `StatusRegistry::space` never gives it so the unversioned accounts are told apart by their size
+    pub const LEN: usize = 10_000;
+}
+
+/// statuses of the unversioned registries, they didn't record the feeds used for the SLI
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, PartialEq, Eq, Clone)]
+pub enum StatusV0 {
+    NotVerified,
+    Respected { value: DslaDecimal },
+    NotRespected { value: DslaDecimal },
+}
+
+impl From<StatusV0> for Status {
+    fn from(status: StatusV0) -> Self {
+        // the unversioned SLAs were validated with their single switchboard aggregator, at index 0
+        match status {
+            StatusV0::NotVerified => Status::NotVerified,
+            StatusV0::Respected { value } => Status::settled(true, value, 1),
+            StatusV0::NotRespected { value } => Status::settled(false, value, 1),
+        }
+    }
+}
+
+impl From<StatusRegistryV0> for StatusRegistry {
+    fn from(registry: StatusRegistryV0) -> Self {
+        StatusRegistry {
+            version: StatusRegistry::VERSION,
+            status_registry: registry
+                .status_registry
+                .into_iter()
+                .map(Status::from)
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal::Decimal;
+
+    fn respected() -> Status {
+        Status::Respected {
+            value: DslaDecimal::from_decimal(Decimal::ONE),
+            contributors: 1,
+        }
+    }
+
+    #[test]
+    fn pending_periods_skips_verified() {
+        let registry = StatusRegistry {
+            version: StatusRegistry::VERSION,
+            status_registry: vec![
+                respected(),
+                Status::NotVerified,
+                respected(),
+                Status::NotVerified,
+                Status::NotVerified,
+            ],
+        };
+        assert_eq!(registry.pending_periods(5, 10), vec![1, 3, 4]);
+    }
+    #[test]
+    fn pending_periods_only_finished() {
+        let registry = StatusRegistry {
+            version: StatusRegistry::VERSION,
+            status_registry: StatusRegistry::new_vec(5),
+        };
+        assert_eq!(registry.pending_periods(2, 10), vec![0, 1]);
+        assert!(registry.pending_periods(0, 10).is_empty());
+    }
+    #[test]
+    fn pending_periods_bounded() {
+        let registry = StatusRegistry {
+            version: StatusRegistry::VERSION,
+            status_registry: StatusRegistry::new_vec(5),
+        };
+        assert_eq!(registry.pending_periods(5, 3), vec![0, 1, 2]);
+    }
+    #[test]
+    fn all_settled() {
+        let mut registry = StatusRegistry {
+            version: StatusRegistry::VERSION,
+            status_registry: vec![respected(), respected()],
+        };
+        assert!(registry.all_settled());
+        registry.status_registry[1] = Status::Expired;
+        assert!(registry.all_settled());
+        for status in [Status::NotVerified, Status::Proposed, Status::Disputed] {
+            registry.status_registry[1] = status;
+            assert!(!registry.all_settled());
+        }
+    }
+    #[test]
+    fn expire_unverified() {
+        let mut registry = StatusRegistry {
+            version: StatusRegistry::VERSION,
+            status_registry: vec![
+                respected(),
+                Status::NotVerified,
+                Status::Proposed,
+                Status::NotVerified,
+                Status::Expired,
+            ],
+        };
+        assert_eq!(registry.expire_unverified(), 2);
+        assert_eq!(
+            registry.status_registry,
+            vec![
+                respected(),
+                Status::Expired,
+                Status::Proposed,
+                Status::Expired,
+                Status::Expired,
+            ]
+        );
+        assert_eq!(registry.expire_unverified(), 0);
+    }
+    #[test]
+    fn space_fits_largest_status() {
+        let registry = StatusRegistry {
+            version: StatusRegistry::VERSION,
+            status_registry: vec![
+                Status::NotRespected {
+                    value: DslaDecimal::from_decimal(Decimal::new(-99999, 3)),
+                    contributors: u8::MAX,
+                },
+                respected(),
+            ],
+        };
+        assert_eq!(
+            registry.try_to_vec().unwrap().len() + 8 + 16,
+            StatusRegistry::space(2)
+        );
+    }
+    #[test]
+    fn space_never_unversioned_len() {
+        assert!((0..=crate::constants::MAX_PERIODS)
+            .all(|n_periods| StatusRegistry::space(n_periods) != StatusRegistryV0::LEN));
+    }
+    fn account_data<T: AnchorSerialize>(registry: &T, len: usize) -> Vec<u8> {
+        let mut data = StatusRegistry::discriminator().to_vec();
+        registry.serialize(&mut data).unwrap();
+        data.resize(len, 0);
+        data
+    }
+    #[test]
+    fn migrate_v0() {
+        let value = DslaDecimal::from_decimal(Decimal::new(995, 1));
+        let legacy = StatusRegistryV0 {
+            status_registry: vec![
+                StatusV0::Respected { value },
+                StatusV0::NotRespected { value },
+                StatusV0::NotVerified,
+            ],
+        };
+        let data = account_data(&legacy, StatusRegistryV0::LEN);
+        let registry = StatusRegistry::migrate(&data).unwrap();
+        assert_eq!(registry.version, StatusRegistry::VERSION);
+        assert_eq!(
+            registry.status_registry,
+            vec![
+                Status::Respected {
+                    value,
+                    contributors: 1
+                },
+                Status::NotRespected {
+                    value,
+                    contributors: 1
+                },
+                Status::NotVerified,
+            ]
+        );
+        assert!(registry.is_current(3));
+
+        // the migrated account deserializes with the current layout
+        let migrated = account_data(&registry, StatusRegistry::space(3));
+        let deserialized = StatusRegistry::try_deserialize(&mut &migrated[..]).unwrap();
+        assert_eq!(deserialized.status_registry, registry.status_registry);
+    }
+    #[test]
+    fn unversioned_registry_is_not_current() {
+        // a single unverified period deserializes with the current layout as an empty registry
+        let legacy = StatusRegistryV0 {
+            status_registry: vec![StatusV0::NotVerified],
+        };
+        let data = account_data(&legacy, StatusRegistryV0::LEN);
+        let registry = StatusRegistry::try_deserialize(&mut &data[..]).unwrap();
+        assert_eq!(registry.version, StatusRegistry::VERSION);
+        assert!(!registry.is_current(1));
+    }
+    #[test]
+    fn migrate_current_version() {
+        let registry = StatusRegistry {
+            version: StatusRegistry::VERSION,
+            status_registry: StatusRegistry::new_vec(2),
+        };
+        let data = account_data(&registry, StatusRegistry::space(2));
+        assert_eq!(
+            StatusRegistry::migrate(&data).err(),
+            Some(error!(ErrorCode::StatusRegistryAlreadyMigrated))
+        );
+    }
+    #[test]
+    fn space_of_max_periods_can_be_allocated() {
+        assert!(
+            StatusRegistry::space(crate::constants::MAX_PERIODS)
+                <= anchor_lang::solana_program::entrypoint::MAX_PERMITTED_DATA_INCREASE
+        );
+    }
 }
@@ -1,5 +1,4 @@
 import { Keypair, PublicKey } from "@solana/web3.js";
-export const SLA_REGISTRY_SPACE = 10_000_000;
 import { BN } from "@project-serum/anchor";
 import * as anchor from "@project-serum/anchor";
 
@@ -11,12 +10,22 @@ export const POOL_SEED: string = "vault";
 export const UT_MINT_SEED: string = "ut-mint";
 export const PT_MINT_SEED: string = "pt-mint";
 export const GOVERNANCE_SEED: string = "governance";
+export const GOVERNANCE_CHANGE_SEED: string = "governance-change";
 export const UT_ACCOUNT_SEED: string = "ut-account";
 export const PT_ACCOUNT_SEED: string = "pt-account";
+export const SLA_REGISTRY_HEADER_SEED: string = "sla-registry-header";
+export const SLA_REGISTRY_PAGE_SEED: string = "sla-registry-page";
+export const SLA_REGISTRY_ENTRY_SEED: string = "sla-registry-entry";
+export const DEPLOYER_INDEX_SEED: string = "deployer-index";
+export const MINT_INDEX_SEED: string = "mint-index";
+export const SLA_INDEX_PAGE_SEED: string = "sla-index-page";
+
+// PRICING
+// UT and PT minted for each staked token at par
+export const VIRTUAL_SHARES = new BN(1000);
 
 // KEYPAIRS
 export const SLA_REGISTRY_DEPLOYER = Keypair.generate();
-export const SLA_REGISTRY_KEYPAIR = Keypair.generate();
 export const MINT_AUTHORITY: Keypair = Keypair.generate();
 export const DSLA_MINT_AUTHORITY: Keypair = Keypair.generate();
 export const SLA_PROTOCOL_DEPLOYER = {
@@ -77,6 +86,29 @@ export const GOVERNANCE_PARAMETERS = {
     mantissa: new BN(10),
     scale: new BN("0"),
   },
+  defaultMaxStaleness: 60 * 60, // 1 hour
+  maxStalenessLimit: 24 * 60 * 60, // 1 day
+  defaultMaxConfidenceInterval: {
+    mantissa: new BN("1"),
+    scale: new BN("2"),
+  }, // 0.01
+  maxConfidenceIntervalLimit: {
+    mantissa: new BN("1"),
+    scale: new BN("1"),
+  }, // 0.1
+  attestationWindow: 60 * 60, // 1 hour
+  disputeWindow: 24 * 60 * 60, // 1 day
+  proposalBond: new BN(dslaDepositByPeriod),
+  disputeBond: new BN(dslaDepositByPeriod),
+  arbiter: SLA_PROTOCOL_DEPLOYER.publicKey,
+  protocolTreasury: SLA_PROTOCOL_DEPLOYER.publicKey,
+  depositReclaimGracePeriod: 30 * 24 * 60 * 60, // 30 days
+  depositReclaimPolicy: { protocolTreasury: {} },
+  slaCancellationFeeRate: {
+    mantissa: new BN("1"),
+    scale: new BN("1"),
+  }, // 10%
+  guardian: SLA_PROTOCOL_DEPLOYER.publicKey,
 };
 export const SLOS = [
   {
@@ -110,6 +142,13 @@ export const SLAS = [
     start: new BN(Date.now() + 1000),
     nPeriods: new BN("100"),
     periodLength: { custom: { length: new BN(1000 * 60 * 60) } },
+    severity: [],
+    penalty: [],
+    oracleSource: { optimistic: {} },
+    aggregationPolicy: { median: {} },
+    minQuorum: 0,
+    maxStaleness: null,
+    maxConfidenceInterval: null,
   },
   {
     id: 1,
@@ -119,6 +158,13 @@ export const SLAS = [
     start: new BN(Date.now() + 2000),
     nPeriods: new BN("1"),
     periodLength: { custom: { length: new BN(1000 * 60) } },
+    severity: [],
+    penalty: [],
+    oracleSource: { optimistic: {} },
+    aggregationPolicy: { median: {} },
+    minQuorum: 0,
+    maxStaleness: null,
+    maxConfidenceInterval: null,
   },
   {
     id: 2,
@@ -128,5 +174,12 @@ export const SLAS = [
     start: new BN(Date.now() + 3000),
     nPeriods: new BN("1000"),
     periodLength: { custom: { length: new BN(1000 * 60 * 60 * 24) } },
+    severity: [],
+    penalty: [],
+    oracleSource: { optimistic: {} },
+    aggregationPolicy: { median: {} },
+    minQuorum: 0,
+    maxStaleness: null,
+    maxConfidenceInterval: null,
   },
 ];
@@ -1,17 +1,42 @@
 import { getOrCreateAssociatedTokenAccount } from "@solana/spl-token";
-import { Keypair } from "@solana/web3.js";
+import { PublicKey } from "@solana/web3.js";
+import { BN } from "@project-serum/anchor";
 import { expect } from "chai";
 import {
   SLA_DEPLOYERS,
-  SLA_REGISTRY_KEYPAIR,
   SLA_KEYPAIRS,
   SLAS,
+  DEPLOYER_INDEX_SEED,
+  MINT_INDEX_SEED,
+  SLA_REGISTRY_ENTRY_SEED,
 } from "./constants";
 import { connection, program, mint, dsla_mint } from "./init";
+import {
+  slaRegistryPda,
+  slaRegistryPagePda,
+  slaIndexPda,
+  slaIndexPagePda,
+} from "./utils";
 
 describe("Deploy SLA", () => {
   let expectedSlaAccountAddresses = [];
 
+  before(async function () {
+    // the index of the mint can be initialized ahead of time,
+    // the indexes of the deployers are created on demand by their first SLA
+    const mintIndex = slaIndexPda(MINT_INDEX_SEED, mint);
+    await program.methods
+      .initSlaIndex({ mint: {} })
+      .accounts({
+        payer: SLA_DEPLOYERS[0].publicKey,
+        indexedKey: mint,
+        slaIndex: mintIndex,
+        slaIndexPage: slaIndexPagePda(mintIndex, new BN(0)),
+      })
+      .signers([SLA_DEPLOYERS[0]])
+      .rpc();
+  });
+
   SLAS.forEach((sla) => {
     it(`Deploys SLA ${sla.id}`, async () => {
       let deployerDslaTokenAccount = await getOrCreateAssociatedTokenAccount(
@@ -20,6 +45,18 @@ describe("Deploy SLA", () => {
         dsla_mint, // mint
         SLA_DEPLOYERS[sla.id].publicKey // owner,
       );
+      const deployerIndex = slaIndexPda(
+        DEPLOYER_INDEX_SEED,
+        SLA_DEPLOYERS[sla.id].publicKey
+      );
+      const mintIndex = slaIndexPda(MINT_INDEX_SEED, mint);
+      const slaRegistryEntry = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from(SLA_REGISTRY_ENTRY_SEED),
+          SLA_KEYPAIRS[sla.id].publicKey.toBuffer(),
+        ],
+        program.programId
+      )[0];
 
       // DEPLOY SLA
       try {
@@ -29,11 +66,24 @@ describe("Deploy SLA", () => {
             sla.leverage,
             sla.start,
             sla.nPeriods,
-            sla.periodLength
+            sla.periodLength,
+            sla.severity,
+            sla.penalty,
+            sla.oracleSource,
+            sla.aggregationPolicy,
+            sla.minQuorum,
+            sla.maxStaleness,
+            sla.maxConfidenceInterval
           )
           .accounts({
             deployer: SLA_DEPLOYERS[sla.id].publicKey,
-            slaRegistry: SLA_REGISTRY_KEYPAIR.publicKey,
+            slaRegistry: slaRegistryPda(),
+            slaRegistryPage: slaRegistryPagePda(new BN(0)),
+            deployerIndex,
+            deployerIndexPage: slaIndexPagePda(deployerIndex, new BN(0)),
+            mintIndex,
+            mintIndexPage: slaIndexPagePda(mintIndex, new BN(0)),
+            slaRegistryEntry,
             sla: SLA_KEYPAIRS[sla.id].publicKey,
             mint: mint,
             dslaMint: dsla_mint,
@@ -47,8 +97,13 @@ describe("Deploy SLA", () => {
 
       // VERIFY CORRECT DEPLOYMENT
       expectedSlaAccountAddresses.push(SLA_KEYPAIRS[sla.id].publicKey);
+      const slaRegistry = await program.account.slaRegistryHeader.fetch(
+        slaRegistryPda()
+      );
       const actualSlaAccountAddresses = (
-        await program.account.slaRegistry.fetch(SLA_REGISTRY_KEYPAIR.publicKey)
+        await program.account.slaRegistryPage.fetch(
+          slaRegistryPagePda(new BN(0))
+        )
       ).slaAccountAddresses;
 
       expect(
@@ -61,12 +116,40 @@ describe("Deploy SLA", () => {
         "SLA registry lenghth doesn't match"
       ).to.equal(expectedSlaAccountAddresses.length);
 
+      expect(
+        slaRegistry.slaCount.toNumber(),
+        "SLA count doesn't match"
+      ).to.equal(expectedSlaAccountAddresses.length);
+
       expect(
         actualSlaAccountAddresses[sla.id].toString(),
         "match to wrong address"
       ).to.not.equal(
         SLA_KEYPAIRS[(sla.id + 1) % SLA_KEYPAIRS.length].publicKey.toString()
       );
+
+      const entry = await program.account.slaRegistryEntry.fetch(
+        slaRegistryEntry
+      );
+      expect(entry.slaId.toNumber(), "SLA id doesn't match").to.equal(sla.id);
+      expect(
+        entry.deployerIndexId.toNumber(),
+        "deployer index id doesn't match"
+      ).to.equal(0);
+      expect(
+        entry.mintIndexId.toNumber(),
+        "mint index id doesn't match"
+      ).to.equal(sla.id);
+
+      const deployerSlas = (
+        await program.account.slaRegistryPage.fetch(
+          slaIndexPagePda(deployerIndex, new BN(0))
+        )
+      ).slaAccountAddresses;
+      expect(
+        deployerSlas.map((address) => address.toString()),
+        "deployer index doesn't match"
+      ).to.deep.equal([SLA_KEYPAIRS[sla.id].publicKey.toString()]);
     });
   });
 });
@@ -0,0 +1,149 @@
+import { AnchorError, BN } from "@project-serum/anchor";
+import { PublicKey } from "@solana/web3.js";
+import { expect } from "chai";
+import {
+  SLA_PROTOCOL_DEPLOYER,
+  GOVERNANCE_PARAMETERS,
+  GOVERNANCE_SEED,
+  GOVERNANCE_CHANGE_SEED,
+  STAKERS,
+} from "./constants";
+import { program } from "./init";
+
+describe("Change the governance parameters", () => {
+  const governanceChange = PublicKey.findProgramAddressSync(
+    [Buffer.from(GOVERNANCE_CHANGE_SEED)],
+    program.programId
+  )[0];
+  const parameters = {
+    ...GOVERNANCE_PARAMETERS,
+    proposalBond: GOVERNANCE_PARAMETERS.proposalBond.add(new BN(1)),
+  };
+
+  it("should fail to propose a governance change because it's not the governance authority", async () => {
+    try {
+      await program.methods
+        .proposeGovernanceChange(parameters)
+        .accounts({
+          governanceAuthority: STAKERS[0].publicKey,
+        })
+        .signers([STAKERS[0]])
+        .rpc();
+      chai.assert(false, "should've failed but didn't ");
+    } catch (_err) {
+      expect(_err).to.be.instanceOf(AnchorError);
+      const err: AnchorError = _err;
+      expect(err.error.errorCode.code).to.equal(
+        "UnauthorizedGovernanceAuthority"
+      );
+      expect(err.error.errorCode.number).to.equal(6023);
+    }
+  });
+
+  it("proposes a governance change", async () => {
+    await program.methods
+      .proposeGovernanceChange(parameters)
+      .accounts({
+        governanceAuthority: SLA_PROTOCOL_DEPLOYER.publicKey,
+      })
+      .signers([SLA_PROTOCOL_DEPLOYER])
+      .rpc();
+
+    const change = await program.account.governanceChange.fetch(
+      governanceChange
+    );
+    expect(change.proposer.toString()).to.equal(
+      SLA_PROTOCOL_DEPLOYER.publicKey.toString()
+    );
+    expect(change.parameters.proposalBond.toString()).to.equal(
+      parameters.proposalBond.toString()
+    );
+  });
+
+  it("should fail to execute the governance change before the timelock ended", async () => {
+    try {
+      await program.methods
+        .executeGovernanceChange()
+        .accounts({
+          proposer: SLA_PROTOCOL_DEPLOYER.publicKey,
+        })
+        .rpc();
+      chai.assert(false, "should've failed but didn't ");
+    } catch (_err) {
+      expect(_err).to.be.instanceOf(AnchorError);
+      const err: AnchorError = _err;
+      expect(err.error.errorCode.code).to.equal("GovernanceChangeTimelocked");
+      expect(err.error.errorCode.number).to.equal(6022);
+    }
+  });
+
+  it("cancels the governance change", async () => {
+    await program.methods
+      .cancelGovernanceChange()
+      .accounts({
+        governanceAuthority: SLA_PROTOCOL_DEPLOYER.publicKey,
+        proposer: SLA_PROTOCOL_DEPLOYER.publicKey,
+      })
+      .signers([SLA_PROTOCOL_DEPLOYER])
+      .rpc();
+
+    expect(
+      await program.account.governanceChange.fetchNullable(governanceChange)
+    ).to.be.null;
+  });
+});
+
+describe("Transfer the governance authority", () => {
+  it("should fail to accept the governance authority because it wasn't transferred to it", async () => {
+    try {
+      await program.methods
+        .acceptGovernanceAuthority()
+        .accounts({
+          newAuthority: STAKERS[1].publicKey,
+        })
+        .signers([STAKERS[1]])
+        .rpc();
+      chai.assert(false, "should've failed but didn't ");
+    } catch (_err) {
+      expect(_err).to.be.instanceOf(AnchorError);
+      const err: AnchorError = _err;
+      expect(err.error.errorCode.code).to.equal(
+        "UnauthorizedGovernanceAuthority"
+      );
+      expect(err.error.errorCode.number).to.equal(6023);
+    }
+  });
+
+  it("transfers the governance authority and back", async () => {
+    for (const [authority, newAuthority] of [
+      [SLA_PROTOCOL_DEPLOYER, STAKERS[0]],
+      [STAKERS[0], SLA_PROTOCOL_DEPLOYER],
+    ]) {
+      await program.methods
+        .transferGovernanceAuthority(newAuthority.publicKey)
+        .accounts({
+          governanceAuthority: authority.publicKey,
+        })
+        .signers([authority])
+        .rpc();
+      await program.methods
+        .acceptGovernanceAuthority()
+        .accounts({
+          newAuthority: newAuthority.publicKey,
+        })
+        .signers([newAuthority])
+        .rpc();
+
+      const governance = await program.account.governance.fetch(
+        PublicKey.findProgramAddressSync(
+          [Buffer.from(GOVERNANCE_SEED)],
+          program.programId
+        )[0]
+      );
+      expect(governance.governanceAuthority.toString()).to.equal(
+        newAuthority.publicKey.toString()
+      );
+      expect(governance.pendingGovernanceAuthority).to.be.null;
+    }
+  });
+});
@@ -4,7 +4,7 @@ import {
   GOVERNANCE_PARAMETERS,
   STAKERS,
 } from "./constants";
-import { program } from "./init";
+import { program, dsla_mint } from "./init";
 import { AnchorError, BN, web3 } from "@project-serum/anchor";
 import { expect } from "chai";
 import { PublicKey } from "@solana/web3.js";
@@ -23,17 +23,10 @@ describe("Initialize the Governance PDA", () => {
   it("should fail to initialize governance PDA because it's not the program upgrade authority", async () => {
     try {
       await program.methods
-        .initGovernance(
-          GOVERNANCE_PARAMETERS.dslaDepositByPeriod,
-          GOVERNANCE_PARAMETERS.dslaProtocolReward,
-          GOVERNANCE_PARAMETERS.dslaValidatorReward,
-          GOVERNANCE_PARAMETERS.dslaBurnedByVerification,
-          GOVERNANCE_PARAMETERS.slaDeployerRewardsRate,
-          GOVERNANCE_PARAMETERS.protocolRewardsRate,
-          GOVERNANCE_PARAMETERS.maxLeverage
-        )
+        .initGovernance(GOVERNANCE_PARAMETERS)
         .accounts({
           programUpgradeAuthority: STAKERS[0].publicKey,
+          dslaMint: dsla_mint,
           programData: programDataAddress,
           program: program.programId,
         })
@@ -57,17 +50,10 @@ describe("Initialize the Governance PDA", () => {
 
     try {
       await program.methods
-        .initGovernance(
-          gov_params.dslaDepositByPeriod,
-          gov_params.dslaProtocolReward,
-          gov_params.dslaValidatorReward,
-          gov_params.dslaBurnedByVerification,
-          gov_params.slaDeployerRewardsRate,
-          gov_params.protocolRewardsRate,
-          gov_params.maxLeverage
-        )
+        .initGovernance(gov_params)
         .accounts({
           programUpgradeAuthority: SLA_PROTOCOL_DEPLOYER.publicKey,
+          dslaMint: dsla_mint,
           programData: programDataAddress,
           program: program.programId,
         })
@@ -85,17 +71,10 @@ describe("Initialize the Governance PDA", () => {
 
   it("initialize governance PDA", async () => {
     await program.methods
-      .initGovernance(
-        GOVERNANCE_PARAMETERS.dslaDepositByPeriod,
-        GOVERNANCE_PARAMETERS.dslaProtocolReward,
-        GOVERNANCE_PARAMETERS.dslaValidatorReward,
-        GOVERNANCE_PARAMETERS.dslaBurnedByVerification,
-        GOVERNANCE_PARAMETERS.slaDeployerRewardsRate,
-        GOVERNANCE_PARAMETERS.protocolRewardsRate,
-        GOVERNANCE_PARAMETERS.maxLeverage
-      )
+      .initGovernance(GOVERNANCE_PARAMETERS)
       .accounts({
         programUpgradeAuthority: SLA_PROTOCOL_DEPLOYER.publicKey,
+        dslaMint: dsla_mint,
         programData: programDataAddress,
         program: program.programId,
       })
@@ -5,6 +5,7 @@ import { expect } from "chai";
 import { LAMPORTS_PER_SOL, PublicKey } from "@solana/web3.js";
 import {
   STAKERS,
+  VIRTUAL_SHARES,
   SLA_KEYPAIRS,
   PT_MINT_SEED,
   MINT_AUTHORITY,
@@ -18,6 +19,7 @@ import { mint, program, connection } from "./init";
 describe("Stake Provider", () => {
   it("stakes provider side", async () => {
     const tokenAmount = new BN(LAMPORTS_PER_SOL * 10);
+    const shareAmount = tokenAmount.mul(VIRTUAL_SHARES);
 
     let stakerTokenAccount = await getOrCreateAssociatedTokenAccount(
       connection, // connection
@@ -65,7 +67,7 @@ describe("Stake Provider", () => {
 
     try {
       await program.methods
-        .stakeProvider(tokenAmount)
+        .stakeProvider(tokenAmount, shareAmount)
         .accounts({
           staker: STAKERS[0].publicKey,
           sla: SLA_KEYPAIRS[0].publicKey,
@@ -90,8 +92,8 @@ describe("Stake Provider", () => {
       ).amount
     );
     expect(
-      stakerPtAccountAmount.eq(new BN(tokenAmount)),
-      "provider token account amount does not equal staked token Amount"
+      stakerPtAccountAmount.eq(shareAmount),
+      "provider token account amount does not equal the shares minted at par"
     ).to.be.true;
 
     providerPoolSize = (
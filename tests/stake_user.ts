@@ -4,6 +4,7 @@ import { expect } from "chai";
 import { LAMPORTS_PER_SOL, PublicKey } from "@solana/web3.js";
 import {
   STAKERS,
+  VIRTUAL_SHARES,
   SLA_KEYPAIRS,
   UT_MINT_SEED,
   MINT_AUTHORITY,
@@ -17,6 +18,7 @@ import { mint, program, connection } from "./init";
 describe("Stake User", () => {
   it("checks that it stakes user side", async () => {
     const tokenAmount = new BN(LAMPORTS_PER_SOL * 1);
+    const shareAmount = tokenAmount.mul(VIRTUAL_SHARES);
 
     let stakerTokenAccount = await getOrCreateAssociatedTokenAccount(
       connection, // connection
@@ -64,7 +66,7 @@ describe("Stake User", () => {
 
     try {
       await program.methods
-        .stakeUser(tokenAmount)
+        .stakeUser(tokenAmount, shareAmount)
         .accounts({
           staker: STAKERS[0].publicKey,
           sla: SLA_KEYPAIRS[0].publicKey,
@@ -89,8 +91,8 @@ describe("Stake User", () => {
       ).amount
     );
     expect(
-      stakerUtAccountAmount.eq(new BN(tokenAmount)),
-      "user token account amount does not equal staked token Amount"
+      stakerUtAccountAmount.eq(shareAmount),
+      "user token account amount does not equal the shares minted at par"
     ).to.be.true;
 
     slaAccount = await program.account.sla.fetch(SLA_KEYPAIRS[0].publicKey);
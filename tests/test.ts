@@ -1,5 +1,6 @@
 import "./init";
 import "./init_governance";
+import "./governance_change";
 import "./init_sla_registry";
 import "./deploy_sla";
 // import "./init_lockup_accounts";
@@ -1,4 +1,11 @@
 import { Connection, LAMPORTS_PER_SOL, PublicKey } from "@solana/web3.js";
+import { BN } from "@project-serum/anchor";
+import {
+  SLA_REGISTRY_HEADER_SEED,
+  SLA_REGISTRY_PAGE_SEED,
+  SLA_INDEX_PAGE_SEED,
+} from "./constants";
+import { program } from "./init";
 
 export async function fund_account(connection: Connection, pubkey: PublicKey) {
   let airdropSignature = await connection.requestAirdrop(
@@ -7,3 +14,35 @@ export async function fund_account(connection: Connection, pubkey: PublicKey) {
   );
   await connection.confirmTransaction(airdropSignature);
 }
+
+export function slaRegistryPda() {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from(SLA_REGISTRY_HEADER_SEED)],
+    program.programId
+  )[0];
+}
+
+export function slaRegistryPagePda(page: BN) {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from(SLA_REGISTRY_PAGE_SEED), page.toArrayLike(Buffer, "le", 8)],
+    program.programId
+  )[0];
+}
+
+export function slaIndexPda(seed: string, indexedKey: PublicKey) {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from(seed), indexedKey.toBuffer()],
+    program.programId
+  )[0];
+}
+
+export function slaIndexPagePda(slaIndex: PublicKey, page: BN) {
+  return PublicKey.findProgramAddressSync(
+    [
+      Buffer.from(SLA_INDEX_PAGE_SEED),
+      slaIndex.toBuffer(),
+      page.toArrayLike(Buffer, "le", 8),
+    ],
+    program.programId
+  )[0];
+}